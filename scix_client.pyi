@@ -0,0 +1,717 @@
+"""Type stubs for the scix_client extension module.
+
+Data classes mirror the `#[pyclass(get_all)]` structs in `src/types.rs`; the
+client and builder signatures mirror `src/python.rs`. `tests/test_stub.py`
+fails if either drifts from this file.
+"""
+
+from typing import Any, Awaitable, Dict, List, Optional, Set, Tuple
+
+# ---------------------------------------------------------------------------
+# Enums
+# ---------------------------------------------------------------------------
+
+class PdfLinkType:
+    ArXiv: PdfLinkType
+    Publisher: PdfLinkType
+    AdsScan: PdfLinkType
+    Direct: PdfLinkType
+
+class ExportFormat:
+    BibTeX: ExportFormat
+    BibTeXAbs: ExportFormat
+    AasTex: ExportFormat
+    Icarus: ExportFormat
+    Mnras: ExportFormat
+    Soph: ExportFormat
+    Ris: ExportFormat
+    Endnote: ExportFormat
+    Medlars: ExportFormat
+    Ieee: ExportFormat
+    Csl: ExportFormat
+    DcXml: ExportFormat
+    RefXml: ExportFormat
+    RefAbsXml: ExportFormat
+    VoTable: ExportFormat
+    Rss: ExportFormat
+    Custom: ExportFormat
+    def as_api_str(self) -> str: ...
+    def file_extension(self) -> str: ...
+    @staticmethod
+    def from_str(s: str) -> Optional[ExportFormat]: ...
+
+class KeyStyle:
+    AdsDefault: KeyStyle
+    FirstAuthorYear: KeyStyle
+    FirstAuthorYearTitleWord: KeyStyle
+
+class SortField:
+    Date: SortField
+    EntryDate: SortField
+    CitationCount: SortField
+    CitationCountNorm: SortField
+    ClassicFactor: SortField
+    ReadCount: SortField
+    FirstAuthor: SortField
+    Bibcode: SortField
+    Score: SortField
+    def as_api_str(self) -> str: ...
+    @staticmethod
+    def from_str(s: str) -> Optional[SortField]: ...
+
+class SortDirection:
+    Asc: SortDirection
+    Desc: SortDirection
+
+# ---------------------------------------------------------------------------
+# Data types
+# ---------------------------------------------------------------------------
+
+class Author:
+    @property
+    def name(self) -> str: ...
+    @property
+    def family_name(self) -> str: ...
+    @property
+    def given_name(self) -> Optional[str]: ...
+    @property
+    def affiliation(self) -> Optional[str]: ...
+    @staticmethod
+    def from_ads_format(name: str) -> Author: ...
+    def display_name(self) -> str: ...
+    def bibtex_name(self) -> str: ...
+
+class PdfLink:
+    @property
+    def url(self) -> str: ...
+    @property
+    def link_type(self) -> PdfLinkType: ...
+    @property
+    def label(self) -> str: ...
+
+class Paper:
+    @property
+    def bibcode(self) -> str: ...
+    @property
+    def title(self) -> str: ...
+    @property
+    def authors(self) -> List[Author]: ...
+    @property
+    def year(self) -> Optional[int]: ...
+    @property
+    def publication(self) -> Optional[str]: ...
+    @property
+    def volume(self) -> Optional[str]: ...
+    @property
+    def page(self) -> Optional[str]: ...
+    @property
+    def abstract_text(self) -> Optional[str]: ...
+    @property
+    def abstract_language(self) -> Optional[str]: ...
+    @property
+    def doi(self) -> Optional[str]: ...
+    @property
+    def dois(self) -> List[str]: ...
+    @property
+    def arxiv_id(self) -> Optional[str]: ...
+    @property
+    def identifiers(self) -> List[str]: ...
+    @property
+    def esources(self) -> List[str]: ...
+    @property
+    def citation_count(self) -> Optional[int]: ...
+    @property
+    def read_count(self) -> Optional[int]: ...
+    @property
+    def doctype(self) -> Optional[str]: ...
+    @property
+    def properties(self) -> List[str]: ...
+    @property
+    def keywords(self) -> List[str]: ...
+    @property
+    def affiliations(self) -> List[str]: ...
+    @property
+    def pdf_links(self) -> List[PdfLink]: ...
+    @property
+    def url(self) -> str: ...
+    def citation_line(self) -> str: ...
+    def short_authors(self) -> str: ...
+    def arxiv_categories(self) -> List[str]: ...
+    def subject_keywords(self) -> List[str]: ...
+    def looks_english(self) -> Optional[bool]: ...
+
+class SearchResponse:
+    @property
+    def papers(self) -> List[Paper]: ...
+    @property
+    def num_found(self) -> int: ...
+    @property
+    def meta(self) -> Optional[ResponseMeta]: ...
+    def merge(self, other: SearchResponse) -> SearchResponse: ...
+    def dedup_by_bibcode(self) -> None: ...
+    def coauthors(self, exclude: str) -> List[Tuple[str, int]]: ...
+    def __len__(self) -> int: ...
+
+class ResponseMeta:
+    @property
+    def status(self) -> Optional[int]: ...
+    @property
+    def qtime_ms(self) -> Optional[int]: ...
+    @property
+    def params(self) -> Dict[str, List[str]]: ...
+    def query(self) -> Optional[str]: ...
+
+class BasicStatsEntry:
+    @property
+    def number_of_papers(self) -> Optional[int]: ...
+    @property
+    def normalized_paper_count(self) -> Optional[float]: ...
+    @property
+    def total_citations(self) -> Optional[int]: ...
+    @property
+    def total_normalized_citations(self) -> Optional[float]: ...
+    @property
+    def median_refereed_citations(self) -> Optional[float]: ...
+    @property
+    def mean_refereed_citations(self) -> Optional[float]: ...
+
+class BasicStats:
+    @property
+    def refereed(self) -> Optional[BasicStatsEntry]: ...
+    @property
+    def total(self) -> Optional[BasicStatsEntry]: ...
+
+class CitationStatsEntry:
+    @property
+    def number_of_citing_papers(self) -> Optional[int]: ...
+    @property
+    def total_citations(self) -> Optional[int]: ...
+    @property
+    def number_of_self_citations(self) -> Optional[int]: ...
+    @property
+    def average_citations(self) -> Optional[float]: ...
+    @property
+    def normalized_citations(self) -> Optional[float]: ...
+    def external_citations(self) -> Optional[int]: ...
+
+class ExternalCitationSummary:
+    @property
+    def total(self) -> Optional[int]: ...
+    @property
+    def refereed(self) -> Optional[int]: ...
+
+class CitationStats:
+    @property
+    def refereed(self) -> Optional[CitationStatsEntry]: ...
+    @property
+    def total(self) -> Optional[CitationStatsEntry]: ...
+
+class Indicators:
+    @property
+    def h(self) -> Optional[int]: ...
+    @property
+    def g(self) -> Optional[int]: ...
+    @property
+    def i10(self) -> Optional[int]: ...
+    @property
+    def i100(self) -> Optional[int]: ...
+    @property
+    def m(self) -> Optional[float]: ...
+    @property
+    def tori(self) -> Optional[float]: ...
+    @property
+    def riq(self) -> Optional[int]: ...
+    @property
+    def read10(self) -> Optional[float]: ...
+
+class Metrics:
+    @property
+    def basic_stats(self) -> Optional[BasicStats]: ...
+    @property
+    def citation_stats(self) -> Optional[CitationStats]: ...
+    @property
+    def indicators(self) -> Optional[Indicators]: ...
+    @property
+    def time_series(self) -> Optional[List[TimeSeriesPoint]]: ...
+    @property
+    def histograms(self) -> Optional[Dict[str, Dict[str, Dict[str, float]]]]: ...
+    def external_citation_summary(self) -> ExternalCitationSummary: ...
+
+class MetricType:
+    Basic: MetricType
+    Citations: MetricType
+    Indicators: MetricType
+    Histograms: MetricType
+    TimeSeries: MetricType
+
+class TimeSeriesPoint:
+    @property
+    def year(self) -> int: ...
+    @property
+    def h(self) -> Optional[int]: ...
+    @property
+    def g(self) -> Optional[int]: ...
+    @property
+    def i10(self) -> Optional[int]: ...
+    @property
+    def i100(self) -> Optional[int]: ...
+    @property
+    def read10(self) -> Optional[float]: ...
+    @property
+    def tori(self) -> Optional[float]: ...
+
+class Library:
+    @property
+    def id(self) -> str: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def description(self) -> str: ...
+    @property
+    def num_documents(self) -> int: ...
+    @property
+    def public(self) -> bool: ...
+    @property
+    def owner(self) -> str: ...
+    @property
+    def date_created(self) -> str: ...
+    @property
+    def date_last_modified(self) -> str: ...
+
+class LibraryDetail:
+    @property
+    def metadata(self) -> Library: ...
+    @property
+    def documents(self) -> List[str]: ...
+
+class ExportResult:
+    @property
+    def body(self) -> str: ...
+    @property
+    def succeeded(self) -> List[str]: ...
+    @property
+    def failed(self) -> List[str]: ...
+
+class BibEntry:
+    @property
+    def entry_type(self) -> str: ...
+    @property
+    def key(self) -> str: ...
+    @property
+    def fields(self) -> Dict[str, str]: ...
+
+class Neighborhood:
+    @property
+    def bibcode(self) -> str: ...
+    @property
+    def references(self) -> List[Paper]: ...
+    @property
+    def citations(self) -> List[Paper]: ...
+    @property
+    def num_references(self) -> int: ...
+    @property
+    def num_citations(self) -> int: ...
+    def papers(self) -> List[Paper]: ...
+
+class Dossier:
+    @property
+    def bibcode(self) -> str: ...
+    @property
+    def paper(self) -> Optional[Paper]: ...
+    @property
+    def metrics(self) -> Optional[Metrics]: ...
+    @property
+    def references(self) -> Optional[SearchResponse]: ...
+    @property
+    def citations(self) -> Optional[SearchResponse]: ...
+    @property
+    def links(self) -> Optional[List[ResolvedLink]]: ...
+    @property
+    def errors(self) -> Dict[str, str]: ...
+
+class SyncReport:
+    @property
+    def added(self) -> List[str]: ...
+    @property
+    def removed(self) -> List[str]: ...
+
+class FieldStats:
+    @property
+    def min(self) -> Optional[float]: ...
+    @property
+    def max(self) -> Optional[float]: ...
+    @property
+    def mean(self) -> Optional[float]: ...
+    @property
+    def sum(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+
+class RateLimitStatus:
+    @property
+    def remaining(self) -> Optional[int]: ...
+    @property
+    def limit(self) -> Optional[int]: ...
+    @property
+    def reset_at(self) -> Optional[int]: ...
+    def used(self) -> Optional[int]: ...
+
+class NetworkNode:
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def group(self) -> Optional[str]: ...
+    @property
+    def weight(self) -> Optional[float]: ...
+    @property
+    def papers(self) -> List[str]: ...
+
+class NetworkLink:
+    @property
+    def source(self) -> int: ...
+    @property
+    def target(self) -> int: ...
+    @property
+    def weight(self) -> float: ...
+
+class NetworkGraph:
+    @property
+    def nodes(self) -> List[NetworkNode]: ...
+    @property
+    def links(self) -> List[NetworkLink]: ...
+
+class ObjectResult:
+    @property
+    def object(self) -> str: ...
+    @property
+    def bibcodes(self) -> List[str]: ...
+
+class ResolvedLink:
+    @property
+    def url(self) -> str: ...
+    @property
+    def title(self) -> Optional[str]: ...
+    @property
+    def link_type(self) -> Optional[str]: ...
+
+class ResolvedReference:
+    @property
+    def reference(self) -> str: ...
+    @property
+    def bibcode(self) -> Optional[str]: ...
+    @property
+    def score(self) -> Optional[str]: ...
+
+class Sort:
+    def __init__(self, field: str, direction: SortDirection = ...) -> None: ...
+    @property
+    def field(self) -> str: ...
+    @property
+    def direction(self) -> SortDirection: ...
+    @staticmethod
+    def by(field: SortField, direction: SortDirection = ...) -> Sort: ...
+    @staticmethod
+    def date_desc() -> Sort: ...
+    @staticmethod
+    def citation_count_desc() -> Sort: ...
+    @staticmethod
+    def score_desc() -> Sort: ...
+    @staticmethod
+    def relevance() -> Sort: ...
+    @staticmethod
+    def from_human(s: str) -> Optional[Sort]: ...
+
+# ---------------------------------------------------------------------------
+# Clients
+# ---------------------------------------------------------------------------
+
+class SciXClient:
+    def __init__(self, token: Optional[str] = None) -> None: ...
+    def set_base_url(self, url: str) -> None: ...
+    def close(self) -> None: ...
+    def rate_limit_status(self) -> Optional[RateLimitStatus]: ...
+    def __enter__(self) -> SciXClient: ...
+    def __exit__(
+        self,
+        exc_type: Optional[Any] = None,
+        exc_value: Optional[Any] = None,
+        traceback: Optional[Any] = None,
+    ) -> bool: ...
+
+    # Search
+    def search(self, query: str, rows: int = 10) -> SearchResponse: ...
+    def search_relevant(self, query: str, rows: int = 10) -> SearchResponse: ...
+    def get_paper(self, identifier: str) -> Paper: ...
+    def abstract_of(self, bibcode: str) -> Optional[str]: ...
+    def count(self, query: str) -> int: ...
+    def canonicalize(self, bibcodes: List[str]) -> Dict[str, str]: ...
+    def field_stats(self, query: str, field: str) -> FieldStats: ...
+    def search_with_options(
+        self,
+        query: str,
+        fields: str = ...,
+        sort: Optional[Sort] = None,
+        rows: int = 10,
+        start: int = 0,
+        filters: Optional[List[str]] = None,
+    ) -> SearchResponse: ...
+    def search_raw(
+        self,
+        query: str,
+        fields: str,
+        sort: Optional[Sort] = None,
+        rows: int = 10,
+        start: int = 0,
+    ) -> Dict[str, Any]: ...
+    def explain_query(self, query: str) -> Dict[str, Any]: ...
+    def search_collapse_versions(
+        self,
+        query: str,
+        rows: int = 10,
+        sort: Optional[Sort] = None,
+    ) -> SearchResponse: ...
+    def bigquery(
+        self,
+        bibcodes: List[str],
+        query: Optional[str] = None,
+        fields: Optional[str] = None,
+        sort: Optional[Sort] = None,
+        rows: Optional[int] = None,
+    ) -> SearchResponse: ...
+    def references(self, bibcode: str, rows: int = 25) -> SearchResponse: ...
+    def citations(self, bibcode: str, rows: int = 25) -> SearchResponse: ...
+    def neighborhood(self, bibcode: str, rows: int = 25) -> Neighborhood: ...
+    def dossier(self, bibcode: str, rows: int = 25) -> Dossier: ...
+    def citations_excluding_self(
+        self, bibcode: str, author_name: str, rows: int = 25
+    ) -> SearchResponse: ...
+    def similar(self, bibcode: str, rows: int = 10) -> SearchResponse: ...
+    def coreads(self, bibcode: str, rows: int = 10) -> SearchResponse: ...
+    def reviews(self, bibcode: str, rows: int = 25) -> SearchResponse: ...
+    def author_orcid(
+        self, orcid: str, rows: int = 50, sort: Optional[Sort] = None
+    ) -> SearchResponse: ...
+    def useful(self, bibcodes: List[str], rows: int = 25) -> SearchResponse: ...
+
+    # Export and metrics
+    def export(
+        self,
+        bibcodes: List[str],
+        format: ExportFormat = ...,
+        sort: Optional[Sort] = None,
+    ) -> str: ...
+    def export_to_file(
+        self,
+        bibcodes: List[str],
+        format: ExportFormat = ...,
+        path: Optional[str] = None,
+    ) -> str: ...
+    def export_checked(
+        self,
+        bibcodes: List[str],
+        format: ExportFormat = ...,
+        sort: Optional[Sort] = None,
+    ) -> ExportResult: ...
+    def export_bibtex(self, bibcodes: List[str]) -> str: ...
+    def export_csl_json(self, bibcodes: List[str]) -> List[Dict[str, Any]]: ...
+    def export_bibtex_with_keys(self, bibcodes: List[str], key_style: KeyStyle) -> str: ...
+    def export_bibtex_parsed(self, bibcodes: List[str]) -> List[BibEntry]: ...
+    def metrics(self, bibcodes: List[str]) -> Metrics: ...
+    def metrics_with_types(self, bibcodes: List[str], types: List[MetricType]) -> Metrics: ...
+    def metrics_for_query(self, query: str, max: int = 2000) -> Metrics: ...
+    def citation_growth(self, bibcode: str) -> List[Tuple[int, int]]: ...
+
+    # Libraries
+    def list_libraries(self) -> List[Library]: ...
+    def get_library(self, id: str) -> LibraryDetail: ...
+    def get_library_by_name(self, name: str) -> Optional[LibraryDetail]: ...
+    def create_library(
+        self,
+        name: str,
+        description: str = "",
+        public: bool = False,
+        bibcodes: Optional[List[str]] = None,
+    ) -> Library: ...
+    def edit_library(
+        self,
+        id: str,
+        name: Optional[str] = None,
+        description: Optional[str] = None,
+        public: Optional[bool] = None,
+    ) -> None: ...
+    def delete_library(self, id: str) -> None: ...
+    def add_documents(self, library_id: str, bibcodes: List[str]) -> None: ...
+    def remove_documents(self, library_id: str, bibcodes: List[str]) -> None: ...
+    def all_saved_bibcodes(self) -> Set[str]: ...
+    def sync_library(self, library_id: str, bibcodes: List[str]) -> SyncReport: ...
+    def get_permissions(self, library_id: str) -> Any: ...
+    def update_permissions(self, library_id: str, email: str, permission: str) -> None: ...
+    def transfer_library(self, library_id: str, email: str) -> None: ...
+    def get_annotation(self, library_id: str, bibcode: str) -> str: ...
+    def set_annotation(self, library_id: str, bibcode: str, content: str) -> None: ...
+    def delete_annotation(self, library_id: str, bibcode: str) -> None: ...
+    def library_operation(
+        self,
+        library_id: str,
+        action: str,
+        source_library_ids: Optional[List[str]] = None,
+    ) -> Any: ...
+    def add_documents_by_query(
+        self, library_id: str, query: str, rows: Optional[int] = None
+    ) -> int: ...
+    def create_library_from_query(
+        self,
+        name: str,
+        query: str,
+        description: str = "",
+        public: bool = False,
+        max: int = 50,
+    ) -> Library: ...
+
+    # Resolution
+    def resolve_references(self, references: List[str]) -> List[ResolvedReference]: ...
+    def resolve_objects(self, objects: List[str]) -> Any: ...
+    def resolve_objects_typed(self, objects: List[str], rows: int = 25) -> List[ObjectResult]: ...
+    def resolve_links(self, bibcode: str, link_type: Optional[str] = None) -> Any: ...
+    def resolve_links_typed(
+        self, bibcode: str, link_type: Optional[str] = None
+    ) -> List[ResolvedLink]: ...
+
+    # Networks
+    def author_network(self, bibcodes: List[str]) -> Any: ...
+    def paper_network(self, bibcodes: List[str]) -> Any: ...
+    def author_network_typed(self, bibcodes: List[str]) -> NetworkGraph: ...
+    def top_coauthors(self, bibcodes: List[str], limit: int = 10) -> List[Tuple[str, int]]: ...
+    def paper_network_typed(self, bibcodes: List[str]) -> NetworkGraph: ...
+    def citation_helper(self, bibcodes: List[str]) -> Any: ...
+
+    # Async variants
+    def search_async(self, query: str, rows: int = 10) -> Awaitable[SearchResponse]: ...
+    def search_relevant_async(self, query: str, rows: int = 10) -> Awaitable[SearchResponse]: ...
+    def search_with_options_async(
+        self,
+        query: str,
+        fields: str = ...,
+        sort: Optional[Sort] = None,
+        rows: int = 10,
+        start: int = 0,
+        filters: Optional[List[str]] = None,
+    ) -> Awaitable[SearchResponse]: ...
+    def get_paper_async(self, identifier: str) -> Awaitable[Paper]: ...
+    def count_async(self, query: str) -> Awaitable[int]: ...
+    def references_async(self, bibcode: str, rows: int = 25) -> Awaitable[SearchResponse]: ...
+    def citations_async(self, bibcode: str, rows: int = 25) -> Awaitable[SearchResponse]: ...
+    def export_async(
+        self,
+        bibcodes: List[str],
+        format: ExportFormat = ...,
+        sort: Optional[Sort] = None,
+    ) -> Awaitable[str]: ...
+    def export_bibtex_async(self, bibcodes: List[str]) -> Awaitable[str]: ...
+    def metrics_async(self, bibcodes: List[str]) -> Awaitable[Metrics]: ...
+    def metrics_with_types_async(
+        self, bibcodes: List[str], types: List[MetricType]
+    ) -> Awaitable[Metrics]: ...
+
+class AsyncSciXClient:
+    def __init__(self, token: Optional[str] = None) -> None: ...
+    def set_base_url(self, url: str) -> None: ...
+
+    # Search
+    def search(self, query: str, rows: int = 10) -> Awaitable[SearchResponse]: ...
+    def search_relevant(self, query: str, rows: int = 10) -> Awaitable[SearchResponse]: ...
+    def search_with_options(
+        self,
+        query: str,
+        fields: str = ...,
+        sort: Optional[Sort] = None,
+        rows: int = 10,
+        start: int = 0,
+        filters: Optional[List[str]] = None,
+    ) -> Awaitable[SearchResponse]: ...
+    def get_paper(self, identifier: str) -> Awaitable[Paper]: ...
+    def count(self, query: str) -> Awaitable[int]: ...
+    def field_stats(self, query: str, field: str) -> Awaitable[FieldStats]: ...
+    def references(self, bibcode: str, rows: int = 25) -> Awaitable[SearchResponse]: ...
+    def citations(self, bibcode: str, rows: int = 25) -> Awaitable[SearchResponse]: ...
+    def similar(self, bibcode: str, rows: int = 10) -> Awaitable[SearchResponse]: ...
+    def canonicalize(self, bibcodes: List[str]) -> Awaitable[Dict[str, str]]: ...
+
+    # Export and metrics
+    def export(
+        self,
+        bibcodes: List[str],
+        format: ExportFormat = ...,
+        sort: Optional[Sort] = None,
+    ) -> Awaitable[str]: ...
+    def export_bibtex(self, bibcodes: List[str]) -> Awaitable[str]: ...
+    def metrics(self, bibcodes: List[str]) -> Awaitable[Metrics]: ...
+    def metrics_with_types(
+        self, bibcodes: List[str], types: List[MetricType]
+    ) -> Awaitable[Metrics]: ...
+    def citation_growth(self, bibcode: str) -> Awaitable[List[Tuple[int, int]]]: ...
+
+    # Libraries
+    def list_libraries(self) -> Awaitable[List[Library]]: ...
+    def get_library(self, id: str) -> Awaitable[LibraryDetail]: ...
+    def add_documents(self, library_id: str, bibcodes: List[str]) -> Awaitable[None]: ...
+    def remove_documents(self, library_id: str, bibcodes: List[str]) -> Awaitable[None]: ...
+
+    # Resolution
+    def resolve_references(
+        self, references: List[str]
+    ) -> Awaitable[List[ResolvedReference]]: ...
+
+# ---------------------------------------------------------------------------
+# Query builder
+# ---------------------------------------------------------------------------
+
+class QueryBuilder:
+    def __init__(self) -> None: ...
+    def author(self, name: str) -> QueryBuilder: ...
+    def first_author(self, name: str) -> QueryBuilder: ...
+    def title(self, text: str) -> QueryBuilder: ...
+    def abstract_contains(self, text: str) -> QueryBuilder: ...
+    def full_text(self, text: str) -> QueryBuilder: ...
+    def body(self, text: str) -> QueryBuilder: ...
+    def keyword(self, text: str) -> QueryBuilder: ...
+    def language(self, lang: str) -> QueryBuilder: ...
+    def affiliation(self, text: str) -> QueryBuilder: ...
+    def affiliation_id(self, id: str) -> QueryBuilder: ...
+    def institution(self, id: str) -> QueryBuilder: ...
+    def year(self, year: int) -> QueryBuilder: ...
+    def year_range(self, from_: int, to: int) -> QueryBuilder: ...
+    def pubdate_range(self, from_: str, to: str) -> QueryBuilder: ...
+    def bibcode(self, bibcode: str) -> QueryBuilder: ...
+    def bibcode_list(self, bibcodes: List[str]) -> QueryBuilder: ...
+    def identifier_list(self, identifiers: List[str]) -> QueryBuilder: ...
+    def doi(self, doi: str) -> QueryBuilder: ...
+    def arxiv(self, arxiv_id: str) -> QueryBuilder: ...
+    def object(self, name: str) -> QueryBuilder: ...
+    def bibstem(self, stem: str) -> QueryBuilder: ...
+    def database(self, name: str) -> QueryBuilder: ...
+    def property(self, prop: str) -> QueryBuilder: ...
+    def doctype(self, dtype: str) -> QueryBuilder: ...
+    def orcid(self, orcid: str) -> QueryBuilder: ...
+    def orcid_any(self, orcid: str) -> QueryBuilder: ...
+    def and_(self) -> QueryBuilder: ...
+    def or_(self) -> QueryBuilder: ...
+    def exclude(self) -> QueryBuilder: ...
+    def not_field(self, field: str, value: str) -> QueryBuilder: ...
+    def not_author(self, name: str) -> QueryBuilder: ...
+    def not_property(self, prop: str) -> QueryBuilder: ...
+    def not_doctype(self, dtype: str) -> QueryBuilder: ...
+    def raw(self, query: str) -> QueryBuilder: ...
+    @staticmethod
+    def citations_of(bibcode: str) -> QueryBuilder: ...
+    @staticmethod
+    def references_of(bibcode: str) -> QueryBuilder: ...
+    @staticmethod
+    def reviews_of(bibcode: str) -> QueryBuilder: ...
+    @staticmethod
+    def similar_to(bibcode: str) -> QueryBuilder: ...
+    @staticmethod
+    def trending(bibcode: str) -> QueryBuilder: ...
+    def build(self) -> str: ...
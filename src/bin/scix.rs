@@ -5,7 +5,11 @@
 #[cfg(feature = "cli")]
 mod cli {
     use clap::{Parser, Subcommand};
-    use scix_client::{ExportFormat, SciXClient, Sort, SortDirection};
+    use scix_client::error::SciXError;
+    use scix_client::rate_limit::RateLimiter;
+    use scix_client::{ExportFormat, SciXClient, Sort};
+    use serde::{Deserialize, Serialize};
+    use std::path::{Path, PathBuf};
 
     #[derive(Parser)]
     #[command(name = "scix", about = "SciX / NASA ADS API client", version)]
@@ -14,18 +18,33 @@ mod cli {
         #[arg(long, global = true)]
         token: Option<String>,
 
-        /// Output format
-        #[arg(long, global = true, default_value = "table")]
-        output: OutputFormat,
+        /// Output format [default: table]
+        #[arg(long, global = true)]
+        output: Option<OutputFormat>,
+
+        /// Log each API request and its response status to stderr
+        #[arg(short, long, global = true, conflicts_with = "quiet")]
+        verbose: bool,
+
+        /// Suppress warnings on stderr
+        #[arg(short, long, global = true)]
+        quiet: bool,
 
         #[command(subcommand)]
         command: Commands,
     }
 
-    #[derive(Clone, Copy, clap::ValueEnum)]
+    #[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
     enum OutputFormat {
         Table,
         Json,
+        /// Markdown table (for pasting into docs)
+        Markdown,
+        /// Comma-separated values (for spreadsheets and scripts)
+        Csv,
+        /// One JSON object per line; `search` streams every page
+        Ndjson,
     }
 
     #[derive(Subcommand)]
@@ -33,49 +52,99 @@ mod cli {
         /// Search the SciX database
         Search {
             /// Search query (SciX/ADS syntax)
-            query: String,
-            /// Maximum results to return
-            #[arg(short, long, default_value = "10")]
-            rows: u32,
+            #[arg(required_unless_present = "query_file")]
+            query: Option<String>,
+            /// Read the query from a file instead (`-` for stdin)
+            #[arg(long, value_name = "PATH", conflicts_with = "query")]
+            query_file: Option<PathBuf>,
+            /// Maximum results to return [default: 10]
+            #[arg(short, long)]
+            rows: Option<u32>,
             /// Sort order (e.g., "date desc", "citation_count desc")
             #[arg(short, long)]
             sort: Option<String>,
             /// Fields to return (comma-separated)
             #[arg(short, long)]
             fields: Option<String>,
+            /// Filter query, e.g. "database:astronomy" (repeatable)
+            #[arg(long = "filter")]
+            filters: Vec<String>,
+        },
+        /// Show detailed metadata for a single paper
+        Get {
+            /// Bibcode (or DOI / arXiv ID)
+            bibcode: String,
+        },
+        /// Print a paper's abstract
+        Abstract {
+            /// Bibcode (or DOI / arXiv ID)
+            bibcode: String,
+        },
+        /// Count papers matching a query
+        Count {
+            /// Search query (SciX/ADS syntax)
+            query: String,
         },
         /// Export papers in citation format
         Export {
-            /// Bibcodes to export
+            /// Bibcodes to export (`-`, or none at all, reads one per line
+            /// from stdin)
             bibcodes: Vec<String>,
+            /// Also read bibcodes from this file, one per line (blank lines
+            /// and `#` comments ignored)
+            #[arg(long, value_name = "PATH")]
+            from_file: Option<PathBuf>,
             /// Export format
             #[arg(short, long, default_value = "bibtex")]
             format: String,
+            /// Write to this file (or into this directory as export.<ext>)
+            /// instead of stdout
+            #[arg(long, value_name = "PATH")]
+            output_file: Option<PathBuf>,
+            /// Append to --output-file chunk by chunk, skipping bibcodes a
+            /// previous interrupted run already exported
+            #[arg(long, requires = "output_file")]
+            resume: bool,
+            /// Write each paper to its own file in this directory, named by
+            /// bibcode (`<DIR>/<bibcode>.bib`)
+            #[arg(long, value_name = "DIR", conflicts_with = "output_file")]
+            per_file: Option<PathBuf>,
         },
         /// Show papers referenced by a paper
         Refs {
             /// Bibcode
             bibcode: String,
-            #[arg(short, long, default_value = "25")]
-            rows: u32,
+            /// Maximum results to return [default: 25]
+            #[arg(short, long)]
+            rows: Option<u32>,
         },
         /// Show papers that cite a paper
         Cites {
             /// Bibcode
             bibcode: String,
-            #[arg(short, long, default_value = "25")]
-            rows: u32,
+            /// Maximum results to return [default: 25]
+            #[arg(short, long)]
+            rows: Option<u32>,
         },
         /// Show papers similar to a paper
         Similar {
             /// Bibcode
             bibcode: String,
-            #[arg(short, long, default_value = "10")]
-            rows: u32,
+            /// Maximum results to return [default: 10]
+            #[arg(short, long)]
+            rows: Option<u32>,
+        },
+        /// Show review papers citing a paper
+        Reviews {
+            /// Bibcode
+            bibcode: String,
+            /// Maximum results to return [default: 25]
+            #[arg(short, long)]
+            rows: Option<u32>,
         },
         /// Get citation metrics for papers
         Metrics {
-            /// Bibcodes
+            /// Bibcodes (`-` reads one per line from stdin)
             bibcodes: Vec<String>,
         },
         /// Resolve free-text references to bibcodes
@@ -101,8 +170,24 @@ mod cli {
             #[command(subcommand)]
             action: LibraryAction,
         },
-        /// Start MCP server (stdio)
-        Serve,
+        /// Start MCP server (stdio, or Streamable HTTP with --http)
+        Serve {
+            /// Listen for MCP over HTTP on this address (e.g. 127.0.0.1:8080).
+            /// Non-loopback addresses require a bearer token in SCIX_MCP_HTTP_TOKEN.
+            #[arg(long, value_name = "ADDR")]
+            http: Option<String>,
+        },
+        /// Show the remaining ADS API quota
+        Quota {
+            /// Make a lightweight request to get fresh numbers (uses one call)
+            #[arg(long)]
+            refresh: bool,
+        },
+        /// Inspect the CLI configuration file
+        Config {
+            #[command(subcommand)]
+            action: ConfigAction,
+        },
         /// Set up the SciX MCP server for your AI editor
         Setup {
             /// Configure only this editor (default: all detected)
@@ -114,9 +199,18 @@ mod cli {
             /// Non-interactive (use env token, configure all detected editors)
             #[arg(long, short = 'y')]
             yes: bool,
+            /// Remove the scix entry from each detected editor instead
+            #[arg(long, conflicts_with = "skip_validation")]
+            remove: bool,
         },
     }
 
+    #[derive(Subcommand)]
+    enum ConfigAction {
+        /// Print the effective configuration (config file merged with flags)
+        Show,
+    }
+
     #[derive(Subcommand)]
     enum LibraryAction {
         /// List all libraries
@@ -195,135 +289,597 @@ mod cli {
         },
     }
 
-    fn make_client(token: Option<String>) -> scix_client::error::Result<SciXClient> {
-        match token {
-            Some(t) => Ok(SciXClient::new(t)),
-            None => SciXClient::from_env(),
+    /// Defaults read from `config.toml`; command-line flags take precedence.
+    ///
+    /// The API token is deliberately not read from here: it belongs in
+    /// `SCIX_API_TOKEN` or the dedicated token file (see
+    /// `SciXClient::from_config`), not in a file of shareable settings.
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Config {
+        output: Option<OutputFormat>,
+        rows: Option<u32>,
+        fields: Option<String>,
+        sort: Option<String>,
+        #[serde(skip_serializing)]
+        token: Option<toml::Value>,
+    }
+
+    impl Config {
+        /// `$XDG_CONFIG_HOME/scix/config.toml`, falling back to `~/.config`.
+        fn default_path() -> Option<PathBuf> {
+            let config = std::env::var_os("XDG_CONFIG_HOME")
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| dirs::home_dir().map(|h| h.join(".config")))?;
+            Some(config.join("scix").join("config.toml"))
+        }
+
+        /// Load the config file; a missing file yields the built-in defaults.
+        fn load(path: &Path) -> scix_client::error::Result<Self> {
+            let text = match std::fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+                Err(e) => {
+                    return Err(SciXError::Config(format!(
+                        "cannot read {}: {}",
+                        path.display(),
+                        e
+                    )))
+                }
+            };
+            toml::from_str(&text)
+                .map_err(|e| SciXError::Config(format!("{}: {}", path.display(), e)))
+        }
+
+        fn output(&self, flag: Option<OutputFormat>) -> OutputFormat {
+            flag.or(self.output).unwrap_or(OutputFormat::Table)
+        }
+
+        fn rows(&self, flag: Option<u32>, default: u32) -> u32 {
+            flag.or(self.rows).unwrap_or(default)
+        }
+
+        fn fields(&self, flag: Option<String>) -> String {
+            flag.or_else(|| self.fields.clone())
+                .unwrap_or_else(|| scix_client::parse::DEFAULT_SEARCH_FIELDS.to_string())
+        }
+
+        fn sort(&self, flag: Option<String>) -> Option<String> {
+            flag.or_else(|| self.sort.clone())
+        }
+
+        /// The configuration after applying global flags, for `config show`.
+        fn effective(&self, output: Option<OutputFormat>) -> Self {
+            Self {
+                output: Some(self.output(output)),
+                rows: Some(self.rows(None, 10)),
+                fields: Some(self.fields(None)),
+                sort: self.sort(None),
+                token: None,
+            }
         }
     }
 
-    fn parse_sort(s: &str) -> Sort {
-        let parts: Vec<&str> = s.split_whitespace().collect();
-        let field = parts.first().copied().unwrap_or("date");
-        let dir = match parts.get(1).copied() {
-            Some("asc") => SortDirection::Asc,
-            _ => SortDirection::Desc,
+    fn make_client(token: Option<String>, verbose: bool) -> scix_client::error::Result<SciXClient> {
+        let mut client = match token {
+            Some(t) => SciXClient::new(t),
+            None => SciXClient::from_config()?,
         };
-        Sort::new(field, dir)
+        if verbose {
+            client = client.with_trace(|line| eprintln!("{}", line));
+        }
+        // Each invocation is a new process; share the server quota between runs.
+        Ok(match RateLimiter::default_state_path() {
+            Some(path) => client.with_rate_limit_state_file(path),
+            None => client,
+        })
     }
 
-    fn print_papers_table(papers: &[scix_client::Paper]) {
-        use comfy_table::{ContentArrangement, Table};
-
-        let mut table = Table::new();
-        table.set_content_arrangement(ContentArrangement::Dynamic);
-        table.set_header(vec!["Bibcode", "Year", "First Author", "Title", "Cites"]);
-
-        for paper in papers {
-            let first_author = paper
-                .authors
-                .first()
-                .map(|a| a.family_name.as_str())
-                .unwrap_or("-");
-            let year = paper.year.map(|y| y.to_string()).unwrap_or_default();
-            let cites = paper
-                .citation_count
-                .map(|c| c.to_string())
-                .unwrap_or_default();
-            let title = if paper.title.len() > 60 {
-                format!("{}...", &paper.title[..57])
-            } else {
-                paper.title.clone()
-            };
+    const PAPER_COLUMNS: [&str; 5] = ["Bibcode", "Year", "First Author", "Title", "Cites"];
+    const PAPER_CSV_COLUMNS: [&str; 5] = ["bibcode", "year", "first_author", "title", "cites"];
 
-            table.add_row(vec![&paper.bibcode, &year, first_author, &title, &cites]);
+    /// Print papers as a terminal table, Markdown table, CSV, or NDJSON.
+    ///
+    /// Titles are only truncated in the terminal table.
+    fn print_papers_table(papers: &[scix_client::Paper], format: OutputFormat) {
+        if format == OutputFormat::Ndjson {
+            let mut out = std::io::stdout().lock();
+            for paper in papers {
+                if let Err(e) = write_ndjson_line(paper, &mut out) {
+                    eprintln!("Error: {}", e);
+                    return;
+                }
+            }
+            return;
         }
+        let rows: Vec<Vec<String>> = papers
+            .iter()
+            .map(|paper| {
+                let first_author = paper
+                    .authors
+                    .first()
+                    .map(|a| a.family_name.clone())
+                    .unwrap_or_else(|| "-".to_string());
+                let year = paper.year.map(|y| y.to_string()).unwrap_or_default();
+                let cites = paper
+                    .citation_count
+                    .map(|c| c.to_string())
+                    .unwrap_or_default();
+                let title = if matches!(format, OutputFormat::Table) && paper.title.len() > 60 {
+                    format!("{}...", &paper.title[..57])
+                } else {
+                    paper.title.clone()
+                };
+                vec![paper.bibcode.clone(), year, first_author, title, cites]
+            })
+            .collect();
 
-        println!("{table}");
+        match format {
+            OutputFormat::Csv => print!("{}", csv_table(&PAPER_CSV_COLUMNS, &rows)),
+            _ => print_rows(&PAPER_COLUMNS, &rows, format),
+        }
+    }
+
+    /// Print rows as a terminal table, Markdown table, CSV, or NDJSON.
+    fn print_rows(headers: &[&str], rows: &[Vec<String>], format: OutputFormat) {
+        match format {
+            OutputFormat::Markdown => print!("{}", markdown_table(headers, rows)),
+            OutputFormat::Csv => print!("{}", csv_table(headers, rows)),
+            OutputFormat::Ndjson => print!("{}", ndjson_rows(headers, rows)),
+            OutputFormat::Table | OutputFormat::Json => {
+                use comfy_table::{ContentArrangement, Table};
+
+                let mut table = Table::new();
+                table.set_content_arrangement(ContentArrangement::Dynamic);
+                table.set_header(headers.to_vec());
+                for row in rows {
+                    table.add_row(row);
+                }
+                println!("{table}");
+            }
+        }
+    }
+
+    /// Render rows as a Markdown table.
+    fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+        let cell = |s: &str| s.replace('|', "\\|").replace(['\r', '\n'], " ");
+        let mut out = format!("| {} |\n", headers.join(" | "));
+        out.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+        for row in rows {
+            let cells: Vec<String> = row.iter().map(|c| cell(c)).collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out
+    }
+
+    /// Render rows as CSV with a header line.
+    fn csv_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+        let mut out = headers
+            .iter()
+            .map(|h| csv_field(h))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push('\n');
+        for row in rows {
+            out.push_str(
+                &row.iter()
+                    .map(|c| csv_field(c))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Render rows as NDJSON objects keyed by lowercased header (keys sorted).
+    fn ndjson_rows(headers: &[&str], rows: &[Vec<String>]) -> String {
+        let mut out = String::new();
+        for row in rows {
+            let object: serde_json::Map<String, serde_json::Value> = headers
+                .iter()
+                .map(|h| h.to_lowercase())
+                .zip(row.iter().map(|c| serde_json::Value::from(c.as_str())))
+                .collect();
+            out.push_str(&serde_json::Value::Object(object).to_string());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Write one paper as a single line of JSON.
+    fn write_ndjson_line(
+        paper: &scix_client::Paper,
+        out: &mut impl std::io::Write,
+    ) -> scix_client::error::Result<()> {
+        serde_json::to_writer(&mut *out, paper)?;
+        out.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Quote a CSV field if it contains a comma, quote, or line break (RFC 4180).
+    fn csv_field(s: &str) -> String {
+        if s.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_string()
+        }
+    }
+
+    /// The bibcodes in a list, one per line, skipping blanks and `#` comments.
+    fn bibcode_lines(text: &str) -> impl Iterator<Item = String> + '_ {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+    }
+
+    /// Bibcodes for `export`: arguments (with `-` expanded), plus the lines
+    /// of `--from-file`. With neither, the list is read from `stdin` unless
+    /// it is a terminal.
+    fn read_bibcodes(
+        args: Vec<String>,
+        from_file: Option<&Path>,
+        stdin: impl std::io::BufRead,
+        stdin_is_terminal: bool,
+    ) -> scix_client::error::Result<Vec<String>> {
+        let args = if args.is_empty() && from_file.is_none() && !stdin_is_terminal {
+            vec!["-".to_string()]
+        } else {
+            args
+        };
+        let mut bibcodes = expand_stdin_args(args, stdin)?;
+        if let Some(path) = from_file {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| SciXError::Config(format!("cannot read {}: {}", path.display(), e)))?;
+            bibcodes.extend(bibcode_lines(&text));
+        }
+        if bibcodes.is_empty() {
+            return Err(SciXError::Config(
+                "no bibcodes given: pass them as arguments, with --from-file, or on stdin"
+                    .to_string(),
+            ));
+        }
+        Ok(bibcodes)
+    }
+
+    /// Replace each `-` argument with the bibcode lines of `input`.
+    ///
+    /// Lets bibcode lists be piped in: `... | scix export -`.
+    fn expand_stdin_args(
+        args: Vec<String>,
+        mut input: impl std::io::BufRead,
+    ) -> scix_client::error::Result<Vec<String>> {
+        let mut expanded = Vec::with_capacity(args.len());
+        for arg in args {
+            if arg != "-" {
+                expanded.push(arg);
+                continue;
+            }
+            let mut text = String::new();
+            input
+                .read_to_string(&mut text)
+                .map_err(|e| SciXError::Config(format!("cannot read stdin: {}", e)))?;
+            expanded.extend(bibcode_lines(&text));
+        }
+        Ok(expanded)
+    }
+
+    /// The search query, from the argument or read from `--query-file`.
+    fn read_query(
+        query: Option<String>,
+        query_file: Option<PathBuf>,
+    ) -> scix_client::error::Result<String> {
+        let Some(path) = query_file else {
+            return Ok(query.unwrap_or_default());
+        };
+        let text = if path.as_os_str() == "-" {
+            std::io::read_to_string(std::io::stdin())
+        } else {
+            std::fs::read_to_string(&path)
+        }
+        .map_err(|e| SciXError::Config(format!("cannot read {}: {}", path.display(), e)))?;
+        let query = text.trim();
+        if query.is_empty() {
+            return Err(SciXError::InvalidQuery(format!(
+                "{} is empty",
+                path.display()
+            )));
+        }
+        Ok(query.to_string())
+    }
+
+    /// Format a Unix timestamp as time remaining from now, e.g. "in 3h 12m".
+    fn format_reset(reset_at: u64) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let secs = reset_at.saturating_sub(now);
+        if secs == 0 {
+            return "now".to_string();
+        }
+        let (hours, minutes) = (secs / 3600, (secs % 3600) / 60);
+        if hours > 0 {
+            format!("in {}h {}m", hours, minutes)
+        } else {
+            format!("in {}m {}s", minutes, secs % 60)
+        }
     }
 
     pub async fn run() -> scix_client::error::Result<()> {
         let cli = Cli::parse();
+        let config_path = Config::default_path();
+        let config = match &config_path {
+            Some(path) => {
+                let config = Config::load(path)?;
+                if config.token.is_some() && !cli.quiet {
+                    eprintln!(
+                        "Warning: ignoring `token` in {}; API tokens are read from \
+                         SCIX_API_TOKEN or ~/.config/scix/token; remove it from this file.",
+                        path.display()
+                    );
+                }
+                config
+            }
+            None => Config::default(),
+        };
+        let output = config.output(cli.output);
 
         // Setup doesn't need a pre-built client.
         if let Commands::Setup {
             editor,
             skip_validation,
             yes,
+            remove,
+        } = cli.command
+        {
+            return scix_client::setup::run_setup(editor, skip_validation, yes, remove).await;
+        }
+
+        if let Commands::Config {
+            action: ConfigAction::Show,
         } = cli.command
         {
-            return scix_client::setup::run_setup(editor, skip_validation, yes).await;
+            let effective = config.effective(cli.output);
+            match output {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&effective)?),
+                _ => {
+                    if let Some(path) = &config_path {
+                        let status = if path.exists() { "" } else { " (not found)" };
+                        println!("# {}{}", path.display(), status);
+                    }
+                    let rendered = toml::to_string(&effective)
+                        .map_err(|e| SciXError::Config(e.to_string()))?;
+                    print!("{}", rendered);
+                }
+            }
+            return Ok(());
         }
 
-        let client = make_client(cli.token)?;
+        let client = make_client(cli.token, cli.verbose)?;
 
         match cli.command {
             Commands::Search {
                 query,
+                query_file,
                 rows,
                 sort,
                 fields,
+                filters,
             } => {
-                let sort_val = sort.as_deref().map(parse_sort);
-                let fields_str = fields
-                    .as_deref()
-                    .unwrap_or(scix_client::parse::DEFAULT_SEARCH_FIELDS);
+                let query = read_query(query, query_file)?;
+                let sort_val: Option<Sort> =
+                    config.sort(sort).as_deref().map(str::parse).transpose()?;
+                let fields_str = config.fields(fields);
+                let rows = config.rows(rows, 10);
+                let filters: Vec<&str> = filters.iter().map(|s| s.as_str()).collect();
+                if output == OutputFormat::Ndjson {
+                    // Page through with a cursor so any number of rows fits in memory.
+                    use futures_util::StreamExt;
+                    let mut papers = std::pin::pin!(client.search_stream(
+                        &query,
+                        &fields_str,
+                        sort_val.as_ref(),
+                        &filters,
+                        rows
+                    ));
+                    let mut out = std::io::stdout().lock();
+                    while let Some(paper) = papers.next().await {
+                        write_ndjson_line(&paper?, &mut out)?;
+                    }
+                    return Ok(());
+                }
                 let results = client
-                    .search_with_options(&query, fields_str, sort_val.as_ref(), rows, 0)
+                    .search_with_options(&query, &fields_str, sort_val.as_ref(), rows, 0, &filters)
                     .await?;
 
-                match cli.output {
+                match output {
                     OutputFormat::Json => {
                         println!("{}", serde_json::to_string_pretty(&results)?);
                     }
-                    OutputFormat::Table => {
-                        println!("Found {} results:", results.num_found);
-                        print_papers_table(&results.papers);
+                    format => {
+                        if matches!(format, OutputFormat::Table) {
+                            println!("Found {} results:", results.num_found);
+                        }
+                        print_papers_table(&results.papers, format);
                     }
                 }
             }
 
-            Commands::Export { bibcodes, format } => {
+            Commands::Get { bibcode } => {
+                let paper = client.get_paper(&bibcode).await?;
+                match output {
+                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&paper)?),
+                    OutputFormat::Ndjson => println!("{}", serde_json::to_string(&paper)?),
+                    _ => print!("{}", scix_client::format::format_paper_detail(&paper)),
+                }
+            }
+
+            Commands::Quota { refresh } => {
+                // The state file holds the quota from earlier runs; without it,
+                // or when asked, spend one cheap request to read the headers.
+                let mut status = client.rate_limit_status().await;
+                if refresh || status.is_none() {
+                    client.count("bibcode:0000").await?;
+                    status = client.rate_limit_status().await;
+                }
+                match (output, status) {
+                    (OutputFormat::Json, status) => {
+                        println!("{}", serde_json::to_string_pretty(&status)?)
+                    }
+                    (_, None) => println!("ADS did not report a rate limit"),
+                    (_, Some(status)) => {
+                        let show = |n: Option<u32>| n.map_or("?".to_string(), |n| n.to_string());
+                        println!(
+                            "Remaining: {} / {}",
+                            show(status.remaining),
+                            show(status.limit)
+                        );
+                        if let Some(used) = status.used() {
+                            println!("Used:      {} today", used);
+                        }
+                        if let Some(reset_at) = status.reset_at {
+                            println!("Resets:    {}", format_reset(reset_at));
+                        }
+                    }
+                }
+            }
+            Commands::Abstract { bibcode } => {
+                let text = client.abstract_of(&bibcode).await?;
+                match (output, text) {
+                    (OutputFormat::Json, text) => println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "bibcode": bibcode,
+                            "abstract": text,
+                        }))?
+                    ),
+                    (_, Some(text)) => println!("{}", text),
+                    (_, None) => eprintln!("No abstract for {}", bibcode),
+                }
+            }
+            Commands::Count { query } => {
+                let count = client.count(&query).await?;
+                match output {
+                    OutputFormat::Json => println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "query": query,
+                            "num_found": count,
+                        }))?
+                    ),
+                    _ => println!("{}", count),
+                }
+            }
+
+            Commands::Export {
+                bibcodes,
+                from_file,
+                format,
+                output_file,
+                resume,
+                per_file,
+            } => {
+                use std::io::IsTerminal;
+
                 let fmt = ExportFormat::from_str_loose(&format).unwrap_or(ExportFormat::BibTeX);
+                let stdin = std::io::stdin();
+                let bibcodes = read_bibcodes(
+                    bibcodes,
+                    from_file.as_deref(),
+                    stdin.lock(),
+                    stdin.is_terminal(),
+                )?;
                 let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-                let output = client.export(&refs, fmt, None).await?;
-                println!("{}", output);
+                if let Some(dir) = per_file {
+                    let written = client.export_per_file(&refs, fmt, &dir).await?;
+                    println!("Wrote {} files to {}", written, dir.display());
+                    return Ok(());
+                }
+                match output_file {
+                    Some(path) if resume => {
+                        let exported = client
+                            .export_to_file_resumable(&refs, fmt, &path, true)
+                            .await?;
+                        println!(
+                            "Exported {} bibcodes ({} already done) to {}",
+                            exported,
+                            refs.len() - exported,
+                            path.display()
+                        );
+                    }
+                    Some(path) => {
+                        let written = client.export_to_file(&refs, fmt, Some(&path)).await?;
+                        println!("Wrote {}", written.display());
+                    }
+                    None => {
+                        // Chunked, so thousands of bibcodes don't need one huge request.
+                        use futures_util::StreamExt;
+                        let mut chunks = std::pin::pin!(client.export_stream(&refs, fmt));
+                        while let Some(chunk) = chunks.next().await {
+                            print!("{}", chunk?);
+                        }
+                        println!();
+                    }
+                }
             }
 
             Commands::Refs { bibcode, rows } => {
-                let results = client.references(&bibcode, rows).await?;
-                match cli.output {
+                let results = client.references(&bibcode, config.rows(rows, 25)).await?;
+                match output {
                     OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
-                    OutputFormat::Table => {
-                        println!("References for {}:", bibcode);
-                        print_papers_table(&results.papers);
+                    format => {
+                        if matches!(format, OutputFormat::Table) {
+                            println!("References for {}:", bibcode);
+                        }
+                        print_papers_table(&results.papers, format);
                     }
                 }
             }
 
             Commands::Cites { bibcode, rows } => {
-                let results = client.citations(&bibcode, rows).await?;
-                match cli.output {
+                let results = client.citations(&bibcode, config.rows(rows, 25)).await?;
+                match output {
                     OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
-                    OutputFormat::Table => {
-                        println!("Citations of {}:", bibcode);
-                        print_papers_table(&results.papers);
+                    format => {
+                        if matches!(format, OutputFormat::Table) {
+                            println!("Citations of {}:", bibcode);
+                        }
+                        print_papers_table(&results.papers, format);
                     }
                 }
             }
 
             Commands::Similar { bibcode, rows } => {
-                let results = client.similar(&bibcode, rows).await?;
-                match cli.output {
+                let results = client.similar(&bibcode, config.rows(rows, 10)).await?;
+                match output {
                     OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
-                    OutputFormat::Table => {
-                        println!("Similar to {}:", bibcode);
-                        print_papers_table(&results.papers);
+                    format => {
+                        if matches!(format, OutputFormat::Table) {
+                            println!("Similar to {}:", bibcode);
+                        }
+                        print_papers_table(&results.papers, format);
+                    }
+                }
+            }
+
+            Commands::Reviews { bibcode, rows } => {
+                let results = client.reviews(&bibcode, config.rows(rows, 25)).await?;
+                match output {
+                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+                    format => {
+                        if matches!(format, OutputFormat::Table) {
+                            println!("Reviews citing {}:", bibcode);
+                        }
+                        print_papers_table(&results.papers, format);
                     }
                 }
             }
 
             Commands::Metrics { bibcodes } => {
+                let bibcodes = expand_stdin_args(bibcodes, std::io::stdin().lock())?;
                 let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
                 let metrics = client.metrics(&refs).await?;
                 println!("{}", serde_json::to_string_pretty(&metrics)?);
@@ -332,7 +888,7 @@ mod cli {
             Commands::Resolve { references } => {
                 let refs: Vec<&str> = references.iter().map(|s| s.as_str()).collect();
                 let resolved = client.resolve_references(&refs).await?;
-                match cli.output {
+                match output {
                     OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&resolved)?),
                     OutputFormat::Table => {
                         for r in &resolved {
@@ -340,6 +896,15 @@ mod cli {
                             println!("{} → {}", r.reference, bib);
                         }
                     }
+                    format => {
+                        let rows: Vec<Vec<String>> = resolved
+                            .iter()
+                            .map(|r| {
+                                vec![r.reference.clone(), r.bibcode.clone().unwrap_or_default()]
+                            })
+                            .collect();
+                        print_rows(&["reference", "bibcode"], &rows, format);
+                    }
                 }
             }
 
@@ -357,24 +922,23 @@ mod cli {
             Commands::Libraries { action } => match action {
                 LibraryAction::List => {
                     let libs = client.list_libraries().await?;
-                    match cli.output {
+                    match output {
                         OutputFormat::Json => {
                             println!("{}", serde_json::to_string_pretty(&libs)?);
                         }
-                        OutputFormat::Table => {
-                            use comfy_table::{ContentArrangement, Table};
-                            let mut table = Table::new();
-                            table.set_content_arrangement(ContentArrangement::Dynamic);
-                            table.set_header(vec!["ID", "Name", "Documents", "Public"]);
-                            for lib in &libs {
-                                table.add_row(vec![
-                                    &lib.id,
-                                    &lib.name,
-                                    &lib.num_documents.to_string(),
-                                    &lib.public.to_string(),
-                                ]);
-                            }
-                            println!("{table}");
+                        format => {
+                            let rows: Vec<Vec<String>> = libs
+                                .iter()
+                                .map(|lib| {
+                                    vec![
+                                        lib.id.clone(),
+                                        lib.name.clone(),
+                                        lib.num_documents.to_string(),
+                                        lib.public.to_string(),
+                                    ]
+                                })
+                                .collect();
+                            print_rows(&["ID", "Name", "Documents", "Public"], &rows, format);
                         }
                     }
                 }
@@ -455,15 +1019,246 @@ mod cli {
                 }
             },
 
-            Commands::Serve => {
-                scix_client::mcp::run_server(client).await?;
-            }
+            Commands::Serve { http } => match http {
+                Some(addr) => {
+                    let token = std::env::var("SCIX_MCP_HTTP_TOKEN")
+                        .ok()
+                        .filter(|t| !t.is_empty());
+                    let server = scix_client::mcp::http::HttpServer::bind(&addr, token).await?;
+                    eprintln!(
+                        "SciX MCP server listening on http://{}{}",
+                        server.local_addr()?,
+                        scix_client::mcp::http::ENDPOINT
+                    );
+                    server.run(client).await?
+                }
+                None => scix_client::mcp::run_server(client).await?,
+            },
 
-            Commands::Setup { .. } => unreachable!(),
+            Commands::Setup { .. } | Commands::Config { .. } => unreachable!(),
         }
 
         Ok(())
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn config(rows: Option<u32>, sort: Option<&str>) -> Config {
+            Config {
+                rows,
+                sort: sort.map(String::from),
+                ..Config::default()
+            }
+        }
+
+        #[test]
+        fn test_expand_stdin_args_reads_bibcodes() {
+            let stdin = std::io::Cursor::new("2016PhRvL.116f1102A\n\n  1998AJ....116.1009R \n");
+            let args = vec!["2023ApJ...123..456A".to_string(), "-".to_string()];
+            assert_eq!(
+                expand_stdin_args(args, stdin).unwrap(),
+                [
+                    "2023ApJ...123..456A",
+                    "2016PhRvL.116f1102A",
+                    "1998AJ....116.1009R"
+                ]
+            );
+        }
+
+        #[test]
+        fn test_read_bibcodes_from_piped_stdin() {
+            let stdin = std::io::Cursor::new(
+                "# from an earlier query\n2016PhRvL.116f1102A\n\n1998AJ....116.1009R\n",
+            );
+            assert_eq!(
+                read_bibcodes(vec![], None, stdin, false).unwrap(),
+                ["2016PhRvL.116f1102A", "1998AJ....116.1009R"]
+            );
+        }
+
+        #[test]
+        fn test_read_bibcodes_from_file_skips_stdin() {
+            let path =
+                std::env::temp_dir().join(format!("scix-bibcodes-{}.txt", std::process::id()));
+            std::fs::write(
+                &path,
+                "2016PhRvL.116f1102A\n  # retracted\n1998AJ....116.1009R\n",
+            )
+            .unwrap();
+            let stdin = std::io::Cursor::new("2099Nope.....1....1X\n");
+            let bibcodes = read_bibcodes(
+                vec!["2023ApJ...123..456A".to_string()],
+                Some(&path),
+                stdin,
+                false,
+            )
+            .unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(
+                bibcodes,
+                [
+                    "2023ApJ...123..456A",
+                    "2016PhRvL.116f1102A",
+                    "1998AJ....116.1009R"
+                ]
+            );
+        }
+
+        #[test]
+        fn test_read_bibcodes_terminal_without_args_is_an_error() {
+            let stdin = std::io::Cursor::new("2016PhRvL.116f1102A\n");
+            assert!(matches!(
+                read_bibcodes(vec![], None, stdin, true),
+                Err(SciXError::Config(_))
+            ));
+        }
+
+        #[test]
+        fn test_expand_stdin_args_leaves_plain_args() {
+            let stdin = std::io::Cursor::new("ignored\n");
+            let args = vec!["2016PhRvL.116f1102A".to_string()];
+            assert_eq!(
+                expand_stdin_args(args, stdin).unwrap(),
+                ["2016PhRvL.116f1102A"]
+            );
+        }
+
+        #[test]
+        fn test_read_query_from_file() {
+            let path = std::env::temp_dir().join(format!("scix-query-{}.txt", std::process::id()));
+            std::fs::write(&path, "author:\"Einstein\"\n  year:1905\n").unwrap();
+            let query = read_query(None, Some(path.clone())).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(query, "author:\"Einstein\"\n  year:1905");
+        }
+
+        #[test]
+        fn test_config_flag_overrides_config() {
+            let config = config(Some(50), Some("date desc"));
+            assert_eq!(config.rows(Some(5), 10), 5);
+            assert_eq!(
+                config.sort(Some("citation_count desc".into())).as_deref(),
+                Some("citation_count desc")
+            );
+        }
+
+        #[test]
+        fn test_config_overrides_builtin_default() {
+            let config = config(Some(50), Some("date desc"));
+            assert_eq!(config.rows(None, 10), 50);
+            assert_eq!(config.sort(None).as_deref(), Some("date desc"));
+        }
+
+        #[test]
+        fn test_config_builtin_defaults() {
+            let config = Config::default();
+            assert_eq!(config.rows(None, 25), 25);
+            assert_eq!(config.sort(None), None);
+            assert_eq!(config.output(None), OutputFormat::Table);
+            assert_eq!(
+                config.fields(None),
+                scix_client::parse::DEFAULT_SEARCH_FIELDS
+            );
+        }
+
+        #[test]
+        fn test_config_parse_toml() {
+            let config: Config = toml::from_str(
+                "output = \"csv\"\nrows = 20\nfields = \"bibcode,title\"\nsort = \"date asc\"\n",
+            )
+            .unwrap();
+            assert_eq!(config.output(None), OutputFormat::Csv);
+            assert_eq!(config.output(Some(OutputFormat::Json)), OutputFormat::Json);
+            assert_eq!(config.rows(None, 10), 20);
+            assert_eq!(config.fields(None), "bibcode,title");
+            assert!(toml::from_str::<Config>("rowz = 3").is_err());
+        }
+
+        #[test]
+        fn test_config_missing_file_is_default() {
+            let config = Config::load(Path::new("/nonexistent/scix/config.toml")).unwrap();
+            assert!(config.rows.is_none() && config.output.is_none());
+        }
+
+        #[test]
+        fn test_csv_field_plain() {
+            assert_eq!(csv_field("2016PhRvL.116f1102A"), "2016PhRvL.116f1102A");
+            assert_eq!(csv_field(""), "");
+        }
+
+        #[test]
+        fn test_csv_field_comma() {
+            assert_eq!(
+                csv_field("Stars, Gas, and Dust"),
+                "\"Stars, Gas, and Dust\""
+            );
+        }
+
+        #[test]
+        fn test_csv_field_quotes_doubled() {
+            assert_eq!(
+                csv_field("The \"Hubble Tension\" Revisited"),
+                "\"The \"\"Hubble Tension\"\" Revisited\""
+            );
+        }
+
+        #[test]
+        fn test_csv_field_newlines() {
+            assert_eq!(csv_field("Line one\nline two"), "\"Line one\nline two\"");
+            assert_eq!(csv_field("CR\r"), "\"CR\r\"");
+        }
+
+        #[test]
+        fn test_csv_table_header_and_rows() {
+            let rows = vec![vec!["2020X".to_string(), "A, B".to_string()]];
+            assert_eq!(
+                csv_table(&["bibcode", "title"], &rows),
+                "bibcode,title\n2020X,\"A, B\"\n"
+            );
+        }
+
+        #[test]
+        fn test_ndjson_lines_are_standalone_objects() {
+            let body = r#"{"response": {"numFound": 2, "start": 0, "docs": [
+                {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914:\nfirst detection"]},
+                {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]}}"#;
+            let results = scix_client::parse::parse_search_response(body).unwrap();
+            let mut out = Vec::new();
+            for paper in &results.papers {
+                write_ndjson_line(paper, &mut out).unwrap();
+            }
+
+            let text = String::from_utf8(out).unwrap();
+            let lines: Vec<&str> = text.lines().collect();
+            assert_eq!(lines.len(), 2);
+            for (line, paper) in lines.iter().zip(&results.papers) {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                assert!(value.is_object());
+                assert_eq!(value["bibcode"], paper.bibcode.as_str());
+            }
+        }
+
+        #[test]
+        fn test_ndjson_rows_keyed_by_header() {
+            let rows = vec![vec!["ref".to_string(), "2020X".to_string()]];
+            assert_eq!(
+                ndjson_rows(&["Reference", "Bibcode"], &rows),
+                "{\"bibcode\":\"2020X\",\"reference\":\"ref\"}\n"
+            );
+        }
+
+        #[test]
+        fn test_markdown_table_escapes_pipes() {
+            let rows = vec![vec!["2020X".to_string(), "A | B\nC".to_string()]];
+            let out = markdown_table(&["Bibcode", "Title"], &rows);
+            assert_eq!(
+                out,
+                "| Bibcode | Title |\n|---|---|\n| 2020X | A \\| B C |\n"
+            );
+        }
+    }
 }
 
 #[cfg(feature = "cli")]
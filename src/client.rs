@@ -1,9 +1,17 @@
 //! The SciX / NASA ADS API client.
 
 use crate::error::{Result, SciXError};
+use crate::parse::json_error_message;
 use crate::rate_limit::RateLimiter;
+use crate::types::RateLimitStatus;
 use reqwest::Client;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// `User-Agent` sent with every request, tracking the crate version.
+pub const DEFAULT_USER_AGENT: &str = concat!("scix-client/", env!("CARGO_PKG_VERSION"));
 
 /// Async client for the SciX (NASA ADS) API.
 ///
@@ -25,155 +33,486 @@ pub struct SciXClient {
     pub(crate) api_token: String,
     pub(crate) base_url: String,
     pub(crate) rate_limiter: RateLimiter,
+    pub(crate) dry_run: bool,
+    pub(crate) canonicalize_bibcodes: bool,
+    pub(crate) user_agent: String,
+    pub(crate) compression: bool,
+    pub(crate) strict_parsing: bool,
+    pub(crate) concurrency: Option<Arc<Semaphore>>,
+    pub(crate) trace: Option<TraceHook>,
 }
 
+/// Callback receiving one line per request; see [`SciXClient::with_trace`].
+pub(crate) type TraceHook = Arc<dyn Fn(&str) + Send + Sync>;
+
 impl SciXClient {
     /// Create a new client with the given API token.
     pub fn new(api_token: impl Into<String>) -> Self {
-        let http = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("Failed to create HTTP client");
-
         Self {
-            http,
+            http: http_client(true),
             api_token: api_token.into(),
             base_url: "https://api.adsabs.harvard.edu/v1".to_string(),
             rate_limiter: RateLimiter::new(5.0),
+            dry_run: false,
+            canonicalize_bibcodes: false,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            compression: true,
+            strict_parsing: false,
+            concurrency: None,
+            trace: None,
         }
     }
 
+    /// Create a client with a caller-supplied HTTP client and base URL.
+    ///
+    /// Intended for tests against a local mock server (e.g. `wiremock`):
+    /// the injected `reqwest::Client` can carry its own timeouts or proxy
+    /// settings, and no environment variables are needed. Combine with
+    /// `with_rate_limit(f64::INFINITY)` to turn off client-side throttling.
+    ///
+    /// ```
+    /// let client = scix_client::SciXClient::new_with_client(
+    ///     "test-token",
+    ///     reqwest::Client::new(),
+    ///     "http://127.0.0.1:8080",
+    /// )
+    /// .with_rate_limit(f64::INFINITY);
+    /// ```
+    pub fn new_with_client(
+        api_token: impl Into<String>,
+        http: Client,
+        base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            http,
+            ..Self::new(api_token)
+        }
+        .with_base_url(base_url)
+    }
+
     /// Create a client from the `SCIX_API_TOKEN` (or `ADS_API_TOKEN`) environment variable.
     pub fn from_env() -> Result<Self> {
-        let token = std::env::var("SCIX_API_TOKEN")
-            .or_else(|_| std::env::var("ADS_API_TOKEN"))
-            .map_err(|_| SciXError::AuthRequired)?;
-        if token.is_empty() {
-            return Err(SciXError::AuthRequired);
-        }
-        Ok(Self::new(token))
+        env_token().map(Self::new).ok_or(SciXError::AuthRequired)
+    }
+
+    /// Create a client from the first API token found in, in order:
+    ///
+    /// 1. the `SCIX_API_TOKEN` or `ADS_API_TOKEN` environment variable;
+    /// 2. the token file at [`token_file_path`](Self::token_file_path),
+    ///    `~/.config/scix/token` by default;
+    /// 3. with the `keyring` feature, the OS keyring entry for service
+    ///    `scix`, user `api-token`.
+    ///
+    /// Fails with [`SciXError::AuthRequired`] if none has a token, or with
+    /// [`SciXError::Io`] if the token file exists but can't be read.
+    pub fn from_config() -> Result<Self> {
+        let token = match env_token() {
+            Some(token) => Some(token),
+            None => match Self::token_file_path() {
+                Some(path) => read_token_file(&path)?,
+                None => None,
+            },
+        };
+        token
+            .or_else(keyring_token)
+            .map(Self::new)
+            .ok_or(SciXError::AuthRequired)
+    }
+
+    /// Token file read by [`from_config`](Self::from_config):
+    /// `$XDG_CONFIG_HOME/scix/token`, falling back to `~/.config/scix/token`.
+    pub fn token_file_path() -> Option<PathBuf> {
+        let config = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+        Some(config.join("scix").join("token"))
     }
 
     /// Override the base URL (useful for testing).
+    ///
+    /// Trailing slashes are dropped, since request paths start with one.
     pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
-        self.base_url = url.into();
+        self.base_url = normalize_base_url(url.into());
         self
     }
 
+    /// Override the base URL, rejecting anything that isn't an http(s) URL.
+    ///
+    /// Prefer this over [`with_base_url`](Self::with_base_url) when the URL
+    /// comes from configuration, so a typo fails here with
+    /// [`SciXError::Config`] rather than as a connection error later.
+    pub fn try_with_base_url(mut self, url: impl Into<String>) -> Result<Self> {
+        self.base_url = validate_base_url(url.into())?;
+        Ok(self)
+    }
+
     /// Override the rate limit (requests per second).
     pub fn with_rate_limit(mut self, per_second: f64) -> Self {
         self.rate_limiter = RateLimiter::new(per_second);
         self
     }
 
+    /// Override the rate limit, allowing `burst` requests back to back
+    /// before throttling to `per_second`.
+    ///
+    /// Interactive workloads that fire a handful of requests at once (a
+    /// search followed by metrics and an export, say) finish sooner, while
+    /// the sustained rate stays the same.
+    pub fn with_rate_limit_burst(mut self, per_second: f64, burst: u32) -> Self {
+        self.rate_limiter = RateLimiter::with_burst(per_second, burst);
+        self
+    }
+
+    /// Use `limiter` for this client, sharing it with every other client
+    /// given a clone of it.
+    ///
+    /// ADS quotas are per token, not per client, so independently built
+    /// clients using one token (a pool, say) should draw from one bucket:
+    ///
+    /// ```
+    /// use scix_client::{rate_limit::RateLimiter, SciXClient};
+    ///
+    /// let limiter = RateLimiter::with_burst(5.0, 5);
+    /// let search = SciXClient::new("token").with_shared_rate_limiter(limiter.clone());
+    /// let export = SciXClient::new("token").with_shared_rate_limiter(limiter);
+    /// ```
+    ///
+    /// Clones of a client already share its limiter.
+    pub fn with_shared_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// Persist the server-reported rate limit quota to a state file.
+    ///
+    /// Lets separate processes (e.g. successive CLI invocations) share what
+    /// they know about the remaining quota. Call after [`Self::with_rate_limit`]
+    /// or [`Self::with_rate_limit_burst`], which replace the limiter. See
+    /// [`RateLimiter::default_state_path`].
+    ///
+    /// The limiter is shared with clones of this client and with clients
+    /// given it through [`Self::with_shared_rate_limiter`], so they persist
+    /// to the same file.
+    pub fn with_rate_limit_state_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.rate_limiter = self.rate_limiter.with_state_file(path);
+        self
+    }
+
+    /// Describe requests instead of sending them.
+    ///
+    /// In dry-run mode no request reaches ADS. Each request returns, as its
+    /// response body, a JSON description of what would have been sent:
+    ///
+    /// ```json
+    /// {"method": "GET", "url": "https://.../search/query?q=...", "query": [["q", "..."]],
+    ///  "headers": {"Authorization": "Bearer <redacted>", "User-Agent": "scix-client/..."},
+    ///  "body": null}
+    /// ```
+    ///
+    /// Methods returning raw JSON (e.g. [`Self::author_network`]) hand this
+    /// description back directly; methods that parse a typed response fail
+    /// with [`SciXError::Parse`]. The API token itself is never included.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Report every request to `hook` once it completes.
+    ///
+    /// Each line has the method, full URL with query parameters, and the
+    /// response status (or transport error) with the elapsed time, e.g.
+    /// `GET https://api.adsabs.harvard.edu/v1/search/query?q=x -> 200 (85 ms)`.
+    /// Headers are not included, so the API token never appears. HTML error
+    /// pages, which errors only summarize, are passed to the hook in full.
+    /// The CLI's `--verbose` flag prints these lines to stderr.
+    pub fn with_trace(mut self, hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.trace = Some(Arc::new(hook));
+        self
+    }
+
+    /// Canonicalize bibcodes before library add/remove and export.
+    ///
+    /// Stale bibcodes (ones ADS has since replaced) are mapped to the
+    /// current ones with [`Self::canonicalize`], at the cost of one extra
+    /// search per call for every 100 bibcodes. Bibcodes ADS doesn't
+    /// recognize are passed through.
+    pub fn with_canonical_bibcodes(mut self, enabled: bool) -> Self {
+        self.canonicalize_bibcodes = enabled;
+        self
+    }
+
+    /// Fail on search responses the default lenient parsing would accept.
+    ///
+    /// Search results are parsed with
+    /// [`parse_search_response_strict`](crate::parse::parse_search_response_strict):
+    /// unknown document fields, untitled documents, and a missing
+    /// `numFound` become [`SciXError::Parse`] instead of being ignored,
+    /// dropped, or estimated. For tests and schema-drift monitoring.
+    pub fn with_strict_parsing(mut self, strict: bool) -> Self {
+        self.strict_parsing = strict;
+        self
+    }
+
+    /// Identify the calling application to ADS.
+    ///
+    /// `product` is prepended to the default agent, e.g.
+    /// `with_user_agent("my-tool/1.2")` sends `my-tool/1.2 scix-client/0.3.1`.
+    pub fn with_user_agent(mut self, product: &str) -> Self {
+        self.user_agent = format!("{} {}", product, DEFAULT_USER_AGENT);
+        self
+    }
+
+    /// Ask for gzip/deflate-compressed responses (the default).
+    ///
+    /// Compressed responses are decoded transparently and make large
+    /// searches and exports much smaller on the wire. Turn this off to see
+    /// the raw bytes when debugging with a proxy. Replaces the HTTP client,
+    /// including one passed to [`Self::new_with_client`].
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self.http = http_client(enabled);
+        self
+    }
+
+    /// The ADS quota reported by the most recent response, if any.
+    ///
+    /// Reflects the `X-RateLimit-*` headers of the last response that had
+    /// them (or the state file, see [`Self::with_rate_limit_state_file`]).
+    /// `None` until the first such response.
+    pub async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limiter.status().await
+    }
+
+    /// Cap the number of requests in flight at once.
+    ///
+    /// The rate limiter spaces requests out in time but doesn't bound how
+    /// many are outstanding, so many concurrently spawned calls can pile up
+    /// on ADS. With a cap, each request waits for one of `max` slots, shared
+    /// by all clones of this client. A cap of 0 is treated as 1.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.concurrency = Some(Arc::new(Semaphore::new(max.max(1))));
+        self
+    }
+
+    /// Wait for a concurrency slot, if a cap is set. Held until the response is read.
+    async fn concurrency_permit(&self) -> Option<SemaphorePermit<'_>> {
+        match &self.concurrency {
+            // The semaphore is never closed, so acquiring can't fail.
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Serialize a request for dry-run mode.
+    fn describe_request(
+        &self,
+        method: &str,
+        path: &str,
+        params: &[(&str, &str)],
+        body: serde_json::Value,
+    ) -> Result<String> {
+        let base = format!("{}{}", self.base_url, path);
+        let url = if params.is_empty() {
+            base
+        } else {
+            url::Url::parse_with_params(&base, params)
+                .map(String::from)
+                .map_err(|e| SciXError::Config(format!("Invalid URL {}: {}", base, e)))?
+        };
+        let mut headers = serde_json::json!({ "User-Agent": self.user_agent });
+        if !self.api_token.is_empty() {
+            headers["Authorization"] = "Bearer <redacted>".into();
+        }
+        let description = serde_json::json!({
+            "method": method,
+            "url": url,
+            "query": params,
+            "headers": headers,
+            "body": body,
+        });
+        Ok(serde_json::to_string_pretty(&description)?)
+    }
+
+    /// GET an ADS endpoint this crate doesn't wrap, returning the raw body.
+    ///
+    /// `path` is relative to the base URL (e.g. `/search/bigquery`). The
+    /// request is authenticated, rate limited, traced, and honours dry-run
+    /// mode like every other call. This is an unstable escape hatch: prefer
+    /// the typed methods, which may replace uses of it in later releases.
+    pub async fn raw_get(&self, path: &str, params: &[(&str, &str)]) -> Result<String> {
+        self.get(path, params).await
+    }
+
+    /// POST a JSON body to an ADS endpoint this crate doesn't wrap, returning
+    /// the raw body.
+    ///
+    /// Like [`Self::raw_get`], an unstable escape hatch that still goes
+    /// through authentication and rate limiting.
+    pub async fn raw_post(&self, path: &str, body: &serde_json::Value) -> Result<String> {
+        self.post_json(path, body).await
+    }
+
     /// Make an authenticated GET request to the SciX API.
     pub(crate) async fn get(&self, path: &str, params: &[(&str, &str)]) -> Result<String> {
-        self.rate_limiter.acquire().await;
-
+        if self.dry_run {
+            return self.describe_request("GET", path, params, serde_json::Value::Null);
+        }
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .header("User-Agent", "scix-client/0.1.0")
-            .query(params)
-            .send()
-            .await?;
-
-        self.rate_limiter
-            .update_from_headers(response.headers())
-            .await;
-        handle_response(response).await
+        self.send(self.http.get(&url).query(params)).await
     }
 
     /// Make an authenticated POST request with a JSON body.
     pub(crate) async fn post_json(&self, path: &str, body: &serde_json::Value) -> Result<String> {
-        self.rate_limiter.acquire().await;
-
+        if self.dry_run {
+            return self.describe_request("POST", path, &[], body.clone());
+        }
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .header("User-Agent", "scix-client/0.1.0")
-            .json(body)
-            .send()
-            .await?;
-
-        self.rate_limiter
-            .update_from_headers(response.headers())
-            .await;
-        handle_response(response).await
+        self.send(self.http.post(&url).json(body)).await
     }
 
-    /// Make an authenticated POST request with a text body.
+    /// Make an authenticated POST request with a text body and URL query parameters.
     pub(crate) async fn post_text(
         &self,
         path: &str,
+        params: &[(&str, &str)],
         content_type: &str,
         body: &str,
     ) -> Result<String> {
-        self.rate_limiter.acquire().await;
-
+        if self.dry_run {
+            let body = serde_json::json!({ "content_type": content_type, "text": body });
+            return self.describe_request("POST", path, params, body);
+        }
         let url = format!("{}{}", self.base_url, path);
-        let response = self
+        let request = self
             .http
             .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .header("User-Agent", "scix-client/0.1.0")
             .header("Content-Type", content_type)
-            .body(body.to_string())
-            .send()
-            .await?;
-
-        self.rate_limiter
-            .update_from_headers(response.headers())
-            .await;
-        handle_response(response).await
+            .query(params)
+            .body(body.to_string());
+        self.send(request).await
     }
 
     /// Make an authenticated PUT request with a JSON body.
     pub(crate) async fn put_json(&self, path: &str, body: &serde_json::Value) -> Result<String> {
-        self.rate_limiter.acquire().await;
-
+        if self.dry_run {
+            return self.describe_request("PUT", path, &[], body.clone());
+        }
         let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .put(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .header("User-Agent", "scix-client/0.1.0")
-            .json(body)
-            .send()
-            .await?;
-
-        self.rate_limiter
-            .update_from_headers(response.headers())
-            .await;
-        handle_response(response).await
+        self.send(self.http.put(&url).json(body)).await
     }
 
     /// Make an authenticated DELETE request.
     pub(crate) async fn delete(&self, path: &str) -> Result<String> {
+        if self.dry_run {
+            return self.describe_request("DELETE", path, &[], serde_json::Value::Null);
+        }
+        let url = format!("{}{}", self.base_url, path);
+        self.send(self.http.delete(&url)).await
+    }
+
+    /// Authenticate and send a request, respecting the concurrency cap and
+    /// rate limit, and report it to the trace hook if one is set.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String> {
+        let _permit = self.concurrency_permit().await;
         self.rate_limiter.acquire().await;
 
-        let url = format!("{}{}", self.base_url, path);
-        let response = self
-            .http
-            .delete(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .header("User-Agent", "scix-client/0.1.0")
-            .send()
-            .await?;
+        let mut request = request.header("User-Agent", &self.user_agent);
+        if !self.api_token.is_empty() {
+            request = request.header("Authorization", format!("Bearer {}", self.api_token));
+        }
+        let request = request.build()?;
+        let line = format!("{} {}", request.method(), request.url());
+        let started = std::time::Instant::now();
+        let response = self.http.execute(request).await;
+
+        if let Some(trace) = &self.trace {
+            let elapsed = started.elapsed().as_millis();
+            match &response {
+                Ok(r) => trace(&format!(
+                    "{} -> {} ({} ms)",
+                    line,
+                    r.status().as_u16(),
+                    elapsed
+                )),
+                Err(e) => trace(&format!("{} -> error: {} ({} ms)", line, e, elapsed)),
+            }
+        }
 
+        let response = response?;
         self.rate_limiter
             .update_from_headers(response.headers())
             .await;
-        handle_response(response).await
+        handle_response(response, self.trace.as_ref()).await
     }
 }
 
+/// Strip trailing slashes so `{base}{path}` never doubles the separator.
+pub(crate) fn normalize_base_url(url: String) -> String {
+    url.trim_end_matches('/').to_string()
+}
+
+/// Check that a base URL parses and uses http(s), then normalize it.
+pub(crate) fn validate_base_url(url: String) -> Result<String> {
+    let parsed = url::Url::parse(&url)
+        .map_err(|e| SciXError::Config(format!("Invalid base URL {:?}: {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(SciXError::Config(format!(
+            "Invalid base URL {:?}: scheme must be http or https",
+            url
+        )));
+    }
+    Ok(normalize_base_url(url))
+}
+
+/// Build the HTTP client used for API requests.
+///
+/// With `compression`, requests send `Accept-Encoding: gzip, deflate` and
+/// compressed responses are decoded.
+pub(crate) fn http_client(compression: bool) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .gzip(compression)
+        .deflate(compression)
+        .build()
+        .expect("Failed to create HTTP client")
+}
+
+/// The first non-empty `SCIX_API_TOKEN` or `ADS_API_TOKEN`.
+fn env_token() -> Option<String> {
+    ["SCIX_API_TOKEN", "ADS_API_TOKEN"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|token| !token.is_empty())
+}
+
+/// The token stored in `path`, trimmed; `None` if the file is missing or blank.
+fn read_token_file(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text.trim().to_string()).filter(|t| !t.is_empty())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_token() -> Option<String> {
+    keyring::Entry::new("scix", "api-token")
+        .and_then(|entry| entry.get_password())
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+#[cfg(not(feature = "keyring"))]
+fn keyring_token() -> Option<String> {
+    None
+}
+
 /// Handle the HTTP response, mapping status codes to errors.
-async fn handle_response(response: reqwest::Response) -> Result<String> {
+///
+/// HTML error pages (from gateways in front of ADS) are summarized rather
+/// than copied into the error; the full page goes to the trace hook, if set.
+async fn handle_response(response: reqwest::Response, trace: Option<&TraceHook>) -> Result<String> {
     let status = response.status().as_u16();
 
     match status {
@@ -190,11 +529,432 @@ async fn handle_response(response: reqwest::Response) -> Result<String> {
             Err(SciXError::RateLimited { retry_after })
         }
         _ => {
+            let html = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| v.contains("html"));
             let body = response.text().await.unwrap_or_default();
+            if !(html || body.trim_start().starts_with('<')) {
+                let error_detail = serde_json::from_str::<serde_json::Value>(&body).ok();
+                let message = error_detail
+                    .as_ref()
+                    .and_then(json_error_message)
+                    .unwrap_or(body);
+                return Err(SciXError::Api {
+                    status,
+                    message,
+                    error_detail,
+                });
+            }
+            if let Some(trace) = trace {
+                trace(&format!("HTTP {} error page: {}", status, body));
+            }
             Err(SciXError::Api {
                 status,
-                message: body,
+                message: summarize_html_error(status, &body),
+                error_detail: None,
             })
         }
     }
 }
+
+/// One-line description of an HTML error page, with its `<title>` if short.
+fn summarize_html_error(status: u16, body: &str) -> String {
+    let mut message = format!("ADS returned an HTML error page (HTTP {})", status);
+    let lower = body.to_ascii_lowercase();
+    let title = lower
+        .find("<title>")
+        .map(|start| start + "<title>".len())
+        .and_then(|start| Some(start..start + lower[start..].find("</title>")?))
+        .map(|range| body[range].split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|title| !title.is_empty() && title.chars().count() <= 100);
+    if let Some(title) = title {
+        message.push_str(": ");
+        message.push_str(&title);
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    #[test]
+    fn test_read_token_file() {
+        let dir = std::env::temp_dir().join(format!("scix-token-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("scix")).unwrap();
+        let path = dir.join("scix").join("token");
+
+        assert_eq!(read_token_file(&path).unwrap(), None);
+
+        std::fs::write(&path, "  abc123\n").unwrap();
+        assert_eq!(read_token_file(&path).unwrap().as_deref(), Some("abc123"));
+
+        std::fs::write(&path, "\n").unwrap();
+        assert_eq!(read_token_file(&path).unwrap(), None);
+
+        // A directory where the file should be is an error, not "no token".
+        std::fs::remove_file(&path).unwrap();
+        std::fs::create_dir(&path).unwrap();
+        assert!(matches!(read_token_file(&path), Err(SciXError::Io(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn dry_run_client() -> SciXClient {
+        SciXClient::new("secret-token")
+            .with_base_url("https://api.example.org/v1")
+            .with_dry_run(true)
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_search_request() {
+        let client = dry_run_client();
+        let params = [("q", "author:\"Einstein\" year:1905"), ("rows", "5")];
+        let out = client.get("/search/query", &params).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+        assert_eq!(described["method"], "GET");
+        assert_eq!(
+            described["url"],
+            "https://api.example.org/v1/search/query?q=author%3A%22Einstein%22+year%3A1905&rows=5"
+        );
+        assert_eq!(described["query"][0][1], "author:\"Einstein\" year:1905");
+        assert!(described["body"].is_null());
+        assert!(!out.contains("secret-token"));
+    }
+
+    #[test]
+    fn test_try_with_base_url_accepts_http_urls() {
+        let client = SciXClient::new("t")
+            .try_with_base_url("http://127.0.0.1:8080/v1/")
+            .unwrap();
+        assert_eq!(client.base_url, "http://127.0.0.1:8080/v1");
+    }
+
+    #[test]
+    fn test_try_with_base_url_rejects_invalid_urls() {
+        for bad in ["api.adsabs.harvard.edu/v1", "ftp://example.org", "https://"] {
+            let err = SciXClient::new("t").try_with_base_url(bad).err();
+            assert!(
+                matches!(err, Some(SciXError::Config(_))),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shared_rate_limiter_throttles_clients_together() {
+        let mock = crate::mock::MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 0, "docs": []}}"#,
+            )
+            .start()
+            .await;
+        let limiter = RateLimiter::new(20.0); // one request per 50ms
+        let a = mock.client().with_shared_rate_limiter(limiter.clone());
+        let b = mock.client().with_shared_rate_limiter(limiter);
+
+        let start = std::time::Instant::now();
+        for _ in 0..2 {
+            a.get("/search/query", &[]).await.unwrap();
+            b.get("/search/query", &[]).await.unwrap();
+        }
+        // Four requests through one bucket: three 50ms waits. Separate
+        // limiters would allow this in about 50ms.
+        assert!(start.elapsed() >= Duration::from_millis(140));
+    }
+
+    #[tokio::test]
+    async fn test_trace_reports_each_request() {
+        let mock = crate::mock::MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 7, "docs": []}}"#,
+            )
+            .route_status("DELETE", "/biblib/documents/abc", 404, "")
+            .start()
+            .await;
+        let lines = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = lines.clone();
+        let client = mock
+            .client()
+            .with_trace(move |line| sink.lock().unwrap().push(line.to_string()));
+
+        client.get("/search/query", &[("q", "x y")]).await.unwrap();
+        assert!(client.delete("/biblib/documents/abc").await.is_err());
+
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("GET http://127.0.0.1:"));
+        assert!(lines[0].contains("/search/query?q=x+y -> 200 ("));
+        assert!(
+            lines[1].starts_with("DELETE ") && lines[1].contains("/biblib/documents/abc -> 404")
+        );
+        assert!(!lines.iter().any(|l| l.contains("Bearer")));
+    }
+
+    #[tokio::test]
+    async fn test_base_url_trailing_slash_is_normalized() {
+        let mut urls = Vec::new();
+        for base in [
+            "https://api.example.org/v1",
+            "https://api.example.org/v1/",
+            "https://api.example.org/v1//",
+        ] {
+            let client = SciXClient::new("t").with_base_url(base).with_dry_run(true);
+            let out = client.get("/search/query", &[("q", "x")]).await.unwrap();
+            let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+            urls.push(described["url"].as_str().unwrap().to_string());
+        }
+        assert_eq!(urls[0], "https://api.example.org/v1/search/query?q=x");
+        assert!(urls.iter().all(|u| *u == urls[0]));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_export_request() {
+        let client = dry_run_client();
+        let body = serde_json::json!({ "bibcode": ["2016PhRvL.116f1102A"] });
+        let out = client.post_json("/export/bibtex", &body).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+        assert_eq!(described["method"], "POST");
+        assert_eq!(described["url"], "https://api.example.org/v1/export/bibtex");
+        assert_eq!(described["body"], body);
+        assert_eq!(described["query"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_default_user_agent_tracks_crate_version() {
+        let out = dry_run_client()
+            .delete("/biblib/documents/x")
+            .await
+            .unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            described["headers"]["User-Agent"],
+            format!("scix-client/{}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_user_agent_is_prepended() {
+        let client = dry_run_client().with_user_agent("bibtool/2.0");
+        let out = client.get("/search/query", &[("q", "x")]).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            described["headers"]["User-Agent"],
+            format!("bibtool/2.0 {}", DEFAULT_USER_AGENT)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_raw_requests_are_authorized() {
+        let client = dry_run_client();
+        let out = client
+            .raw_get("/search/bigquery", &[("q", "*:*")])
+            .await
+            .unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(described["method"], "GET");
+        assert_eq!(
+            described["url"],
+            "https://api.example.org/v1/search/bigquery?q=*%3A*"
+        );
+        assert_eq!(described["headers"]["Authorization"], "Bearer <redacted>");
+        assert!(!out.contains("secret-token"));
+
+        let body = serde_json::json!({ "bibcodes": ["2016PhRvL.116f1102A"] });
+        let out = client.raw_post("/vis/word-cloud", &body).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(described["method"], "POST");
+        assert_eq!(described["body"], body);
+        assert_eq!(described["headers"]["Authorization"], "Bearer <redacted>");
+
+        // Without a token no Authorization header is sent, so none is described.
+        let anonymous = SciXClient::new("")
+            .with_base_url("https://api.example.org/v1")
+            .with_dry_run(true);
+        let out = anonymous.raw_get("/search/bigquery", &[]).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert!(described["headers"].get("Authorization").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_raw_requests_send_bearer_token() {
+        let mock = MockServer::new()
+            .route("GET", "/search/bigquery", "{}")
+            .route("POST", "/vis/word-cloud", "{}")
+            .start()
+            .await;
+        let client = mock.client();
+
+        client
+            .raw_get("/search/bigquery", &[("q", "*:*")])
+            .await
+            .unwrap();
+        let body = serde_json::json!({ "bibcodes": ["2016PhRvL.116f1102A"] });
+        client.raw_post("/vis/word-cloud", &body).await.unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.header("authorization"), Some("Bearer test-token"));
+        }
+        assert_eq!(requests[1].json(), body);
+    }
+
+    #[tokio::test]
+    async fn test_compressed_response_is_decoded() {
+        let body = r#"{"response": {"numFound": 1, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .gzip()
+            .start()
+            .await;
+
+        let results = mock.client().search("x", 1).await.unwrap();
+        assert_eq!(results.papers[0].bibcode, "2016PhRvL.116f1102A");
+        let requests = mock.requests();
+        let accept = requests[0].header("accept-encoding").unwrap();
+        assert!(accept.contains("gzip"), "{}", accept);
+    }
+
+    #[tokio::test]
+    async fn test_compression_can_be_disabled() {
+        let body = r#"{"response": {"numFound": 0, "docs": []}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .gzip()
+            .start()
+            .await;
+
+        let client = mock.client().with_compression(false);
+        let results = client.search("x", 1).await.unwrap();
+        assert_eq!(results.num_found, 0);
+        assert_eq!(mock.requests()[0].header("accept-encoding"), None);
+    }
+
+    #[tokio::test]
+    async fn test_html_error_page_is_summarized() {
+        let page = format!(
+            "<html><head><title>502 Bad Gateway</title></head><body>{}</body></html>",
+            "<p>The upstream server is unavailable.</p>".repeat(200)
+        );
+        let mock = MockServer::new()
+            .route_status("GET", "/search/query", 502, page.clone())
+            .start()
+            .await;
+        let traced = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let lines = traced.clone();
+        let client = mock
+            .client()
+            .with_trace(move |line| lines.lock().unwrap().push(line.to_string()));
+
+        let err = client.search("x", 1).await.unwrap_err();
+        let SciXError::Api {
+            status,
+            message,
+            error_detail,
+        } = &err
+        else {
+            panic!("expected an API error, got {:?}", err);
+        };
+        assert_eq!(*status, 502);
+        assert_eq!(*error_detail, None);
+        assert_eq!(
+            message,
+            "ADS returned an HTML error page (HTTP 502): 502 Bad Gateway"
+        );
+        assert!(err.to_string().len() < 120);
+        assert!(traced
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|line| line.contains(&page)));
+    }
+
+    #[tokio::test]
+    async fn test_json_error_bodies_are_parsed() {
+        let cases = [
+            (
+                400,
+                r#"{"error": "Bad request, check payload"}"#,
+                "Bad request, check payload",
+            ),
+            (
+                403,
+                r#"{"message": "User does not have permission"}"#,
+                "User does not have permission",
+            ),
+            (
+                400,
+                r#"{"responseHeader": {"status": 400}, "error": {"msg": "undefined field foo", "code": 400}}"#,
+                "undefined field foo",
+            ),
+        ];
+        for (code, body, expected) in cases {
+            let mock = MockServer::new()
+                .route_status("POST", "/biblib/libraries", code, body)
+                .start()
+                .await;
+            let err = mock
+                .client()
+                .post_json("/biblib/libraries", &serde_json::json!({}))
+                .await
+                .unwrap_err();
+            let SciXError::Api {
+                status,
+                message,
+                error_detail,
+            } = err
+            else {
+                panic!("expected an API error, got {:?}", err);
+            };
+            assert_eq!(status, code);
+            assert_eq!(message, expected);
+            assert_eq!(
+                error_detail.unwrap(),
+                serde_json::from_str::<serde_json::Value>(body).unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unrecognized_error_body_is_kept() {
+        for body in [r#"{"status": "failed"}"#, "upstream timeout"] {
+            let mock = MockServer::new()
+                .route_status("GET", "/search/query", 500, body)
+                .start()
+                .await;
+            let err = mock.client().search("x", 1).await.unwrap_err();
+            assert!(matches!(
+                err,
+                SciXError::Api { status: 500, ref message, .. } if message == body
+            ));
+        }
+    }
+
+    #[test]
+    fn test_summarize_html_error_without_title() {
+        assert_eq!(
+            summarize_html_error(503, "<!DOCTYPE html><html><body>down</body></html>"),
+            "ADS returned an HTML error page (HTTP 503)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_typed_method_reports_parse_error() {
+        let err = dry_run_client()
+            .export_bibtex(&["2016PhRvL.116f1102A"])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SciXError::Parse { .. }));
+    }
+}
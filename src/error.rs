@@ -10,20 +10,40 @@ pub enum SciXError {
     Http(#[from] reqwest::Error),
 
     /// SciX API returned an error status code.
+    ///
+    /// For JSON error bodies such as `{"error": "..."}`, `message` is the
+    /// human-readable text and `error_detail` the whole parsed body;
+    /// otherwise `message` is the body itself (or a summary of an HTML page)
+    /// and `error_detail` is `None`.
     #[error("API error (HTTP {status}): {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        message: String,
+        error_detail: Option<serde_json::Value>,
+    },
 
     /// No API token provided.
-    #[error("Authentication required: set SCIX_API_TOKEN (or ADS_API_TOKEN) environment variable or pass token to SciXClient::new()")]
+    #[error("Authentication required: set SCIX_API_TOKEN (or ADS_API_TOKEN) environment variable, write it to ~/.config/scix/token, or pass token to SciXClient::new()")]
     AuthRequired,
 
     /// Rate limited by SciX API (HTTP 429).
     #[error("Rate limited, retry after {retry_after:?}")]
     RateLimited { retry_after: Option<Duration> },
 
-    /// Failed to parse API response.
-    #[error("Failed to parse response: {0}")]
-    Parse(String),
+    /// Failed to parse an API response.
+    ///
+    /// `snippet` holds the start of the raw response body (at most
+    /// [`PARSE_SNIPPET_CHARS`] characters), so a report shows what ADS
+    /// actually sent.
+    #[error("Failed to parse {endpoint} response: {message}{}", body_note(.snippet))]
+    Parse {
+        /// API endpoint the response came from, e.g. `/search/query`.
+        endpoint: &'static str,
+        /// What went wrong.
+        message: String,
+        /// Truncated raw response body.
+        snippet: String,
+    },
 
     /// Invalid query syntax.
     #[error("Invalid query: {0}")]
@@ -40,7 +60,76 @@ pub enum SciXError {
     /// JSON serialization error.
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
+
+    /// Reading or writing a local file failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Maximum characters of the raw body kept in [`SciXError::Parse`].
+pub const PARSE_SNIPPET_CHARS: usize = 200;
+
+impl SciXError {
+    /// A [`SciXError::Parse`] for `endpoint`, keeping the start of `body`.
+    pub fn parse(endpoint: &'static str, message: impl ToString, body: &str) -> Self {
+        let body = body.trim();
+        let mut snippet: String = body.chars().take(PARSE_SNIPPET_CHARS).collect();
+        if snippet.len() < body.len() {
+            snippet.push('…');
+        }
+        SciXError::Parse {
+            endpoint,
+            message: message.to_string(),
+            snippet,
+        }
+    }
+}
+
+fn body_note(snippet: &str) -> String {
+    if snippet.is_empty() {
+        String::new()
+    } else {
+        format!(" (body: {})", snippet)
+    }
 }
 
 /// Convenience alias for Results using [`SciXError`].
 pub type Result<T> = std::result::Result<T, SciXError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_keeps_truncated_snippet() {
+        let body = format!("<html>{}</html>", "x".repeat(500));
+        let err = SciXError::parse("/search/query", "expected value", &body);
+        let SciXError::Parse {
+            endpoint,
+            ref snippet,
+            ..
+        } = err
+        else {
+            panic!("not a parse error");
+        };
+        assert_eq!(endpoint, "/search/query");
+        assert!(snippet.starts_with("<html>xxx"));
+        assert_eq!(snippet.chars().count(), PARSE_SNIPPET_CHARS + 1);
+        assert!(snippet.ends_with('…'));
+
+        let message = err.to_string();
+        assert!(message
+            .starts_with("Failed to parse /search/query response: expected value (body: <html>"));
+    }
+
+    #[test]
+    fn test_parse_error_short_body_is_untouched() {
+        let err = SciXError::parse("/metrics", "missing citation histogram", " {} ");
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse /metrics response: missing citation histogram (body: {})"
+        );
+        let err = crate::parse::parse_export_response("not json").unwrap_err();
+        assert!(err.to_string().ends_with("(body: not json)"));
+    }
+}
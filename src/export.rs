@@ -2,8 +2,16 @@
 
 use crate::client::SciXClient;
 use crate::error::Result;
-use crate::parse::parse_export_response;
-use crate::types::{ExportFormat, Sort};
+use crate::parse::{
+    parse_bibtex, parse_bibtex_with_key_spans, parse_csl_json_response, parse_export_response,
+};
+use crate::types::{BibEntry, ExportFormat, ExportResult, KeyStyle, Sort};
+use futures_util::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Bibcodes per request in [`SciXClient::export_stream`].
+pub const EXPORT_STREAM_CHUNK: usize = 1000;
 
 impl SciXClient {
     /// Export papers in the specified citation format.
@@ -12,6 +20,40 @@ impl SciXClient {
         bibcodes: &[&str],
         format: ExportFormat,
         sort: Option<&Sort>,
+    ) -> Result<String> {
+        let response_body = self.export_request(bibcodes, format, sort).await?;
+        parse_export_response(&response_body)
+    }
+
+    /// Export papers as CSL-JSON items, ready for citation managers or Pandoc.
+    ///
+    /// ADS returns the CSL-JSON inside the `export` field, either as a JSON
+    /// string or already decoded, and as a bare array or wrapped in an
+    /// object with an `items` array; all of these yield the item list.
+    pub async fn export_csl_json(&self, bibcodes: &[&str]) -> Result<Vec<serde_json::Value>> {
+        let response_body = self
+            .export_request(bibcodes, ExportFormat::Csl, None)
+            .await?;
+        parse_csl_json_response(&response_body)
+    }
+
+    /// POST an export request and return the raw response body.
+    async fn export_request(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        sort: Option<&Sort>,
+    ) -> Result<String> {
+        let bibcodes = self.current_bibcodes(bibcodes).await?;
+        self.post_export(&bibcodes, format, sort).await
+    }
+
+    /// POST an export request for bibcodes already canonicalized.
+    async fn post_export(
+        &self,
+        bibcodes: &[String],
+        format: ExportFormat,
+        sort: Option<&Sort>,
     ) -> Result<String> {
         let mut body = serde_json::json!({
             "bibcode": bibcodes,
@@ -22,12 +64,956 @@ impl SciXClient {
         }
 
         let path = format!("/export/{}", format.as_api_str());
-        let response_body = self.post_json(&path, &body).await?;
-        parse_export_response(&response_body)
+        self.post_json(&path, &body).await
+    }
+
+    /// Export, reporting which requested bibcodes are missing from the output.
+    ///
+    /// ADS silently drops bibcodes it can't export (retracted, merged, or
+    /// mistyped). Each requested bibcode is looked for in the returned text,
+    /// plain or URL-encoded (as in `adsurl` fields). With
+    /// [`SciXClient::with_canonical_bibcodes`], the current bibcode is looked
+    /// for in place of a stale one, and the result still lists the caller's
+    /// bibcodes.
+    pub async fn export_checked(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        sort: Option<&Sort>,
+    ) -> Result<ExportResult> {
+        let sent = self.current_bibcodes(bibcodes).await?;
+        let response_body = self.post_export(&sent, format, sort).await?;
+        let body = parse_export_response(&response_body)?;
+        Ok(diff_export(body, bibcodes, &sent))
+    }
+
+    /// Export a large set of papers, yielding each chunk's text as it arrives.
+    ///
+    /// Bibcodes are exported [`EXPORT_STREAM_CHUNK`] at a time, one request
+    /// per chunk, so tens of thousands of entries can be written out
+    /// incrementally instead of held in memory. Concatenating the items
+    /// gives the same text as exporting each chunk with [`Self::export`].
+    /// The stream ends after the first error.
+    ///
+    /// ```no_run
+    /// # async fn example(client: scix_client::SciXClient, bibcodes: Vec<&str>) -> scix_client::error::Result<()> {
+    /// use futures_util::StreamExt;
+    /// use std::io::Write;
+    ///
+    /// let mut file = std::fs::File::create("refs.bib")?;
+    /// let mut chunks = std::pin::pin!(client.export_stream(&bibcodes, scix_client::ExportFormat::BibTeX));
+    /// while let Some(chunk) = chunks.next().await {
+    ///     file.write_all(chunk?.as_bytes())?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_stream<'a>(
+        &'a self,
+        bibcodes: &'a [&'a str],
+        format: ExportFormat,
+    ) -> impl Stream<Item = Result<String>> + 'a {
+        self.export_stream_chunked(bibcodes, format, EXPORT_STREAM_CHUNK)
+    }
+
+    fn export_stream_chunked<'a>(
+        &'a self,
+        bibcodes: &'a [&'a str],
+        format: ExportFormat,
+        chunk_size: usize,
+    ) -> impl Stream<Item = Result<String>> + 'a {
+        let chunks = Some(bibcodes.chunks(chunk_size));
+        futures_util::stream::unfold(chunks, move |chunks| async move {
+            let mut chunks = chunks?;
+            let chunk = chunks.next()?;
+            match self.export(chunk, format, None).await {
+                Ok(text) => Some((Ok(text), Some(chunks))),
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// Export papers and write the result to a file, returning its path.
+    ///
+    /// With no `path`, or a path to an existing directory, the file is named
+    /// `export.<ext>` with the format's extension (`export.bib`,
+    /// `export.ris`, ...). Bibcodes are exported in chunks as with
+    /// [`Self::export_stream`], so long lists work. The text goes to a
+    /// temporary file next to the target first and is renamed into place,
+    /// so a failed export never leaves a truncated file behind.
+    pub async fn export_to_file(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        path: Option<&Path>,
+    ) -> Result<PathBuf> {
+        let path = export_path(path, format);
+        let mut text = String::new();
+        let mut chunks = std::pin::pin!(self.export_stream(bibcodes, format));
+        while let Some(chunk) = chunks.next().await {
+            text.push_str(&chunk?);
+        }
+        write_atomic(&path, &text)?;
+        Ok(path)
+    }
+
+    /// Export each paper into its own file in `dir`, returning how many were
+    /// written.
+    ///
+    /// Files are named by [`bibcode_file_name`] (`<dir>/<bibcode>.bib` for
+    /// BibTeX), and `dir` is created if needed. Each bibcode is a separate
+    /// request; bibcodes ADS returns nothing for get no file. Stops at the
+    /// first failed request, keeping the files already written.
+    pub async fn export_per_file(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        dir: &Path,
+    ) -> Result<usize> {
+        std::fs::create_dir_all(dir)?;
+        let mut written = 0;
+        for bibcode in bibcodes {
+            let text = self.export(&[bibcode], format, None).await?;
+            if text.trim().is_empty() {
+                continue;
+            }
+            write_atomic(&dir.join(bibcode_file_name(bibcode, format)), &text)?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Export papers to a file chunk by chunk, resuming an interrupted run.
+    ///
+    /// Each chunk of [`EXPORT_STREAM_CHUNK`] bibcodes is appended to `path`
+    /// as soon as it arrives, then recorded in a `<path>.progress` sidecar
+    /// (see [`export_progress_path`]). If the export fails partway, calling
+    /// again with `resume` skips the bibcodes listed there and appends the
+    /// rest; without `resume`, or with no sidecar, the file is started over.
+    /// The sidecar is removed once every bibcode is exported. Returns the
+    /// number of bibcodes exported by this call.
+    ///
+    /// A crash between writing a chunk and recording it exports that chunk
+    /// twice on resume, never zero times. `path` is resolved like
+    /// [`Self::export_to_file`].
+    pub async fn export_to_file_resumable(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        path: &Path,
+        resume: bool,
+    ) -> Result<usize> {
+        self.export_to_file_resumable_chunked(bibcodes, format, path, resume, EXPORT_STREAM_CHUNK)
+            .await
+    }
+
+    async fn export_to_file_resumable_chunked(
+        &self,
+        bibcodes: &[&str],
+        format: ExportFormat,
+        path: &Path,
+        resume: bool,
+        chunk_size: usize,
+    ) -> Result<usize> {
+        use std::io::Write;
+
+        let path = export_path(Some(path), format);
+        let progress_path = export_progress_path(&path);
+        let done: std::collections::HashSet<String> = match std::fs::read_to_string(&progress_path)
+        {
+            Ok(text) if resume => text.lines().map(str::to_string).collect(),
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => Default::default(),
+        };
+        if done.is_empty() {
+            std::fs::write(&path, "")?;
+            std::fs::write(&progress_path, "")?;
+        }
+
+        let remaining: Vec<&str> = bibcodes
+            .iter()
+            .copied()
+            .filter(|b| !done.contains(*b))
+            .collect();
+        let open_append = |p: &Path| std::fs::OpenOptions::new().append(true).open(p);
+        let mut exported = 0;
+        for chunk in remaining.chunks(chunk_size) {
+            let text = self.export(chunk, format, None).await?;
+            let mut out = open_append(&path)?;
+            out.write_all(text.as_bytes())?;
+            out.sync_data()?;
+            let mut progress = open_append(&progress_path)?;
+            for bibcode in chunk {
+                writeln!(progress, "{}", bibcode)?;
+            }
+            exported += chunk.len();
+        }
+        std::fs::remove_file(&progress_path)?;
+        Ok(exported)
     }
 
     /// Convenience: export as BibTeX.
     pub async fn export_bibtex(&self, bibcodes: &[&str]) -> Result<String> {
         self.export(bibcodes, ExportFormat::BibTeX, None).await
     }
+
+    /// Export as BibTeX, rewriting the citation keys to the given style.
+    ///
+    /// Keys are derived from each entry's own fields, so the output is
+    /// deterministic for a given export. Collisions are resolved by appending
+    /// `a`, `b`, ... in entry order.
+    pub async fn export_bibtex_with_keys(
+        &self,
+        bibcodes: &[&str],
+        key_style: KeyStyle,
+    ) -> Result<String> {
+        let bibtex = self.export_bibtex(bibcodes).await?;
+        Ok(rewrite_bibtex_keys(&bibtex, key_style))
+    }
+
+    /// Export as BibTeX and parse the result into entries, for reading
+    /// fields back without an external BibTeX parser.
+    pub async fn export_bibtex_parsed(&self, bibcodes: &[&str]) -> Result<Vec<BibEntry>> {
+        let bibtex = self.export_bibtex(bibcodes).await?;
+        Ok(parse_bibtex(&bibtex))
+    }
+}
+
+/// The progress sidecar used by [`SciXClient::export_to_file_resumable`]:
+/// `path` with `.progress` appended, e.g. `refs.bib.progress`.
+pub fn export_progress_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".progress");
+    PathBuf::from(name)
+}
+
+/// File name for one paper's export: the bibcode plus the format's extension.
+///
+/// Characters other than ASCII letters, digits, `.`, `-`, and `_` become
+/// `_` (`2020A&A...641A...6P` → `2020A_A...641A...6P.bib`), so names are
+/// safe on every filesystem and in shells.
+pub fn bibcode_file_name(bibcode: &str, format: ExportFormat) -> String {
+    let stem: String = bibcode
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+    format!("{}.{}", stem, format.file_extension())
+}
+
+/// Resolve an export target: a directory or no path gets `export.<ext>`.
+fn export_path(path: Option<&Path>, format: ExportFormat) -> PathBuf {
+    let default_name = format!("export.{}", format.file_extension());
+    match path {
+        Some(dir) if dir.is_dir() => dir.join(default_name),
+        Some(path) => path.to_path_buf(),
+        None => PathBuf::from(default_name),
+    }
+}
+
+/// Write `contents` to a sibling temp file, then rename it over `path`.
+fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()));
+    let result = std::fs::write(&tmp, contents).and_then(|_| std::fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Split requested bibcodes by whether they appear in the export body.
+///
+/// `sent[i]` is the bibcode actually exported for `requested[i]`, which
+/// differs when it was canonicalized; it is the one looked for.
+fn diff_export(body: String, requested: &[&str], sent: &[String]) -> ExportResult {
+    let mut result = ExportResult {
+        body,
+        succeeded: Vec::new(),
+        failed: Vec::new(),
+    };
+    for (bibcode, sent) in requested.iter().zip(sent) {
+        let found =
+            result.body.contains(sent.as_str()) || result.body.contains(&url_encode_bibcode(sent));
+        let list = if found {
+            &mut result.succeeded
+        } else {
+            &mut result.failed
+        };
+        list.push(bibcode.to_string());
+    }
+    result
+}
+
+/// Percent-encode the characters bibcodes can contain that URLs escape.
+fn url_encode_bibcode(bibcode: &str) -> String {
+    bibcode.replace('%', "%25").replace('&', "%26")
+}
+
+/// Title words skipped when picking the key word for
+/// [`KeyStyle::FirstAuthorYearTitleWord`].
+const TITLE_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "by", "for", "from", "in", "into", "of", "on", "or", "the", "to",
+    "with",
+];
+
+/// Rewrite the citation keys of every entry in a BibTeX string.
+///
+/// Entries missing the fields needed for the requested style keep their
+/// original key.
+pub fn rewrite_bibtex_keys(bibtex: &str, style: KeyStyle) -> String {
+    if style == KeyStyle::AdsDefault {
+        return bibtex.to_string();
+    }
+
+    let entries = parse_bibtex_with_key_spans(bibtex);
+    let base_keys: Vec<String> = entries
+        .iter()
+        .map(|(entry, _)| citation_key(entry, style).unwrap_or_else(|| entry.key.clone()))
+        .collect();
+
+    // Every entry sharing a base key gets a suffix, including the first.
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    for base in &base_keys {
+        *totals.entry(base.as_str()).or_insert(0) += 1;
+    }
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    let keys: Vec<String> = base_keys
+        .iter()
+        .map(|base| {
+            if totals[base.as_str()] == 1 {
+                return base.clone();
+            }
+            let n = seen.entry(base.as_str()).or_insert(0);
+            let key = format!("{}{}", base, collision_suffix(*n));
+            *n += 1;
+            key
+        })
+        .collect();
+
+    let mut out = String::with_capacity(bibtex.len());
+    let mut last = 0;
+    for ((_, span), key) in entries.iter().zip(keys) {
+        out.push_str(&bibtex[last..span.start]);
+        out.push_str(&key);
+        last = span.end;
+    }
+    out.push_str(&bibtex[last..]);
+    out
+}
+
+/// Build the base citation key for one entry.
+fn citation_key(entry: &BibEntry, style: KeyStyle) -> Option<String> {
+    let authors = entry.fields.get("author")?;
+    let first_author = authors.split(" and ").next()?;
+    let family = first_author.split(',').next()?;
+    let family = key_chars(family);
+    let year = key_chars(entry.fields.get("year")?);
+    if family.is_empty() || year.is_empty() {
+        return None;
+    }
+
+    match style {
+        KeyStyle::AdsDefault => None,
+        KeyStyle::FirstAuthorYear => Some(format!("{}{}", family, year)),
+        KeyStyle::FirstAuthorYearTitleWord => {
+            let word = entry
+                .fields
+                .get("title")
+                .and_then(|title| {
+                    title.split_whitespace().map(key_chars).find(|w| {
+                        !w.is_empty() && !TITLE_STOPWORDS.contains(&w.to_lowercase().as_str())
+                    })
+                })
+                .map(|w| {
+                    let mut chars = w.chars();
+                    match chars.next() {
+                        Some(c) => c.to_ascii_uppercase().to_string() + chars.as_str(),
+                        None => String::new(),
+                    }
+                })
+                .unwrap_or_default();
+            Some(format!("{}{}{}", family, year, word))
+        }
+    }
+}
+
+/// Reduce a BibTeX value to the ASCII letters and digits usable in a key.
+///
+/// Braces, LaTeX commands, and punctuation are dropped, so `{\"O}pik` and
+/// `{\v{S}}imon` become `Opik` and `Simon`. Commands that stand for letters
+/// (`{\o}`, `\ss`, ...) and accented Latin letters written directly in
+/// UTF-8 are spelled in ASCII (`Müller` becomes `Muller`).
+fn key_chars(s: &str) -> String {
+    let mut key = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let mut command = String::new();
+            while let Some(&l) = chars.peek().filter(|l| l.is_ascii_alphabetic()) {
+                command.push(l);
+                chars.next();
+            }
+            if command.is_empty() {
+                // A control symbol such as \" or \&.
+                chars.next();
+            } else if let Some(&(_, letter)) =
+                LATEX_LETTERS.iter().find(|(name, _)| *name == command)
+            {
+                push_key_char(&mut key, letter);
+            }
+        } else {
+            push_key_char(&mut key, c);
+        }
+    }
+    key
+}
+
+fn push_key_char(key: &mut String, c: char) {
+    if c.is_ascii_alphanumeric() {
+        key.push(c);
+    } else if let Some((_, ascii)) = LATIN_FOLDS.iter().find(|(letters, _)| letters.contains(c)) {
+        key.push_str(ascii);
+    }
+}
+
+/// LaTeX commands that produce a letter rather than accent one.
+const LATEX_LETTERS: &[(&str, char)] = &[
+    ("o", 'ø'),
+    ("O", 'Ø'),
+    ("l", 'ł'),
+    ("L", 'Ł'),
+    ("ss", 'ß'),
+    ("ae", 'æ'),
+    ("AE", 'Æ'),
+    ("oe", 'œ'),
+    ("OE", 'Œ'),
+    ("aa", 'å'),
+    ("AA", 'Å'),
+    ("i", 'ı'),
+    ("j", 'j'),
+];
+
+/// Accented Latin letters and their ASCII spelling in citation keys.
+const LATIN_FOLDS: &[(&str, &str)] = &[
+    ("ÀÁÂÃÄÅĀĂĄ", "A"),
+    ("àáâãäåāăą", "a"),
+    ("ÆǼ", "AE"),
+    ("æǽ", "ae"),
+    ("ÇĆĈĊČ", "C"),
+    ("çćĉċč", "c"),
+    ("ĎĐ", "D"),
+    ("ďđ", "d"),
+    ("ÈÉÊËĒĔĖĘĚ", "E"),
+    ("èéêëēĕėęě", "e"),
+    ("ĜĞĠĢ", "G"),
+    ("ĝğġģ", "g"),
+    ("ÌÍÎÏĨĪĬĮİ", "I"),
+    ("ìíîïĩīĭįı", "i"),
+    ("ĹĻĽĿŁ", "L"),
+    ("ĺļľŀł", "l"),
+    ("ÑŃŅŇ", "N"),
+    ("ñńņň", "n"),
+    ("ÒÓÔÕÖØŌŎŐ", "O"),
+    ("òóôõöøōŏő", "o"),
+    ("Œ", "OE"),
+    ("œ", "oe"),
+    ("ŔŖŘ", "R"),
+    ("ŕŗř", "r"),
+    ("ŚŜŞŠ", "S"),
+    ("śŝşš", "s"),
+    ("ß", "ss"),
+    ("ŢŤ", "T"),
+    ("ţť", "t"),
+    ("ÙÚÛÜŨŪŬŮŰŲ", "U"),
+    ("ùúûüũūŭůűų", "u"),
+    ("ÝŸ", "Y"),
+    ("ýÿ", "y"),
+    ("ŹŻŽ", "Z"),
+    ("źżž", "z"),
+];
+
+/// Suffix for the `n`th colliding key: `a`..`z`, then `aa`, `ab`, ...
+fn collision_suffix(mut n: usize) -> String {
+    let mut suffix = Vec::new();
+    loop {
+        suffix.push(b'a' + (n % 26) as u8);
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    suffix.reverse();
+    String::from_utf8(suffix).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    #[tokio::test]
+    async fn test_export_stream_matches_export() {
+        let bibtex = "@ARTICLE{2016PhRvL.116f1102A,\n  title = {GW150914}\n}\n\n";
+        let mock = MockServer::new()
+            .route(
+                "POST",
+                "/export/bibtex",
+                serde_json::json!({ "export": bibtex }).to_string(),
+            )
+            .start()
+            .await;
+        let client = mock.client();
+        let bibcodes = ["A", "B", "C", "D", "E"];
+
+        let whole = client
+            .export(&bibcodes, ExportFormat::BibTeX, None)
+            .await
+            .unwrap();
+        let streamed: Vec<String> = client
+            .export_stream(&bibcodes, ExportFormat::BibTeX)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(streamed.concat(), whole);
+
+        let chunked: Vec<String> = client
+            .export_stream_chunked(&bibcodes, ExportFormat::BibTeX, 2)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(chunked.len(), 3);
+        let requests = mock.requests();
+        let sent: Vec<usize> = requests[2..]
+            .iter()
+            .map(|r| r.json()["bibcode"].as_array().unwrap().len())
+            .collect();
+        assert_eq!(sent, [2, 2, 1]);
+        assert_eq!(requests[4].json()["bibcode"][0], "E");
+    }
+
+    #[tokio::test]
+    async fn test_export_to_file_names_by_format() {
+        let bibtex = "@ARTICLE{2016PhRvL.116f1102A,\n  title = {GW150914}\n}\n";
+        let mock = MockServer::new()
+            .route(
+                "POST",
+                "/export/bibtex",
+                serde_json::json!({ "export": bibtex }).to_string(),
+            )
+            .start()
+            .await;
+        let dir = std::env::temp_dir().join(format!("scix-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = mock
+            .client()
+            .export_to_file(&["2016PhRvL.116f1102A"], ExportFormat::BibTeX, Some(&dir))
+            .await
+            .unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        let leftovers = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(path, dir.join("export.bib"));
+        assert_eq!(written, bibtex);
+        assert_eq!(leftovers, 1, "temporary file should be renamed away");
+    }
+
+    #[tokio::test]
+    async fn test_resumable_export_continues_after_failure() {
+        let bibtex = "@ARTICLE{chunk,\n  title = {Chunk}\n}\n";
+        let response = serde_json::json!({ "export": bibtex }).to_string();
+        let bibcodes = [
+            "2016PhRvL.116f1102A",
+            "2016PhRvL.116x1103A",
+            "1998AJ....116.1009R",
+        ];
+        let path = std::env::temp_dir().join(format!("scix-resume-{}.bib", std::process::id()));
+        let progress = export_progress_path(&path);
+
+        // The first chunk succeeds, then the server starts failing.
+        let failing = MockServer::new()
+            .route("POST", "/export/bibtex", response.clone())
+            .fail_after(1)
+            .start()
+            .await;
+        let err = failing
+            .client()
+            .export_to_file_resumable_chunked(&bibcodes, ExportFormat::BibTeX, &path, true, 2)
+            .await;
+        assert!(err.is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), bibtex);
+        assert_eq!(
+            std::fs::read_to_string(&progress).unwrap(),
+            "2016PhRvL.116f1102A\n2016PhRvL.116x1103A\n"
+        );
+
+        // Resuming exports only the bibcode the failed run didn't finish.
+        let mock = MockServer::new()
+            .route("POST", "/export/bibtex", response)
+            .start()
+            .await;
+        let exported = mock
+            .client()
+            .export_to_file_resumable_chunked(&bibcodes, ExportFormat::BibTeX, &path, true, 2)
+            .await
+            .unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(exported, 1);
+        assert_eq!(written, bibtex.repeat(2));
+        assert!(!progress.exists(), "progress sidecar should be removed");
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].json()["bibcode"],
+            serde_json::json!(["1998AJ....116.1009R"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_per_file() {
+        let bibtex = "@ARTICLE{paper,\n  title = {Paper}\n}\n";
+        let mock = MockServer::new()
+            .route(
+                "POST",
+                "/export/bibtex",
+                serde_json::json!({ "export": bibtex }).to_string(),
+            )
+            .start()
+            .await;
+        let dir = std::env::temp_dir().join(format!("scix-per-file-{}", std::process::id()));
+
+        let written = mock
+            .client()
+            .export_per_file(
+                &["2016PhRvL.116f1102A", "2020A&A...641A...6P"],
+                ExportFormat::BibTeX,
+                &dir,
+            )
+            .await
+            .unwrap();
+        let first = std::fs::read_to_string(dir.join("2016PhRvL.116f1102A.bib"));
+        let second = std::fs::read_to_string(dir.join("2020A_A...641A...6P.bib"));
+        let files = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(first.unwrap(), bibtex);
+        assert_eq!(second.unwrap(), bibtex);
+        assert_eq!(files, 2);
+        let requests = mock.requests();
+        assert_eq!(
+            requests[1].json()["bibcode"],
+            serde_json::json!(["2020A&A...641A...6P"])
+        );
+    }
+
+    #[test]
+    fn test_bibcode_file_name() {
+        assert_eq!(
+            bibcode_file_name("1998AJ....116.1009R", ExportFormat::BibTeX),
+            "1998AJ....116.1009R.bib"
+        );
+        assert_eq!(
+            bibcode_file_name("2020A&A...641A...6P", ExportFormat::Ris),
+            "2020A_A...641A...6P.ris"
+        );
+        assert_eq!(
+            bibcode_file_name("a/b c", ExportFormat::BibTeX),
+            "a_b_c.bib"
+        );
+    }
+
+    #[test]
+    fn test_export_progress_path() {
+        assert_eq!(
+            export_progress_path(Path::new("out/refs.bib")),
+            PathBuf::from("out/refs.bib.progress")
+        );
+    }
+
+    #[test]
+    fn test_file_extensions() {
+        assert_eq!(ExportFormat::BibTeX.file_extension(), "bib");
+        assert_eq!(ExportFormat::Ris.file_extension(), "ris");
+        assert_eq!(ExportFormat::DcXml.file_extension(), "xml");
+    }
+
+    #[tokio::test]
+    async fn test_export_csl_json() {
+        let body = r#"{"msg": "Retrieved 2 abstracts", "export": "[{\"id\": \"2016PhRvL.116f1102A\", \"type\": \"article-journal\", \"title\": \"Observation of Gravitational Waves\"}, {\"id\": \"1998AJ....116.1009R\", \"type\": \"article-journal\", \"title\": \"Observational Evidence from Supernovae\"}]"}"#;
+        let mock = MockServer::new()
+            .route("POST", "/export/csl", body)
+            .start()
+            .await;
+
+        let items = mock
+            .client()
+            .export_csl_json(&["2016PhRvL.116f1102A", "1998AJ....116.1009R"])
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["id"], "2016PhRvL.116f1102A");
+        assert_eq!(items[1]["title"], "Observational Evidence from Supernovae");
+        assert_eq!(
+            mock.requests()[0].json()["bibcode"][1],
+            "1998AJ....116.1009R"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_bibtex_parsed() {
+        let export = serde_json::json!({ "export": TWO_ENTRIES }).to_string();
+        let mock = MockServer::new()
+            .route("POST", "/export/bibtex", &export)
+            .start()
+            .await;
+
+        let entries = mock
+            .client()
+            .export_bibtex_parsed(&["2016PhRvL.116f1102A", "2016PhRvL.116x1103A"])
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].key, "2016PhRvL.116x1103A");
+        assert_eq!(entries[0].fields["volume"], "116");
+    }
+
+    const TWO_ENTRIES: &str = r#"@ARTICLE{2016PhRvL.116f1102A,
+       author = {{Abbott}, B.~P. and {Abbott}, R.},
+        title = "{Observation of Gravitational Waves from a Binary Black Hole Merger}",
+      journal = {\prl},
+         year = 2016,
+       volume = {116},
+}
+
+@ARTICLE{2016PhRvL.116x1103A,
+       author = {{Abbott}, B.~P. and {Abbott}, R.},
+        title = "{GW151226: Observation of Gravitational Waves from a 22-Solar-Mass Binary Black Hole Coalescence}",
+      journal = {\prl},
+         year = 2016,
+       volume = {116},
+}
+"#;
+
+    #[test]
+    fn test_ads_default_is_unchanged() {
+        assert_eq!(
+            rewrite_bibtex_keys(TWO_ENTRIES, KeyStyle::AdsDefault),
+            TWO_ENTRIES
+        );
+    }
+
+    #[test]
+    fn test_first_author_year_collision_suffixes() {
+        let out = rewrite_bibtex_keys(TWO_ENTRIES, KeyStyle::FirstAuthorYear);
+        assert!(out.contains("@ARTICLE{Abbott2016a,"));
+        assert!(out.contains("@ARTICLE{Abbott2016b,"));
+        assert!(!out.contains("2016PhRvL.116f1102A"));
+        // Only the keys change.
+        assert!(out.contains("journal = {\\prl},"));
+    }
+
+    #[test]
+    fn test_first_author_year_title_word() {
+        let out = rewrite_bibtex_keys(TWO_ENTRIES, KeyStyle::FirstAuthorYearTitleWord);
+        assert!(out.contains("@ARTICLE{Abbott2016Observation,"));
+        assert!(out.contains("@ARTICLE{Abbott2016GW151226,"));
+    }
+
+    #[test]
+    fn test_title_word_skips_stopwords_and_accents() {
+        let entry = r#"@ARTICLE{1932BAN.....6..249O,
+       author = {{\"O}pik, E.},
+        title = "{The Survival of Comets}",
+         year = 1932,
+}"#;
+        let out = rewrite_bibtex_keys(entry, KeyStyle::FirstAuthorYearTitleWord);
+        assert!(out.starts_with("@ARTICLE{Opik1932Survival,"));
+    }
+
+    #[test]
+    fn test_special_characters_in_author_names() {
+        let bibtex = r#"@ARTICLE{1990A&A...001..001L,
+       author = {{Le F{\`e}vre}, O. and {Smith}, J.},
+        title = "{Galaxy Redshifts}",
+         year = 1990,
+}
+
+@ARTICLE{1991A&A...002..002M,
+       author = {{M{\"u}ller}, K.},
+        title = "{Stellar Winds}",
+         year = 1991,
+}
+
+@ARTICLE{1991A&A...003..003M,
+       author = {Müller, K.},
+        title = "{Stellar Winds Revisited}",
+         year = 1991,
+}
+
+@ARTICLE{1992ApJ...004..004O,
+       author = {{O'Brien}, P. and {Garc{\'\i}a-P{\'e}rez}, A.},
+        title = {On the Nature of Quasars},
+         year = 1992,
+}
+
+@ARTICLE{1993A&A...005..005S,
+       author = {{{\v{S}}imon}, V.},
+        title = {Cataclysmic Variables},
+         year = 1993,
+}
+
+@ARTICLE{1994A&A...006..006C,
+       author = {{Fran{\c{c}}ois}, P. and {\.Zak}, M.},
+        title = {Abundances},
+         year = 1994,
+}
+
+@ARTICLE{1995A&A...007..007E,
+       author = {{Erd{\H{o}}s}, P.},
+        title = {Random Graphs},
+         year = 1995,
+}
+
+@ARTICLE{1996A&A...008..008N,
+       author = {{N{\o}rgaard-Nielsen}, H.~U. and {Stra{\ss}er}, S.},
+        title = {Dust},
+         year = 1996,
+}
+
+@ARTICLE{1997A&A...009..009L,
+       author = {{{\L}{\aa}ng}, B.},
+        title = {Fables},
+         year = 1997,
+}
+"#;
+        let out = rewrite_bibtex_keys(bibtex, KeyStyle::FirstAuthorYear);
+        let keys: Vec<String> = parse_bibtex(&out).into_iter().map(|e| e.key).collect();
+        // LaTeX accents and UTF-8 accents fold to the same key and collide.
+        assert_eq!(
+            keys,
+            [
+                "LeFevre1990",
+                "Muller1991a",
+                "Muller1991b",
+                "OBrien1992",
+                "Simon1993",
+                "Francois1994",
+                "Erdos1995",
+                "NorgaardNielsen1996",
+                "Lang1997",
+            ]
+        );
+
+        let out = rewrite_bibtex_keys(bibtex, KeyStyle::FirstAuthorYearTitleWord);
+        assert!(out.contains("{OBrien1992Nature,"));
+        assert!(out.contains("{Muller1991Stellar"));
+    }
+
+    #[test]
+    fn test_title_word_keys_collide_only_on_same_word() {
+        let bibtex = "@ARTICLE{a,\n author = {{Abbott}, B.},\n title = {Observation A},\n year = 2016,\n}\n\
+                      @ARTICLE{b,\n author = {{Abbott}, B.},\n title = {Observation B},\n year = 2016,\n}\n\
+                      @ARTICLE{c,\n author = {{Abbott}, B.},\n title = {Tests of GR},\n year = 2016,\n}\n";
+        let out = rewrite_bibtex_keys(bibtex, KeyStyle::FirstAuthorYearTitleWord);
+        let keys: Vec<String> = parse_bibtex(&out).into_iter().map(|e| e.key).collect();
+        assert_eq!(
+            keys,
+            [
+                "Abbott2016Observationa",
+                "Abbott2016Observationb",
+                "Abbott2016Tests"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_keep_original_key() {
+        let entry = "@MISC{2020xxx..000..000X,\n  title = {No Author}\n}\n";
+        let out = rewrite_bibtex_keys(entry, KeyStyle::FirstAuthorYear);
+        assert_eq!(out, entry);
+    }
+
+    #[test]
+    fn test_diff_export_missing_bibcode() {
+        let requested = [
+            "2016PhRvL.116f1102A",
+            "2016PhRvL.116x1103A",
+            "2099Retr.....1....1X",
+        ];
+        let sent = requested.map(String::from);
+        let result = diff_export(TWO_ENTRIES.to_string(), &requested, &sent);
+        assert_eq!(
+            result.succeeded,
+            vec!["2016PhRvL.116f1102A", "2016PhRvL.116x1103A"]
+        );
+        assert_eq!(result.failed, vec!["2099Retr.....1....1X"]);
+        assert_eq!(result.body, TWO_ENTRIES);
+    }
+
+    #[test]
+    fn test_diff_export_url_encoded_bibcode() {
+        let body =
+            "TY  - JOUR\nUR  - https://ui.adsabs.harvard.edu/abs/2004A%26A...424..727S\nER  -\n";
+        let result = diff_export(
+            body.to_string(),
+            &["2004A&A...424..727S"],
+            &["2004A&A...424..727S".to_string()],
+        );
+        assert_eq!(result.succeeded, vec!["2004A&A...424..727S"]);
+        assert!(result.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_checked_with_canonical_bibcodes() {
+        let search = r#"{"response": {"numFound": 1, "start": 0, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "alternate_bibcode": ["2016arXiv160203837T"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", search)
+            .route(
+                "POST",
+                "/export/bibtex",
+                serde_json::json!({ "export": TWO_ENTRIES }).to_string(),
+            )
+            .start()
+            .await;
+
+        let result = mock
+            .client()
+            .with_canonical_bibcodes(true)
+            .export_checked(
+                &["2016arXiv160203837T", "2099Retr.....1....1X"],
+                ExportFormat::BibTeX,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.succeeded, vec!["2016arXiv160203837T"]);
+        assert_eq!(result.failed, vec!["2099Retr.....1....1X"]);
+
+        // Canonicalization ran once, and the export got the current bibcode.
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].json()["bibcode"],
+            serde_json::json!(["2016PhRvL.116f1102A", "2099Retr.....1....1X"])
+        );
+    }
+
+    #[test]
+    fn test_collision_suffix_sequence() {
+        assert_eq!(collision_suffix(0), "a");
+        assert_eq!(collision_suffix(25), "z");
+        assert_eq!(collision_suffix(26), "aa");
+        assert_eq!(collision_suffix(27), "ab");
+    }
 }
@@ -0,0 +1,213 @@
+//! Human-readable formatting shared by the CLI and the MCP server.
+
+use crate::types::Paper;
+use serde_json::Value;
+
+/// Format a single paper as a Markdown detail view.
+///
+/// Includes authors, publication details, identifiers, keywords, abstract,
+/// and links. Author lists longer than ten are shortened to the first five,
+/// and only their distinct affiliations are listed.
+pub fn format_paper_detail(paper: &Paper) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("# {}\n\n", paper.title));
+
+    let authors_str = if paper.authors.len() > 10 {
+        let first_five: Vec<_> = paper.authors[..5].iter().map(|a| a.name.as_str()).collect();
+        format!(
+            "{} ... and {} more",
+            first_five.join("; "),
+            paper.authors.len() - 5
+        )
+    } else {
+        paper
+            .authors
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    out.push_str(&format!("**Authors:** {}\n", authors_str));
+    let shown = if paper.authors.len() > 10 {
+        5
+    } else {
+        paper.authors.len()
+    };
+    let mut affiliations: Vec<&str> = Vec::new();
+    for aff in paper.affiliations.iter().take(shown) {
+        if aff != "-" && !aff.is_empty() && !affiliations.contains(&aff.as_str()) {
+            affiliations.push(aff);
+        }
+    }
+    if !affiliations.is_empty() {
+        out.push_str(&format!("**Affiliations:** {}\n", affiliations.join("; ")));
+    }
+    out.push_str(&format!(
+        "**Year:** {}\n",
+        paper.year.map(|y| y.to_string()).unwrap_or_default()
+    ));
+
+    if let Some(pub_name) = &paper.publication {
+        let location: Vec<&str> = [&paper.volume, &paper.page]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        if location.is_empty() {
+            out.push_str(&format!("**Publication:** {}\n", pub_name));
+        } else {
+            out.push_str(&format!(
+                "**Publication:** {}, {}\n",
+                pub_name,
+                location.join(", ")
+            ));
+        }
+    }
+    if let Some(doctype) = &paper.doctype {
+        out.push_str(&format!("**Type:** {}\n", doctype));
+    }
+    out.push_str(&format!("**Bibcode:** {}\n", paper.bibcode));
+    if let Some(doi) = &paper.doi {
+        out.push_str(&format!("**DOI:** {}\n", doi));
+    }
+    if let Some(arxiv) = &paper.arxiv_id {
+        out.push_str(&format!("**arXiv:** {}\n", arxiv));
+    }
+    if let Some(cites) = paper.citation_count {
+        out.push_str(&format!("**Citations:** {}\n", cites));
+    }
+    if let Some(reads) = paper.read_count {
+        out.push_str(&format!("**Reads:** {}\n", reads));
+    }
+    if !paper.properties.is_empty() {
+        out.push_str(&format!(
+            "**Properties:** {}\n",
+            paper.properties.join(", ")
+        ));
+    }
+    if !paper.keywords.is_empty() {
+        out.push_str(&format!("**Keywords:** {}\n", paper.keywords.join("; ")));
+    }
+
+    if let Some(abstract_text) = &paper.abstract_text {
+        out.push_str(&format!("\n**Abstract:**\n{}\n", abstract_text));
+    }
+
+    if !paper.pdf_links.is_empty() {
+        out.push_str("\n**Links:**\n");
+        for link in &paper.pdf_links {
+            out.push_str(&format!("- [{}]({})\n", link.label, link.url));
+        }
+    }
+
+    out.push_str(&format!("\n**ADS:** {}\n", paper.url));
+
+    out
+}
+
+/// Outline an ADS query tree (from
+/// [`SciXClient::explain_query`](crate::SciXClient::explain_query)) as
+/// indented lines, one per node.
+///
+/// Operators show their label (`AND`, `OR`, `DEFOP` for the implicit
+/// operator between bare terms), and leaves show their input text.
+/// Wrapper nodes with a single child and nothing to show are skipped.
+pub fn format_query_tree(tree: &Value) -> String {
+    let mut out = String::new();
+    outline_node(tree, 0, &mut out);
+    out
+}
+
+fn outline_node(node: &Value, depth: usize, out: &mut String) {
+    let children = node["children"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    let label = node["label"].as_str();
+    let input = node["input"].as_str();
+    if children.len() == 1 && label.is_none() && input.is_none() {
+        return outline_node(&children[0], depth, out);
+    }
+
+    let name = node["name"].as_str().unwrap_or("?");
+    out.push_str(&"  ".repeat(depth));
+    out.push_str(name);
+    if let Some(label) = label {
+        out.push_str(&format!(" {}", label));
+    }
+    if let Some(input) = input {
+        out.push_str(&format!(": {}", input));
+    }
+    out.push('\n');
+    for child in children {
+        outline_node(child, depth + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_paper_detail() {
+        let mut paper = Paper::test_stub("2016PhRvL.116f1102A")
+            .with_title("Observation of Gravitational Waves")
+            .with_authors(&["Abbott, B. P.", "Abbott, R."])
+            .with_year(2016)
+            .with_citation_count(5000);
+        paper.abstract_text = Some("On September 14, 2015 ...".to_string());
+        paper.read_count = Some(812);
+        paper.publication = Some("Physical Review Letters".to_string());
+        paper.volume = Some("116".to_string());
+        paper.page = Some("061102".to_string());
+        paper.keywords = vec![
+            "General Relativity".to_string(),
+            "Gravitational Waves".to_string(),
+        ];
+        paper.affiliations = vec!["LIGO, Caltech".to_string(), "LIGO, Caltech".to_string()];
+
+        let out = format_paper_detail(&paper);
+        assert!(out.starts_with("# Observation of Gravitational Waves\n"));
+        assert!(out.contains("**Authors:** Abbott, B. P.; Abbott, R.\n"));
+        assert!(out.contains("**Year:** 2016\n"));
+        assert!(out.contains("**Citations:** 5000\n"));
+        assert!(out.contains("**Reads:** 812\n"));
+        assert!(out.contains("**Publication:** Physical Review Letters, 116, 061102\n"));
+        assert!(out.contains("**Keywords:** General Relativity; Gravitational Waves\n"));
+        assert!(out.contains("**Affiliations:** LIGO, Caltech\n"));
+        assert!(out.contains("**Abstract:**\nOn September 14, 2015 ...\n"));
+        assert!(out.contains("**ADS:** https://scixplorer.org/abs/2016PhRvL.116f1102A"));
+        assert!(!out.contains("**DOI:**"));
+    }
+
+    #[test]
+    fn test_format_query_tree() {
+        let tree = serde_json::json!({
+            "name": "OPERATOR", "label": "DEFOP", "children": [
+                {"name": "MODIFIER", "children": [
+                    {"name": "FIELD", "children": [
+                        {"name": "TERM_NORMAL", "input": "author"},
+                        {"name": "QNORMAL", "input": "Le"}
+                    ]}
+                ]},
+                {"name": "QNORMAL", "input": "Fevre"}
+            ]
+        });
+        assert_eq!(
+            format_query_tree(&tree),
+            "OPERATOR DEFOP\n  FIELD\n    TERM_NORMAL: author\n    QNORMAL: Le\n  QNORMAL: Fevre\n"
+        );
+    }
+
+    #[test]
+    fn test_format_paper_detail_truncates_long_author_lists() {
+        let names: Vec<String> = (0..12).map(|i| format!("Author{}, A.", i)).collect();
+        let refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+        let paper = Paper::test_stub("2020X").with_authors(&refs);
+
+        let out = format_paper_detail(&paper);
+        assert!(out.contains("Author4, A. ... and 7 more"));
+        assert!(!out.contains("Author5"));
+    }
+}
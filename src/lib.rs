@@ -45,6 +45,7 @@
 pub mod client;
 pub mod error;
 pub mod export;
+pub mod format;
 pub mod libraries;
 pub mod links;
 pub mod metrics;
@@ -59,6 +60,9 @@ pub mod types;
 
 pub mod mcp;
 
+#[cfg(test)]
+mod mock;
+
 #[cfg(feature = "cli")]
 pub mod setup;
 
@@ -2,14 +2,21 @@
 
 use crate::client::SciXClient;
 use crate::error::{Result, SciXError};
-use crate::types::{Library, LibraryDetail};
+use crate::types::{Library, LibraryDetail, SyncReport};
+use std::collections::HashSet;
+
+/// Documents requested per page by [`SciXClient::library_documents`].
+const LIBRARY_PAGE_SIZE: u32 = 1000;
+
+/// Bibcodes sent per add/remove request by [`SciXClient::sync_library`].
+const LIBRARY_EDIT_CHUNK: usize = 1000;
 
 impl SciXClient {
     /// List all libraries for the authenticated user.
     pub async fn list_libraries(&self) -> Result<Vec<Library>> {
         let body = self.get("/biblib/libraries", &[]).await?;
         let parsed: serde_json::Value = serde_json::from_str(&body)
-            .map_err(|e| SciXError::Parse(format!("Invalid libraries response: {}", e)))?;
+            .map_err(|e| SciXError::parse("/biblib/libraries", e, &body))?;
 
         let libraries = parsed["libraries"]
             .as_array()
@@ -36,10 +43,41 @@ impl SciXClient {
     }
 
     /// Get a library with its documents.
+    ///
+    /// ADS returns one page of documents; use [`Self::library_documents`]
+    /// to collect every bibcode of a large library.
     pub async fn get_library(&self, id: &str) -> Result<LibraryDetail> {
-        let body = self.get(&format!("/biblib/libraries/{}", id), &[]).await?;
+        self.get_library_page(id, &[]).await
+    }
+
+    /// Fetch every bibcode in a library, paging through the documents.
+    ///
+    /// Returns the library metadata along with the complete document list.
+    pub async fn library_documents(&self, id: &str) -> Result<LibraryDetail> {
+        let rows = LIBRARY_PAGE_SIZE.to_string();
+        let mut library = self
+            .get_library_page(id, &[("start", "0"), ("rows", &rows)])
+            .await?;
+        while library.documents.len() < library.metadata.num_documents as usize {
+            let start = library.documents.len().to_string();
+            let page = self
+                .get_library_page(id, &[("start", &start), ("rows", &rows)])
+                .await?;
+            if page.documents.is_empty() {
+                break;
+            }
+            library.documents.extend(page.documents);
+        }
+        Ok(library)
+    }
+
+    /// Fetch one page of a library's documents.
+    async fn get_library_page(&self, id: &str, params: &[(&str, &str)]) -> Result<LibraryDetail> {
+        let body = self
+            .get(&format!("/biblib/libraries/{}", id), params)
+            .await?;
         let parsed: serde_json::Value = serde_json::from_str(&body)
-            .map_err(|e| SciXError::Parse(format!("Invalid library response: {}", e)))?;
+            .map_err(|e| SciXError::parse("/biblib/libraries/{id}", e, &body))?;
 
         let metadata_val = &parsed["metadata"];
         let metadata = Library {
@@ -75,6 +113,42 @@ impl SciXClient {
         })
     }
 
+    /// Every bibcode saved in any of the user's libraries.
+    ///
+    /// Lists the libraries, then pages through each non-empty one with
+    /// [`Self::library_documents`], so a user with many libraries costs
+    /// many requests; they go through the client's rate limiter like any
+    /// other. Use the result to check whether papers are already saved.
+    pub async fn all_saved_bibcodes(&self) -> Result<HashSet<String>> {
+        let mut saved = HashSet::new();
+        for library in self.list_libraries().await? {
+            if library.num_documents == 0 {
+                continue;
+            }
+            saved.extend(self.library_documents(&library.id).await?.documents);
+        }
+        Ok(saved)
+    }
+
+    /// Find a library by its exact (case-sensitive) name and fetch it.
+    ///
+    /// Returns `Ok(None)` if no library has that name, and an error if more
+    /// than one does, since picking one would be a guess.
+    pub async fn get_library_by_name(&self, name: &str) -> Result<Option<LibraryDetail>> {
+        let libraries = self.list_libraries().await?;
+        let mut matches = libraries.iter().filter(|lib| lib.name == name);
+        let Some(library) = matches.next() else {
+            return Ok(None);
+        };
+        if matches.next().is_some() {
+            return Err(SciXError::InvalidQuery(format!(
+                "More than one library is named '{}'; use its ID instead",
+                name
+            )));
+        }
+        self.get_library(&library.id).await.map(Some)
+    }
+
     /// Create a new library.
     pub async fn create_library(
         &self,
@@ -95,7 +169,7 @@ impl SciXClient {
 
         let response_body = self.post_json("/biblib/libraries", &body).await?;
         let parsed: serde_json::Value = serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid create library response: {}", e)))?;
+            .map_err(|e| SciXError::parse("/biblib/libraries", e, &response_body))?;
 
         Ok(Library {
             id: parsed["id"].as_str().unwrap_or("").to_string(),
@@ -144,24 +218,69 @@ impl SciXClient {
 
     /// Add documents (bibcodes) to a library.
     pub async fn add_documents(&self, library_id: &str, bibcodes: &[&str]) -> Result<()> {
-        let body = serde_json::json!({
-            "bibcode": bibcodes,
-            "action": "add",
-        });
-        self.post_json(&format!("/biblib/documents/{}", library_id), &body)
-            .await?;
+        self.add_documents_counted(library_id, bibcodes).await?;
         Ok(())
     }
 
+    /// Add documents, returning how many ADS actually added.
+    ///
+    /// Bibcodes already in the library are not counted.
+    async fn add_documents_counted(&self, library_id: &str, bibcodes: &[&str]) -> Result<u32> {
+        let bibcodes = self.current_bibcodes(bibcodes).await?;
+        let response = self.edit_documents(library_id, &bibcodes, "add").await?;
+        let parsed: serde_json::Value = serde_json::from_str(&response).unwrap_or_default();
+        Ok(parsed["number_added"]
+            .as_u64()
+            .map(|n| n as u32)
+            .unwrap_or(bibcodes.len() as u32))
+    }
+
     /// Remove documents (bibcodes) from a library.
     pub async fn remove_documents(&self, library_id: &str, bibcodes: &[&str]) -> Result<()> {
+        let bibcodes = self.current_bibcodes(bibcodes).await?;
+        self.edit_documents(library_id, &bibcodes, "remove").await?;
+        Ok(())
+    }
+
+    /// Make a library hold exactly `desired_bibcodes`.
+    ///
+    /// Fetches the current documents, then adds what's missing and removes
+    /// what's extra, in chunks of at most 1000 bibcodes per request. Nothing
+    /// is sent when the library already matches. Useful for keeping a
+    /// library in step with a saved search. Bibcodes are canonicalized
+    /// first if [`with_canonical_bibcodes`](Self::with_canonical_bibcodes)
+    /// is enabled.
+    pub async fn sync_library(
+        &self,
+        library_id: &str,
+        desired_bibcodes: &[&str],
+    ) -> Result<SyncReport> {
+        let desired = self.current_bibcodes(desired_bibcodes).await?;
+        let current = self.library_documents(library_id).await?.documents;
+        let report = library_diff(&current, &desired);
+
+        for chunk in report.added.chunks(LIBRARY_EDIT_CHUNK) {
+            self.edit_documents(library_id, chunk, "add").await?;
+        }
+        for chunk in report.removed.chunks(LIBRARY_EDIT_CHUNK) {
+            self.edit_documents(library_id, chunk, "remove").await?;
+        }
+        Ok(report)
+    }
+
+    /// POST an add or remove `action` for `bibcodes`, returning the body.
+    async fn edit_documents(
+        &self,
+        library_id: &str,
+        bibcodes: &[String],
+        action: &str,
+    ) -> Result<String> {
         let body = serde_json::json!({
             "bibcode": bibcodes,
-            "action": "remove",
+            "action": action,
         });
         self.post_json(&format!("/biblib/documents/{}", library_id), &body)
-            .await?;
-        Ok(())
+            .await
     }
 
     // -- Permissions --
@@ -173,8 +292,7 @@ impl SciXClient {
         let body = self
             .get(&format!("/biblib/permissions/{}", library_id), &[])
             .await?;
-        serde_json::from_str(&body)
-            .map_err(|e| SciXError::Parse(format!("Invalid permissions response: {}", e)))
+        serde_json::from_str(&body).map_err(|e| SciXError::parse("/biblib/permissions", e, &body))
     }
 
     /// Update permissions for a collaborator on a library.
@@ -214,7 +332,7 @@ impl SciXClient {
             )
             .await?;
         let parsed: serde_json::Value = serde_json::from_str(&body)
-            .map_err(|e| SciXError::Parse(format!("Invalid annotation response: {}", e)))?;
+            .map_err(|e| SciXError::parse("/biblib/libraries/{id}/notes", e, &body))?;
         Ok(parsed["content"].as_str().unwrap_or("").to_string())
     }
 
@@ -267,14 +385,14 @@ impl SciXClient {
             )
             .await?;
         serde_json::from_str(&response)
-            .map_err(|e| SciXError::Parse(format!("Invalid operation response: {}", e)))
+            .map_err(|e| SciXError::parse("/biblib/libraries/operations", e, &response))
     }
 
     // -- Add by Query --
 
     /// Search for papers and add them to a library.
     ///
-    /// Returns the number of documents added.
+    /// Returns the number of documents added (excluding ones already present).
     pub async fn add_documents_by_query(
         &self,
         library_id: &str,
@@ -287,7 +405,245 @@ impl SciXClient {
         if bibcodes.is_empty() {
             return Ok(0);
         }
-        self.add_documents(library_id, &bibcodes).await?;
-        Ok(bibcodes.len() as u32)
+        self.add_documents_counted(library_id, &bibcodes).await
+    }
+
+    /// Create a library and fill it with up to `max` papers matching `query`.
+    ///
+    /// The returned library's `num_documents` is the number ADS added.
+    pub async fn create_library_from_query(
+        &self,
+        name: &str,
+        description: &str,
+        public: bool,
+        query: &str,
+        max: u32,
+    ) -> Result<Library> {
+        let mut library = self.create_library(name, description, public, None).await?;
+        library.num_documents = self
+            .add_documents_by_query(&library.id, query, Some(max))
+            .await?;
+        Ok(library)
+    }
+}
+
+/// Bibcodes to add (in `desired` order) and remove (in library order).
+fn library_diff(current: &[String], desired: &[String]) -> SyncReport {
+    let have: HashSet<&str> = current.iter().map(String::as_str).collect();
+    let want: HashSet<&str> = desired.iter().map(String::as_str).collect();
+    let mut queued = HashSet::new();
+    SyncReport {
+        added: desired
+            .iter()
+            .filter(|b| !have.contains(b.as_str()) && queued.insert(b.as_str()))
+            .cloned()
+            .collect(),
+        removed: current
+            .iter()
+            .filter(|b| !want.contains(b.as_str()))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::SciXError;
+    use crate::mock::MockServer;
+
+    const LIBRARIES: &str = r#"{"libraries": [
+        {"id": "abc", "name": "Reading list", "num_documents": 2},
+        {"id": "def", "name": "Thesis", "num_documents": 1},
+        {"id": "ghi", "name": "Thesis", "num_documents": 0}
+    ]}"#;
+
+    const SEARCH: &str = r#"{"response": {"numFound": 3, "docs": [
+        {"bibcode": "2016PhRvL.116f1102A", "title": ["Observation of Gravitational Waves"]},
+        {"bibcode": "2017PhRvL.119p1101A", "title": ["GW170817"]},
+        {"bibcode": "2019PhRvX...9c1040A", "title": ["GWTC-1"]}
+    ]}}"#;
+
+    #[tokio::test]
+    async fn test_get_library_by_name() {
+        let mock = MockServer::new()
+            .route("GET", "/biblib/libraries", LIBRARIES)
+            .route(
+                "GET",
+                "/biblib/libraries/abc",
+                r#"{"metadata": {"name": "Reading list", "num_documents": 2},
+                    "documents": ["2016PhRvL.116f1102A", "2017PhRvL.119p1101A"]}"#,
+            )
+            .start()
+            .await;
+        let client = mock.client();
+
+        let library = client
+            .get_library_by_name("Reading list")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(library.metadata.id, "abc");
+        assert_eq!(library.documents.len(), 2);
+
+        assert!(client
+            .get_library_by_name("reading list")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            client.get_library_by_name("Thesis").await,
+            Err(SciXError::InvalidQuery(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_all_saved_bibcodes_unions_libraries() {
+        let mock = MockServer::new()
+            .route("GET", "/biblib/libraries", LIBRARIES)
+            .route(
+                "GET",
+                "/biblib/libraries/abc",
+                r#"{"metadata": {"name": "Reading list", "num_documents": 2},
+                    "documents": ["2016PhRvL.116f1102A", "2017PhRvL.119p1101A"]}"#,
+            )
+            .route(
+                "GET",
+                "/biblib/libraries/def",
+                r#"{"metadata": {"name": "Thesis", "num_documents": 1},
+                    "documents": ["2017PhRvL.119p1101A"]}"#,
+            )
+            .start()
+            .await;
+
+        let saved = mock.client().all_saved_bibcodes().await.unwrap();
+        let mut saved: Vec<&str> = saved.iter().map(String::as_str).collect();
+        saved.sort();
+        assert_eq!(saved, ["2016PhRvL.116f1102A", "2017PhRvL.119p1101A"]);
+
+        // The empty library "ghi" is never fetched.
+        let targets: Vec<String> = mock
+            .requests()
+            .iter()
+            .map(|r| r.target.split('?').next().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                "/biblib/libraries",
+                "/biblib/libraries/abc",
+                "/biblib/libraries/def"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_library_adds_and_removes_difference() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/biblib/libraries/abc",
+                r#"{"metadata": {"name": "GW", "num_documents": 3},
+                    "documents": ["2016PhRvL.116f1102A", "2017PhRvL.119p1101A", "1998AJ....116.1009R"]}"#,
+            )
+            .route("POST", "/biblib/documents/abc", r#"{"number_added": 1}"#)
+            .start()
+            .await;
+
+        let report = mock
+            .client()
+            .sync_library(
+                "abc",
+                &[
+                    "2016PhRvL.116f1102A",
+                    "2019PhRvX...9c1040A",
+                    "2017PhRvL.119p1101A",
+                    "2019PhRvX...9c1040A",
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(report.added, ["2019PhRvX...9c1040A"]);
+        assert_eq!(report.removed, ["1998AJ....116.1009R"]);
+
+        let requests = mock.requests();
+        let methods: Vec<_> = requests.iter().map(|r| r.method.as_str()).collect();
+        assert_eq!(methods, ["GET", "POST", "POST"]);
+        let add = requests[1].json();
+        assert_eq!(add["action"], "add");
+        assert_eq!(add["bibcode"], serde_json::json!(["2019PhRvX...9c1040A"]));
+        let remove = requests[2].json();
+        assert_eq!(remove["action"], "remove");
+        assert_eq!(
+            remove["bibcode"],
+            serde_json::json!(["1998AJ....116.1009R"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_library_in_step_sends_nothing() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/biblib/libraries/abc",
+                r#"{"metadata": {"name": "GW", "num_documents": 1},
+                    "documents": ["2016PhRvL.116f1102A"]}"#,
+            )
+            .start()
+            .await;
+
+        let report = mock
+            .client()
+            .sync_library("abc", &["2016PhRvL.116f1102A"])
+            .await
+            .unwrap();
+        assert!(report.added.is_empty() && report.removed.is_empty());
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_library_from_query() {
+        let mock = MockServer::new()
+            .route("GET", "/search/query", SEARCH)
+            .route(
+                "POST",
+                "/biblib/libraries",
+                r#"{"id": "lib123", "name": "GW"}"#,
+            )
+            .route("POST", "/biblib/documents/lib123", r#"{"number_added": 3}"#)
+            .start()
+            .await;
+
+        let library = mock
+            .client()
+            .create_library_from_query("GW", "LIGO papers", false, "gravitational waves", 3)
+            .await
+            .unwrap();
+        assert_eq!(library.id, "lib123");
+        assert_eq!(library.num_documents, 3);
+
+        let requests = mock.requests();
+        let methods: Vec<_> = requests.iter().map(|r| r.method.as_str()).collect();
+        assert_eq!(methods, ["POST", "GET", "POST"]);
+        assert_eq!(requests[0].json()["name"], "GW");
+        assert!(requests[1].target.contains("rows=3"));
+        let add = requests[2].json();
+        assert_eq!(add["action"], "add");
+        assert_eq!(add["bibcode"][2], "2019PhRvX...9c1040A");
+    }
+
+    #[tokio::test]
+    async fn test_add_documents_by_query_counts_actual_additions() {
+        let mock = MockServer::new()
+            .route("GET", "/search/query", SEARCH)
+            .route("POST", "/biblib/documents/lib123", r#"{"number_added": 1}"#)
+            .start()
+            .await;
+
+        let added = mock
+            .client()
+            .add_documents_by_query("lib123", "gravitational waves", Some(3))
+            .await
+            .unwrap();
+        assert_eq!(added, 1);
     }
 }
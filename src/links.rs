@@ -4,6 +4,8 @@
 
 use crate::client::SciXClient;
 use crate::error::{Result, SciXError};
+use crate::types::ResolvedLink;
+use serde_json::Value;
 
 impl SciXClient {
     /// Resolve links for a paper.
@@ -22,6 +24,121 @@ impl SciXClient {
 
         let response_body = self.get(&path, &[]).await?;
         serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid links response: {}", e)))
+            .map_err(|e| SciXError::parse("/resolver", e, &response_body))
+    }
+
+    /// Resolve links for a paper as a flat list.
+    ///
+    /// See [`Self::resolve_links`] for `link_type`.
+    pub async fn resolve_links_typed(
+        &self,
+        bibcode: &str,
+        link_type: Option<&str>,
+    ) -> Result<Vec<ResolvedLink>> {
+        let raw = self.resolve_links(bibcode, link_type).await?;
+        parse_links_response(&raw)
+    }
+}
+
+/// Flatten a resolver response into its links.
+///
+/// The resolver either redirects to a single link (`action: "redirect"`) or
+/// lists several under `links.records`; data records can nest their links
+/// in a `data` array.
+pub fn parse_links_response(raw: &Value) -> Result<Vec<ResolvedLink>> {
+    if let Some(url) = raw["link"].as_str() {
+        return Ok(vec![ResolvedLink {
+            url: url.to_string(),
+            title: None,
+            link_type: raw["link_type"].as_str().map(String::from),
+        }]);
+    }
+
+    let records = raw["links"]["records"].as_array().ok_or_else(|| {
+        SciXError::parse(
+            "/resolver",
+            "missing link or links.records",
+            &raw.to_string(),
+        )
+    })?;
+
+    let mut links = Vec::new();
+    for record in records {
+        let link_type = record["link_type"]
+            .as_str()
+            .or_else(|| raw["links"]["link_type"].as_str());
+        collect_link(record, link_type, &mut links);
+        for nested in record["data"].as_array().into_iter().flatten() {
+            collect_link(nested, link_type, &mut links);
+        }
+    }
+    Ok(links)
+}
+
+fn collect_link(value: &Value, link_type: Option<&str>, links: &mut Vec<ResolvedLink>) {
+    if let Some(url) = value["url"].as_str() {
+        links.push(ResolvedLink {
+            url: url.to_string(),
+            title: value["title"].as_str().map(String::from),
+            link_type: value["link_type"].as_str().or(link_type).map(String::from),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_redirect() {
+        let raw = serde_json::json!({
+            "action": "redirect",
+            "link": "https://arxiv.org/pdf/1602.03837",
+            "link_type": "ESOURCE|EPRINT_PDF",
+            "service": "https://arxiv.org/pdf/1602.03837"
+        });
+        let links = parse_links_response(&raw).unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].url, "https://arxiv.org/pdf/1602.03837");
+        assert_eq!(links[0].link_type.as_deref(), Some("ESOURCE|EPRINT_PDF"));
+    }
+
+    #[test]
+    fn test_parse_display_records() {
+        let raw = serde_json::json!({
+            "action": "display",
+            "links": {
+                "count": 2,
+                "link_type": "DATA",
+                "records": [
+                    {
+                        "title": "Publisher PDF",
+                        "url": "https://journals.aps.org/prl/pdf/10.1103/PhysRevLett.116.061102",
+                        "link_type": "ESOURCE|PUB_PDF"
+                    },
+                    {
+                        "title": "GWOSC",
+                        "data": [
+                            {"url": "https://gwosc.org/events/GW150914", "title": "GW150914"}
+                        ]
+                    }
+                ]
+            }
+        });
+        let links = parse_links_response(&raw).unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].title.as_deref(), Some("Publisher PDF"));
+        assert_eq!(links[0].link_type.as_deref(), Some("ESOURCE|PUB_PDF"));
+        assert_eq!(links[1].url, "https://gwosc.org/events/GW150914");
+        assert_eq!(links[1].link_type.as_deref(), Some("DATA"));
+    }
+
+    #[test]
+    fn test_parse_invalid_response() {
+        let raw = serde_json::json!({"error": "no links"});
+        assert!(matches!(
+            parse_links_response(&raw),
+            Err(SciXError::Parse { .. })
+        ));
     }
 }
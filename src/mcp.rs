@@ -1,17 +1,19 @@
 //! MCP (Model Context Protocol) server implementation.
 //!
 //! Implements the JSON-RPC 2.0 protocol over stdio, exposing SciX API tools
-//! for AI agent integration.
+//! for AI agent integration. The `http` module (behind the `http` feature)
+//! serves the same tools over the Streamable HTTP transport.
+
+#[cfg(feature = "http")]
+pub mod http;
 
 use crate::client::SciXClient;
 use crate::error::SciXError;
+use crate::format::{format_paper_detail, format_query_tree};
 use crate::types::ExportFormat;
 use serde_json::{json, Value};
 use std::io::{self, BufRead, Write};
 
-/// Rich fields for single-paper detail view.
-const RICH_FIELDS: &str = "bibcode,title,author,year,pub,abstract,doi,identifier,doctype,esources,citation_count,property,read_count,volume,page,keyword,aff";
-
 /// Run the MCP server over stdin/stdout.
 pub async fn run_server(client: SciXClient) -> crate::error::Result<()> {
     let stdin = io::stdin();
@@ -37,21 +39,8 @@ pub async fn run_server(client: SciXClient) -> crate::error::Result<()> {
             }
         };
 
-        let id = request.get("id").cloned().unwrap_or(Value::Null);
-        let method = request["method"].as_str().unwrap_or("");
-
-        let response = match method {
-            "initialize" => handle_initialize(&id),
-            "tools/list" => handle_tools_list(&id),
-            "tools/call" => handle_tool_call(&client, &id, &request["params"]).await,
-            "resources/list" => handle_resources_list(&id),
-            "resources/read" => handle_resource_read(&id, &request["params"]),
-            "notifications/initialized" | "notifications/cancelled" => continue,
-            _ => json!({
-                "jsonrpc": "2.0",
-                "id": id,
-                "error": { "code": -32601, "message": format!("Method not found: {}", method) }
-            }),
+        let Some(response) = handle_message(&client, &request).await else {
+            continue;
         };
 
         writeln!(stdout.lock(), "{}", response)
@@ -65,6 +54,30 @@ pub async fn run_server(client: SciXClient) -> crate::error::Result<()> {
     Ok(())
 }
 
+/// Dispatch a single JSON-RPC message.
+///
+/// Returns `None` for notifications, which get no response.
+pub(crate) async fn handle_message(client: &SciXClient, request: &Value) -> Option<Value> {
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = request["method"].as_str().unwrap_or("");
+
+    let response = match method {
+        "initialize" => handle_initialize(&id),
+        "tools/list" => handle_tools_list(&id),
+        "tools/call" => handle_tool_call(client, &id, &request["params"]).await,
+        "resources/list" => handle_resources_list(&id),
+        "resources/templates/list" => handle_resource_templates_list(&id),
+        "resources/read" => handle_resource_read(client, &id, &request["params"]).await,
+        "notifications/initialized" | "notifications/cancelled" => return None,
+        _ => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32601, "message": format!("Method not found: {}", method) }
+        }),
+    };
+    Some(response)
+}
+
 fn handle_initialize(id: &Value) -> Value {
     json!({
         "jsonrpc": "2.0",
@@ -116,17 +129,53 @@ fn handle_resources_list(id: &Value) -> Value {
     })
 }
 
-fn handle_resource_read(id: &Value, params: &Value) -> Value {
+/// URI template for reading a single paper as a resource.
+const PAPER_TEMPLATE_PREFIX: &str = "scix://paper/";
+
+fn handle_resource_templates_list(id: &Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "result": {
+            "resourceTemplates": [
+                {
+                    "uriTemplate": format!("{}{{bibcode}}", PAPER_TEMPLATE_PREFIX),
+                    "name": "SciX Paper",
+                    "description": "Full record for one paper: title, authors, affiliations, abstract, identifiers",
+                    "mimeType": "text/plain"
+                }
+            ]
+        }
+    })
+}
+
+async fn handle_resource_read(client: &SciXClient, id: &Value, params: &Value) -> Value {
     let uri = params["uri"].as_str().unwrap_or("");
     let content = match uri {
         "scix://fields" => FIELDS_REFERENCE.to_string(),
         "scix://syntax" => SYNTAX_REFERENCE.to_string(),
         _ => {
-            return json!({
-                "jsonrpc": "2.0",
-                "id": id,
-                "error": { "code": -32602, "message": format!("Unknown resource: {}", uri) }
-            });
+            let bibcode = uri
+                .strip_prefix(PAPER_TEMPLATE_PREFIX)
+                .map(percent_decode)
+                .filter(|b| !b.is_empty());
+            let Some(bibcode) = bibcode else {
+                return json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": { "code": -32602, "message": format!("Unknown resource: {}", uri) }
+                });
+            };
+            match tool_get_paper(client, &json!({ "bibcode": bibcode })).await {
+                Ok(text) => text,
+                Err(e) => {
+                    return json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": -32603, "message": format!("{}: {}", uri, e) }
+                    });
+                }
+            }
         }
     };
 
@@ -143,45 +192,161 @@ fn handle_resource_read(id: &Value, params: &Value) -> Value {
     })
 }
 
+/// Decode `%XX` escapes, e.g. `A%26A` in a bibcode. Malformed escapes are
+/// kept as-is.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
 async fn handle_tool_call(client: &SciXClient, id: &Value, params: &Value) -> Value {
     let tool_name = params["name"].as_str().unwrap_or("");
     let args = &params["arguments"];
 
     let result = match tool_name {
         "scix_search" => tool_search(client, args).await,
+        "scix_count" => tool_count(client, args).await,
         "scix_bigquery" => tool_bigquery(client, args).await,
         "scix_export" => tool_export(client, args).await,
         "scix_metrics" => tool_metrics(client, args).await,
         "scix_library" => tool_library(client, args).await,
         "scix_library_documents" => tool_library_documents(client, args).await,
+        "scix_library_export" => tool_library_export(client, args).await,
         "scix_citation_helper" => tool_citation_helper(client, args).await,
         "scix_network" => tool_network(client, args).await,
         "scix_object_search" => tool_object_search(client, args).await,
         "scix_resolve_reference" => tool_resolve_reference(client, args).await,
         "scix_resolve_links" => tool_resolve_links(client, args).await,
         "scix_get_paper" => tool_get_paper(client, args).await,
+        "scix_neighborhood" => tool_neighborhood(client, args).await,
+        "scix_dossier" => tool_dossier(client, args).await,
+        "scix_explain_query" => tool_explain_query(client, args).await,
         _ => Err(SciXError::Config(format!("Unknown tool: {}", tool_name))),
     };
 
+    let max_chars = max_output_chars();
     match result {
-        Ok(content) => json!({
-            "jsonrpc": "2.0",
-            "id": id,
-            "result": {
-                "content": [{ "type": "text", "text": content }]
-            }
-        }),
+        Ok(content) => {
+            let text = if args["format"] == "json" {
+                truncate_json_output(content, max_chars)
+            } else {
+                truncate_output(content, max_chars)
+            };
+            json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "result": { "content": [{ "type": "text", "text": text }] }
+            })
+        }
         Err(e) => json!({
             "jsonrpc": "2.0",
             "id": id,
             "result": {
-                "content": [{ "type": "text", "text": format!("Error: {}", e) }],
+                "content": [{
+                    "type": "text",
+                    "text": truncate_output(format!("Error: {}", e), max_chars)
+                }],
                 "isError": true
             }
         }),
     }
 }
 
+/// Default character budget for a single tool result.
+const DEFAULT_MAX_OUTPUT_CHARS: usize = 8000;
+
+/// Appended to tool results cut to fit the character budget.
+const TRUNCATION_MARKER: &str = "\n...(truncated, refine your query)";
+
+/// Character budget for tool results, from `SCIX_MCP_MAX_CHARS`.
+///
+/// `0` disables truncation; unset or unparseable values use the default.
+fn max_output_chars() -> usize {
+    std::env::var("SCIX_MCP_MAX_CHARS")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_MAX_OUTPUT_CHARS)
+}
+
+/// Cut `text` to at most `max_chars` characters, marker included.
+fn truncate_output(text: String, max_chars: usize) -> String {
+    if max_chars == 0 || text.chars().count() <= max_chars {
+        return text;
+    }
+    let keep = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let cut = text
+        .char_indices()
+        .nth(keep)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    let mut truncated = text[..cut].to_string();
+    truncated.push_str(TRUNCATION_MARKER);
+    truncated
+}
+
+/// Fit a `format: "json"` result to `max_chars` without breaking the JSON.
+///
+/// Trailing elements of the longest array (such as `papers`) are dropped
+/// until the result fits, and an object result gains `"truncated": true`.
+/// Results that aren't JSON fall back to [`truncate_output`]; JSON with no
+/// array left to shrink is returned whole.
+fn truncate_json_output(text: String, max_chars: usize) -> String {
+    if max_chars == 0 || text.chars().count() <= max_chars {
+        return text;
+    }
+    let Ok(mut value) = serde_json::from_str::<Value>(&text) else {
+        return truncate_output(text, max_chars);
+    };
+    if let Value::Object(map) = &mut value {
+        map.insert("truncated".to_string(), Value::Bool(true));
+    }
+    loop {
+        let text = value.to_string();
+        let chars = text.chars().count();
+        if chars <= max_chars {
+            return text;
+        }
+        let Some(items) = longest_array(&mut value).filter(|items| !items.is_empty()) else {
+            return text;
+        };
+        // Drop about as many elements as the excess is worth, at least one.
+        let per_item = (chars / items.len()).max(1);
+        let drop = ((chars - max_chars) / per_item).clamp(1, items.len());
+        items.truncate(items.len() - drop);
+    }
+}
+
+/// The result itself if it is an array, else its longest non-empty array field.
+fn longest_array(value: &mut Value) -> Option<&mut Vec<Value>> {
+    match value {
+        Value::Array(items) => Some(items),
+        Value::Object(map) => map
+            .values_mut()
+            .filter_map(Value::as_array_mut)
+            .filter(|items| !items.is_empty())
+            .max_by_key(|items| items.len()),
+        _ => None,
+    }
+}
+
 // --- Tool implementations ---
 
 async fn tool_search(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
@@ -193,17 +358,9 @@ async fn tool_search(client: &SciXClient, args: &Value) -> Result<String, SciXEr
     let sort = args["sort"].as_str();
     let fields = args["fields"].as_str();
 
-    let sort_val = sort.map(|s| {
-        let parts: Vec<&str> = s.split_whitespace().collect();
-        crate::types::Sort::new(
-            parts.first().copied().unwrap_or("date"),
-            if parts.get(1) == Some(&"asc") {
-                crate::types::SortDirection::Asc
-            } else {
-                crate::types::SortDirection::Desc
-            },
-        )
-    });
+    let sort_val: Option<crate::types::Sort> = sort
+        .map(|s| crate::types::Sort::from_human(s).map_or_else(|| s.parse(), Ok))
+        .transpose()?;
 
     let fl_owned: String;
     let fl = match fields {
@@ -214,33 +371,38 @@ async fn tool_search(client: &SciXClient, args: &Value) -> Result<String, SciXEr
             fl_owned.as_str()
         }
     };
+    let filters: Vec<&str> = args["filters"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str())
+        .collect();
     let results = client
-        .search_with_options(query, fl, sort_val.as_ref(), rows, start)
+        .search_with_options(query, fl, sort_val.as_ref(), rows, start, &filters)
         .await?;
 
-    Ok(format_search_results(&results, start))
+    render(args, &results, |r| format_search_results(r, start))
+}
+
+async fn tool_count(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
+    let query = args["query"]
+        .as_str()
+        .ok_or_else(|| SciXError::InvalidQuery("'query' parameter required".into()))?;
+
+    let count = client.count(query).await?;
+    Ok(format!("{} papers match: {}", count, query))
 }
 
 async fn tool_bigquery(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let bibcodes: Vec<&str> = args["bibcodes"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
 
     let query = args["query"].as_str();
     let results = client.bigquery(&bibcodes, query, None, None, None).await?;
-    Ok(format_search_results(&results, 0))
+    render(args, &results, |r| format_search_results(r, 0))
 }
 
 async fn tool_export(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let bibcodes: Vec<&str> = args["bibcodes"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
 
     let format_str = args["format"].as_str().unwrap_or("bibtex");
     let format = ExportFormat::from_str_loose(format_str).unwrap_or(ExportFormat::BibTeX);
@@ -249,15 +411,10 @@ async fn tool_export(client: &SciXClient, args: &Value) -> Result<String, SciXEr
 }
 
 async fn tool_metrics(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let bibcodes: Vec<&str> = args["bibcodes"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
 
     let metrics = client.metrics(&bibcodes).await?;
-    serde_json::to_string_pretty(&metrics).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&metrics)?)
 }
 
 async fn tool_library(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
@@ -307,7 +464,7 @@ async fn tool_library(client: &SciXClient, args: &Value) -> Result<String, SciXE
             let lib = client
                 .create_library(name, description, public, None)
                 .await?;
-            serde_json::to_string_pretty(&lib).map_err(|e| SciXError::Parse(e.to_string()))
+            Ok(serde_json::to_string_pretty(&lib)?)
         }
         "delete" => {
             let id = args["id"]
@@ -331,7 +488,7 @@ async fn tool_library(client: &SciXClient, args: &Value) -> Result<String, SciXE
                 .as_str()
                 .ok_or_else(|| SciXError::InvalidQuery("'id' required for permissions".into()))?;
             let perms = client.get_permissions(id).await?;
-            serde_json::to_string_pretty(&perms).map_err(|e| SciXError::Parse(e.to_string()))
+            Ok(serde_json::to_string_pretty(&perms)?)
         }
         "update_permissions" => {
             let id = args["id"].as_str().ok_or_else(|| {
@@ -366,6 +523,24 @@ async fn tool_library(client: &SciXClient, args: &Value) -> Result<String, SciXE
     }
 }
 
+async fn tool_library_export(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
+    let library_id = args["library_id"]
+        .as_str()
+        .ok_or_else(|| SciXError::InvalidQuery("'library_id' required".into()))?;
+    let format_str = args["format"].as_str().unwrap_or("bibtex");
+    let format = ExportFormat::from_str_loose(format_str).unwrap_or(ExportFormat::BibTeX);
+
+    let library = client.library_documents(library_id).await?;
+    if library.documents.is_empty() {
+        return Ok(format!(
+            "Library '{}' has no documents to export.",
+            library.metadata.name
+        ));
+    }
+    let bibcodes: Vec<&str> = library.documents.iter().map(|s| s.as_str()).collect();
+    client.export(&bibcodes, format, None).await
+}
+
 async fn tool_library_documents(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
     let action = args["action"]
         .as_str()
@@ -376,22 +551,12 @@ async fn tool_library_documents(client: &SciXClient, args: &Value) -> Result<Str
 
     match action {
         "add" => {
-            let bibcodes: Vec<&str> = args["bibcodes"]
-                .as_array()
-                .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-                .iter()
-                .filter_map(|v| v.as_str())
-                .collect();
+            let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
             client.add_documents(library_id, &bibcodes).await?;
             Ok(format!("Added {} documents", bibcodes.len()))
         }
         "remove" => {
-            let bibcodes: Vec<&str> = args["bibcodes"]
-                .as_array()
-                .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-                .iter()
-                .filter_map(|v| v.as_str())
-                .collect();
+            let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
             client.remove_documents(library_id, &bibcodes).await?;
             Ok(format!("Removed {} documents", bibcodes.len()))
         }
@@ -428,7 +593,7 @@ async fn tool_library_documents(client: &SciXClient, args: &Value) -> Result<Str
             let result = client
                 .library_operation(library_id, action, source_slice)
                 .await?;
-            serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+            Ok(serde_json::to_string_pretty(&result)?)
         }
         "add_by_query" => {
             let query = args["query"].as_str().ok_or_else(|| {
@@ -448,24 +613,14 @@ async fn tool_library_documents(client: &SciXClient, args: &Value) -> Result<Str
 }
 
 async fn tool_citation_helper(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let bibcodes: Vec<&str> = args["bibcodes"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
 
     let result = client.citation_helper(&bibcodes).await?;
-    serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&result)?)
 }
 
 async fn tool_network(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let bibcodes: Vec<&str> = args["bibcodes"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'bibcodes' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let bibcodes = list_arg(args, "bibcodes", IDENTIFIERS)?;
 
     let network_type = args["type"].as_str().unwrap_or("author");
     let result = match network_type {
@@ -473,31 +628,21 @@ async fn tool_network(client: &SciXClient, args: &Value) -> Result<String, SciXE
         _ => client.author_network(&bibcodes).await?,
     };
 
-    serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&result)?)
 }
 
 async fn tool_object_search(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let objects: Vec<&str> = args["objects"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'objects' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let objects = list_arg(args, "objects", NAMES)?;
 
     let result = client.resolve_objects(&objects).await?;
-    serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&result)?)
 }
 
 async fn tool_resolve_reference(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
-    let references: Vec<&str> = args["references"]
-        .as_array()
-        .ok_or_else(|| SciXError::InvalidQuery("'references' array required".into()))?
-        .iter()
-        .filter_map(|v| v.as_str())
-        .collect();
+    let references = list_arg(args, "references", LINES)?;
 
     let result = client.resolve_references(&references).await?;
-    serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&result)?)
 }
 
 async fn tool_resolve_links(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
@@ -507,7 +652,7 @@ async fn tool_resolve_links(client: &SciXClient, args: &Value) -> Result<String,
     let link_type = args["link_type"].as_str();
 
     let result = client.resolve_links(bibcode, link_type).await?;
-    serde_json::to_string_pretty(&result).map_err(|e| SciXError::Parse(e.to_string()))
+    Ok(serde_json::to_string_pretty(&result)?)
 }
 
 async fn tool_get_paper(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
@@ -515,82 +660,96 @@ async fn tool_get_paper(client: &SciXClient, args: &Value) -> Result<String, Sci
         .as_str()
         .ok_or_else(|| SciXError::InvalidQuery("'bibcode' required".into()))?;
 
-    let query = format!("identifier:{}", bibcode);
-    let results = client
-        .search_with_options(&query, RICH_FIELDS, None, 1, 0)
-        .await?;
+    let paper = client.get_paper(bibcode).await?;
+    render(args, &paper, format_paper_detail)
+}
 
-    if results.papers.is_empty() {
-        return Err(SciXError::NotFound(format!("Paper not found: {}", bibcode)));
-    }
+async fn tool_neighborhood(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
+    let bibcode = args["bibcode"]
+        .as_str()
+        .ok_or_else(|| SciXError::InvalidQuery("'bibcode' required".into()))?;
+    let rows = args["rows"].as_u64().unwrap_or(10) as u32;
 
-    let paper = &results.papers[0];
-    let mut out = String::new();
+    let hood = client.neighborhood(bibcode, rows).await?;
+    render(args, &hood, format_neighborhood)
+}
+
+async fn tool_dossier(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
+    let bibcode = args["bibcode"]
+        .as_str()
+        .ok_or_else(|| SciXError::InvalidQuery("'bibcode' required".into()))?;
+    let rows = args["rows"].as_u64().unwrap_or(10) as u32;
 
-    out.push_str(&format!("# {}\n\n", paper.title));
+    let dossier = client.dossier(bibcode, rows).await;
+    render(args, &dossier, format_dossier)
+}
 
-    let authors_str = if paper.authors.len() > 10 {
-        let first_five: Vec<_> = paper.authors[..5].iter().map(|a| a.name.as_str()).collect();
+async fn tool_explain_query(client: &SciXClient, args: &Value) -> Result<String, SciXError> {
+    let query = args["query"]
+        .as_str()
+        .ok_or_else(|| SciXError::InvalidQuery("'query' parameter required".into()))?;
+
+    let tree = client.explain_query(query).await?;
+    render(args, &tree, |tree| {
         format!(
-            "{} ... and {} more",
-            first_five.join("; "),
-            paper.authors.len() - 5
+            "Query: {}\n\n{}\nRaw tree:\n{}",
+            query,
+            format_query_tree(tree),
+            serde_json::to_string_pretty(tree).unwrap_or_default()
         )
-    } else {
-        paper
-            .authors
-            .iter()
-            .map(|a| a.name.as_str())
-            .collect::<Vec<_>>()
-            .join("; ")
-    };
-    out.push_str(&format!("**Authors:** {}\n", authors_str));
-    out.push_str(&format!(
-        "**Year:** {}\n",
-        paper.year.map(|y| y.to_string()).unwrap_or_default()
-    ));
-
-    if let Some(pub_name) = &paper.publication {
-        out.push_str(&format!("**Publication:** {}\n", pub_name));
-    }
-    if let Some(doctype) = &paper.doctype {
-        out.push_str(&format!("**Type:** {}\n", doctype));
-    }
-    out.push_str(&format!("**Bibcode:** {}\n", paper.bibcode));
-    if let Some(doi) = &paper.doi {
-        out.push_str(&format!("**DOI:** {}\n", doi));
-    }
-    if let Some(arxiv) = &paper.arxiv_id {
-        out.push_str(&format!("**arXiv:** {}\n", arxiv));
-    }
-    if let Some(cites) = paper.citation_count {
-        out.push_str(&format!("**Citations:** {}\n", cites));
-    }
-    if !paper.properties.is_empty() {
-        out.push_str(&format!(
-            "**Properties:** {}\n",
-            paper.properties.join(", ")
-        ));
-    }
-
-    if let Some(abstract_text) = &paper.abstract_text {
-        out.push_str(&format!("\n**Abstract:**\n{}\n", abstract_text));
-    }
+    })
+}
 
-    if !paper.pdf_links.is_empty() {
-        out.push_str("\n**Links:**\n");
-        for link in &paper.pdf_links {
-            out.push_str(&format!("- [{}]({})\n", link.label, link.url));
+// --- Argument helpers ---
+
+/// Separators for bibcodes and other identifiers, which never contain spaces.
+const IDENTIFIERS: &[char] = &[',', ';', ' ', '\t', '\n', '\r'];
+/// Separators for object names like `NGC 1234`, which may contain spaces.
+const NAMES: &[char] = &[',', ';', '\n', '\r'];
+/// Separators for free-text references, which contain commas and spaces.
+const LINES: &[char] = &['\n', '\r'];
+
+/// A list-of-strings argument.
+///
+/// Models often send `"A, B"` where the schema asks for `["A", "B"]`; a
+/// single string is split on `separators` instead of being rejected.
+fn list_arg<'a>(
+    args: &'a Value,
+    key: &str,
+    separators: &[char],
+) -> Result<Vec<&'a str>, SciXError> {
+    let items: Vec<&str> = match &args[key] {
+        Value::Array(values) => values.iter().filter_map(Value::as_str).collect(),
+        Value::String(joined) => joined.split(separators).collect(),
+        _ => {
+            return Err(SciXError::InvalidQuery(format!(
+                "'{}' required: an array of strings, e.g. [\"first\", \"second\"]",
+                key
+            )))
         }
-    }
-
-    out.push_str(&format!("\n**ADS:** {}\n", paper.url));
-
-    Ok(out)
+    };
+    Ok(items
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect())
 }
 
 // --- Formatting helpers ---
 
+/// Serialize `value` as compact JSON if the call asked for `format: "json"`,
+/// otherwise render it as text for reading.
+fn render<T: serde::Serialize>(
+    args: &Value,
+    value: &T,
+    text: impl FnOnce(&T) -> String,
+) -> Result<String, SciXError> {
+    match args["format"].as_str() {
+        Some("json") => Ok(serde_json::to_string(value)?),
+        _ => Ok(text(value)),
+    }
+}
+
 fn format_library_list(libs: &[crate::types::Library]) -> String {
     if libs.is_empty() {
         return "No libraries found.".to_string();
@@ -611,7 +770,108 @@ fn format_library_list(libs: &[crate::types::Library]) -> String {
 
 fn format_search_results(results: &crate::types::SearchResponse, start: u32) -> String {
     let mut out = format!("Found {} results:\n\n", results.num_found);
-    for (i, paper) in results.papers.iter().enumerate() {
+    out.push_str(&format_paper_list(&results.papers, start));
+
+    let shown = start as u64 + results.papers.len() as u64;
+    if results.num_found > shown {
+        out.push_str(&format!("*Use start={} to see more results*\n", shown));
+    }
+
+    out
+}
+
+fn format_neighborhood(hood: &crate::types::Neighborhood) -> String {
+    let mut out = format!(
+        "## References of {} ({} of {})\n\n",
+        hood.bibcode,
+        hood.references.len(),
+        hood.num_references
+    );
+    out.push_str(&format_paper_list(&hood.references, 0));
+    out.push_str(&format!(
+        "## Citations of {} ({} of {})\n\n",
+        hood.bibcode,
+        hood.citations.len(),
+        hood.num_citations
+    ));
+    out.push_str(&format_paper_list(&hood.citations, 0));
+    out
+}
+
+fn format_dossier(dossier: &crate::types::Dossier) -> String {
+    let mut out = match &dossier.paper {
+        Some(paper) => format_paper_detail(paper),
+        None => format!("# {}\n", dossier.bibcode),
+    };
+
+    if let Some(metrics) = &dossier.metrics {
+        out.push_str("\n## Metrics\n\n");
+        if let Some(total) = metrics
+            .citation_stats
+            .as_ref()
+            .and_then(|s| s.total.as_ref())
+        {
+            if let Some(cites) = total.total_citations {
+                out.push_str(&format!("Citations: {}\n", cites));
+            }
+            if let Some(external) = total.external_citations() {
+                out.push_str(&format!("Excluding self-citations: {}\n", external));
+            }
+        }
+        if let Some(indicators) = &metrics.indicators {
+            let values = [
+                ("h", indicators.h),
+                ("g", indicators.g),
+                ("i10", indicators.i10),
+            ];
+            for (name, value) in values {
+                if let Some(value) = value {
+                    out.push_str(&format!("{}-index: {}\n", name, value));
+                }
+            }
+        }
+    }
+
+    if let Some(links) = dossier.links.as_ref().filter(|links| !links.is_empty()) {
+        out.push_str("\n## Links\n\n");
+        for link in links {
+            let label = link
+                .title
+                .as_deref()
+                .or(link.link_type.as_deref())
+                .unwrap_or("link");
+            out.push_str(&format!("- {}: {}\n", label, link.url));
+        }
+    }
+
+    for (name, results) in [
+        ("References", &dossier.references),
+        ("Citations", &dossier.citations),
+    ] {
+        if let Some(results) = results {
+            out.push_str(&format!(
+                "\n## {} ({} of {})\n\n",
+                name,
+                results.papers.len(),
+                results.num_found
+            ));
+            out.push_str(&format_paper_list(&results.papers, 0));
+        }
+    }
+
+    if !dossier.errors.is_empty() {
+        out.push_str("\n## Unavailable\n\n");
+        for (part, error) in &dossier.errors {
+            out.push_str(&format!("- {}: {}\n", part, error));
+        }
+    }
+    out
+}
+
+/// Numbered list of papers with authors, bibcode, DOI, and citation count.
+fn format_paper_list(papers: &[crate::types::Paper], start: u32) -> String {
+    let mut out = String::new();
+    for (i, paper) in papers.iter().enumerate() {
         let authors_str = if paper.authors.len() > 3 {
             format!("{} et al.", paper.authors[0].family_name)
         } else {
@@ -640,12 +900,6 @@ fn format_search_results(results: &crate::types::SearchResponse, start: u32) ->
         }
         out.push('\n');
     }
-
-    let shown = start as u64 + results.papers.len() as u64;
-    if results.num_found > shown {
-        out.push_str(&format!("*Use start={} to see more results*\n", shown));
-    }
-
     out
 }
 
@@ -662,8 +916,27 @@ fn tool_definitions() -> Value {
                     "query": { "type": "string", "description": "ADS query string (e.g., 'author:\"Einstein\" year:1905')" },
                     "rows": { "type": "integer", "description": "Max results (default 10)", "default": 10 },
                     "start": { "type": "integer", "description": "Starting index for pagination (default 0)", "default": 0 },
-                    "sort": { "type": "string", "description": "Sort order (e.g., 'date desc', 'citation_count desc')" },
-                    "fields": { "type": "string", "description": "Comma-separated fields to return" }
+                    "sort": { "type": "string", "description": "Sort order: 'field [asc|desc]' (e.g., 'date desc', 'citation_count desc') or a phrase like 'most cited', 'newest', 'oldest', 'most read', 'best match'. Fields: date, entry_date, citation_count, citation_count_norm, classic_factor, read_count, first_author, bibcode, score" },
+                    "fields": { "type": "string", "description": "Comma-separated fields to return" },
+                    "filters": { "type": "array", "items": { "type": "string" }, "description": "Filter queries that restrict results without affecting ranking (e.g., ['database:astronomy', 'property:refereed'])" },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, human-readable) or 'json' (machine-readable)", "default": "text" }
+                },
+                "required": ["query"]
+            },
+            "annotations": {
+                "readOnlyHint": true,
+                "destructiveHint": false,
+                "idempotentHint": true,
+                "openWorldHint": true
+            }
+        },
+        {
+            "name": "scix_count",
+            "description": "Count the papers matching a query without returning them. Cheaper than scix_search when only the number of results is needed.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "ADS query string (e.g., 'abs:\"dark matter\" year:2023')" }
                 },
                 "required": ["query"]
             },
@@ -681,7 +954,8 @@ fn tool_definitions() -> Value {
                 "type": "object",
                 "properties": {
                     "bibcodes": { "type": "array", "items": { "type": "string" }, "description": "List of bibcodes to search within" },
-                    "query": { "type": "string", "description": "Optional additional query filter" }
+                    "query": { "type": "string", "description": "Optional additional query filter" },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, human-readable) or 'json' (machine-readable)", "default": "text" }
                 },
                 "required": ["bibcodes"]
             },
@@ -774,6 +1048,24 @@ fn tool_definitions() -> Value {
                 "openWorldHint": true
             }
         },
+        {
+            "name": "scix_library_export",
+            "description": "Export every paper in a SciX library in a citation format (bibtex, ris, aastex, mnras, ieee, csl, etc.).",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "library_id": { "type": "string", "description": "Library ID" },
+                    "format": { "type": "string", "description": "Export format (bibtex, ris, aastex, mnras, ieee, csl, etc.)", "default": "bibtex" }
+                },
+                "required": ["library_id"]
+            },
+            "annotations": {
+                "readOnlyHint": true,
+                "destructiveHint": false,
+                "idempotentHint": true,
+                "openWorldHint": true
+            }
+        },
         {
             "name": "scix_citation_helper",
             "description": "Find papers frequently co-cited with the given set but not yet included.",
@@ -867,7 +1159,46 @@ fn tool_definitions() -> Value {
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "bibcode": { "type": "string", "description": "Paper bibcode" }
+                    "bibcode": { "type": "string", "description": "Paper bibcode" },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, human-readable) or 'json' (machine-readable)", "default": "text" }
+                },
+                "required": ["bibcode"]
+            },
+            "annotations": {
+                "readOnlyHint": true,
+                "destructiveHint": false,
+                "idempotentHint": true,
+                "openWorldHint": true
+            }
+        },
+        {
+            "name": "scix_neighborhood",
+            "description": "Get a paper's bibliographic neighborhood in one call: the papers it references and the papers citing it. A good starting point for a literature review.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "bibcode": { "type": "string", "description": "Paper bibcode" },
+                    "rows": { "type": "integer", "description": "Max references and max citations to return (default 10 each)", "default": 10 },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, human-readable) or 'json' (machine-readable)", "default": "text" }
+                },
+                "required": ["bibcode"]
+            },
+            "annotations": {
+                "readOnlyHint": true,
+                "destructiveHint": false,
+                "idempotentHint": true,
+                "openWorldHint": true
+            }
+        },
+        {
+            "name": "scix_dossier",
+            "description": "Get everything about one paper in a single call: full metadata, citation metrics, references, citations, and full-text/data links. Parts that fail are listed as unavailable instead of failing the whole call. Use this for a deep dive into a specific paper.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "bibcode": { "type": "string", "description": "Paper bibcode" },
+                    "rows": { "type": "integer", "description": "Max references and max citations to return (default 10 each)", "default": 10 },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, human-readable) or 'json' (machine-readable)", "default": "text" }
                 },
                 "required": ["bibcode"]
             },
@@ -877,6 +1208,24 @@ fn tool_definitions() -> Value {
                 "idempotentHint": true,
                 "openWorldHint": true
             }
+        },
+        {
+            "name": "scix_explain_query",
+            "description": "Show how ADS parses a search query, as an outline of fields, terms, and operators plus the raw parse tree. Use this when a search returns far more or fewer results than expected, to spot unquoted names, misspelled fields, or misplaced operators.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "ADS search query to explain" },
+                    "format": { "type": "string", "enum": ["text", "json"], "description": "Output format: 'text' (default, outline plus raw tree) or 'json' (raw tree only)", "default": "text" }
+                },
+                "required": ["query"]
+            },
+            "annotations": {
+                "readOnlyHint": true,
+                "destructiveHint": false,
+                "idempotentHint": true,
+                "openWorldHint": true
+            }
         }
     ])
 }
@@ -899,6 +1248,9 @@ Common search fields:
   object       - Astronomical object name
   orcid        - Author ORCID
   keyword      - Keywords
+  aff          - Affiliation text as printed (e.g., aff:"Harvard")
+  aff_id       - Canonical affiliation ID (e.g., aff_id:A00211)
+  inst         - Curated institution abbreviation (e.g., inst:"CfA")
   full         - Full text search
   property     - Paper properties (refereed, openaccess, etc.)
   doctype      - Document type (article, inproceedings, etc.)
@@ -906,7 +1258,7 @@ Common search fields:
 Common returnable fields:
   bibcode, title, author, year, pub, abstract, doi, identifier,
   doctype, esources, citation_count, reference, property, aff,
-  orcid_pub, keyword, volume, page, read_count
+  orcid_pub, keyword, volume, page, read_count, lang
 "#;
 
 const SYNTAX_REFERENCE: &str = r#"SciX Query Syntax Guide
@@ -930,6 +1282,7 @@ Functional operators:
   similar(bibcode:XXX)        - Content-similar papers
   trending(bibcode:XXX)       - Trending co-reads
   reviews(bibcode:XXX)        - Review articles
+  useful(bibcode:(X OR Y))    - Papers most useful for understanding X and Y
 
 Wildcards:
   author:"Eins*"              - Prefix matching
@@ -950,29 +1303,14 @@ Sort options:
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Author, Library, Paper, SearchResponse};
+    use crate::mock::MockServer;
+    use crate::types::{Library, Paper, SearchResponse};
 
     fn make_paper(bibcode: &str, title: &str, authors: &[&str], year: u16) -> Paper {
-        Paper {
-            bibcode: bibcode.to_string(),
-            title: title.to_string(),
-            authors: authors
-                .iter()
-                .map(|name| Author::from_ads_format(name))
-                .collect(),
-            year: Some(year),
-            publication: None,
-            citation_count: None,
-            doi: None,
-            arxiv_id: None,
-            abstract_text: None,
-            doctype: None,
-            identifiers: vec![],
-            esources: vec![],
-            pdf_links: vec![],
-            properties: vec![],
-            url: String::new(),
-        }
+        Paper::test_stub(bibcode)
+            .with_title(title)
+            .with_authors(authors)
+            .with_year(year)
     }
 
     fn make_library(id: &str, name: &str, num_documents: u32, description: &str) -> Library {
@@ -988,6 +1326,371 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_truncate_output_oversized() {
+        let text = "é".repeat(10_000);
+        let out = truncate_output(text, 100);
+        assert_eq!(out.chars().count(), 100);
+        assert!(out.ends_with("...(truncated, refine your query)"));
+        assert!(out.starts_with("éé"));
+    }
+
+    #[test]
+    fn test_truncate_output_within_budget() {
+        assert_eq!(truncate_output("short".to_string(), 100), "short");
+        assert_eq!(truncate_output("x".repeat(200), 0).len(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_search_tool_json_output() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 42, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}]}}"#,
+            )
+            .start()
+            .await;
+        let client = mock.client();
+
+        let params =
+            json!({ "name": "scix_search", "arguments": { "query": "gw", "format": "json" } });
+        let response = handle_tool_call(&client, &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        let parsed: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed["num_found"], 42);
+        assert_eq!(parsed["papers"][0]["bibcode"], "2016PhRvL.116f1102A");
+
+        let params = json!({ "name": "scix_search", "arguments": { "query": "gw" } });
+        let response = handle_tool_call(&client, &json!(2), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(serde_json::from_str::<Value>(text).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_json_output_stays_valid() {
+        let docs: Vec<Value> = (0..200)
+            .map(|i| json!({ "bibcode": format!("2016PhRvL.116f{:04}A", i), "title": ["x".repeat(100)] }))
+            .collect();
+        let body = json!({ "response": { "numFound": 5000, "start": 0, "docs": docs } });
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body.to_string())
+            .start()
+            .await;
+
+        let params = json!({
+            "name": "scix_search",
+            "arguments": { "query": "gw", "rows": 200, "format": "json" }
+        });
+        let response = handle_tool_call(&mock.client(), &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.chars().count() <= DEFAULT_MAX_OUTPUT_CHARS);
+        let parsed: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed["truncated"], true);
+        assert_eq!(parsed["num_found"], 5000);
+        let papers = parsed["papers"].as_array().unwrap();
+        assert!(!papers.is_empty() && papers.len() < 200);
+    }
+
+    #[test]
+    fn test_truncate_json_output_falls_back_for_text() {
+        let out = truncate_json_output("é".repeat(10_000), 100);
+        assert!(out.ends_with("...(truncated, refine your query)"));
+        let small = json!({ "papers": [1, 2, 3] }).to_string();
+        assert_eq!(truncate_json_output(small.clone(), 100), small);
+    }
+
+    #[tokio::test]
+    async fn test_search_tool_accepts_sort_phrases() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 0, "start": 0, "docs": []}}"#,
+            )
+            .start()
+            .await;
+        let client = mock.client();
+
+        for (i, sort) in ["most cited", "oldest", "read_count asc"]
+            .iter()
+            .enumerate()
+        {
+            let params =
+                json!({ "name": "scix_search", "arguments": { "query": "gw", "sort": sort } });
+            handle_tool_call(&client, &json!(i), &params).await;
+        }
+        let requests = mock.requests();
+        assert!(requests[0].target.contains("sort=citation_count+desc"));
+        assert!(requests[1].target.contains("sort=date+asc"));
+        assert!(requests[2].target.contains("sort=read_count+asc"));
+
+        let params =
+            json!({ "name": "scix_search", "arguments": { "query": "gw", "sort": "most wow" } });
+        let response = handle_tool_call(&client, &json!(9), &params).await;
+        assert_eq!(response["result"]["isError"], true);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bibcodes_accept_a_single_string() {
+        let mock = MockServer::new()
+            .route("POST", "/metrics", "{}")
+            .route(
+                "POST",
+                "/export/bibtex",
+                r#"{"export": "@ARTICLE{2016PhRvL.116f1102A,}"}"#,
+            )
+            .start()
+            .await;
+        let client = mock.client();
+
+        let params = json!({
+            "name": "scix_metrics",
+            "arguments": { "bibcodes": "2016PhRvL.116f1102A, 2017PhRvL.119p1101A" }
+        });
+        let response = handle_tool_call(&client, &json!(1), &params).await;
+        assert!(response["result"]["isError"].is_null(), "{}", response);
+
+        let params = json!({
+            "name": "scix_export",
+            "arguments": { "bibcodes": "2016PhRvL.116f1102A 2017PhRvL.119p1101A\n" }
+        });
+        let response = handle_tool_call(&client, &json!(2), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("@ARTICLE{2016PhRvL.116f1102A"));
+
+        let expected = json!(["2016PhRvL.116f1102A", "2017PhRvL.119p1101A"]);
+        let requests = mock.requests();
+        assert_eq!(requests[0].json()["bibcodes"], expected);
+        assert_eq!(requests[1].json()["bibcode"], expected);
+    }
+
+    #[test]
+    fn test_list_arg_separators() {
+        let args = json!({
+            "objects": "M31, NGC 1234;Crab Nebula",
+            "references": "Einstein, A. 1905, AnP, 17, 891\nRiess, A. G. et al. 1998, AJ, 116, 1009",
+            "bibcodes": 42
+        });
+        assert_eq!(
+            list_arg(&args, "objects", NAMES).unwrap(),
+            ["M31", "NGC 1234", "Crab Nebula"]
+        );
+        assert_eq!(list_arg(&args, "references", LINES).unwrap().len(), 2);
+        let err = list_arg(&args, "bibcodes", IDENTIFIERS).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("'bibcodes' required: an array of strings"));
+    }
+
+    #[tokio::test]
+    async fn test_neighborhood_tool() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 40, "start": 0, "docs": [{"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]}}"#,
+            )
+            .start()
+            .await;
+
+        let params = json!({
+            "name": "scix_neighborhood",
+            "arguments": { "bibcode": "2011RvMP...83..835K", "rows": 1 }
+        });
+        let response = handle_tool_call(&mock.client(), &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("## References of 2011RvMP...83..835K (1 of 40)"));
+        assert!(text.contains("## Citations of 2011RvMP...83..835K (1 of 40)"));
+        assert_eq!(text.matches("Bibcode: 1998AJ....116.1009R").count(), 2);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dossier_tool() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 40, "start": 0, "docs": [{"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]}}"#,
+            )
+            .route(
+                "POST",
+                "/metrics",
+                r#"{"citation_stats": {"total": {"total_citations": 30, "number_of_self_citations": 2}}, "indicators": {"h": 1}}"#,
+            )
+            .start()
+            .await;
+
+        let params = json!({
+            "name": "scix_dossier",
+            "arguments": { "bibcode": "1998AJ....116.1009R", "rows": 1 }
+        });
+        let response = handle_tool_call(&mock.client(), &json!(1), &params).await;
+        assert!(response["result"]["isError"].is_null());
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.starts_with("# Supernovae"));
+        assert!(text.contains("Excluding self-citations: 28"));
+        assert!(text.contains("h-index: 1"));
+        assert!(text.contains("## References (1 of 40)"));
+        assert!(text.contains("## Citations (1 of 40)"));
+        // The resolver isn't mocked, so links are reported as unavailable.
+        assert!(text.contains("## Unavailable\n\n- links: "));
+        assert_eq!(mock.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_explain_query_tool() {
+        let tree = json!({
+            "name": "OPERATOR", "label": "DEFOP", "children": [
+                {"name": "FIELD", "children": [
+                    {"name": "TERM_NORMAL", "input": "author"},
+                    {"name": "QNORMAL", "input": "Le"}
+                ]},
+                {"name": "QNORMAL", "input": "Fevre"}
+            ]
+        });
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/qtree",
+                json!({ "qtree": tree.to_string() }).to_string(),
+            )
+            .start()
+            .await;
+
+        let params = json!({
+            "name": "scix_explain_query",
+            "arguments": { "query": "author:Le Fevre" }
+        });
+        let response = handle_tool_call(&mock.client(), &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("OPERATOR DEFOP\n  FIELD\n    TERM_NORMAL: author"));
+        assert!(text.contains("Raw tree:"));
+
+        let params = json!({
+            "name": "scix_explain_query",
+            "arguments": { "query": "author:Le Fevre", "format": "json" }
+        });
+        let response = handle_tool_call(&mock.client(), &json!(2), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert_eq!(serde_json::from_str::<Value>(text).unwrap(), tree);
+    }
+
+    #[tokio::test]
+    async fn test_library_export_tool() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/biblib/libraries/lib123",
+                r#"{"metadata": {"name": "GW", "num_documents": 2},
+                    "documents": ["2016PhRvL.116f1102A", "2017PhRvL.119p1101A"]}"#,
+            )
+            .route(
+                "POST",
+                "/export/bibtex",
+                r#"{"export": "@ARTICLE{2016PhRvL.116f1102A,}\n@ARTICLE{2017PhRvL.119p1101A,}"}"#,
+            )
+            .route(
+                "GET",
+                "/biblib/libraries/empty",
+                r#"{"metadata": {"name": "Empty", "num_documents": 0}, "documents": []}"#,
+            )
+            .start()
+            .await;
+        let client = mock.client();
+
+        let params =
+            json!({ "name": "scix_library_export", "arguments": { "library_id": "lib123" } });
+        let response = handle_tool_call(&client, &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("@ARTICLE{2017PhRvL.119p1101A"));
+
+        let requests = mock.requests();
+        assert!(requests[0].target.contains("start=0"));
+        assert_eq!(requests[1].json()["bibcode"][1], "2017PhRvL.119p1101A");
+
+        let params =
+            json!({ "name": "scix_library_export", "arguments": { "library_id": "empty" } });
+        let response = handle_tool_call(&client, &json!(2), &params).await;
+        assert_eq!(
+            response["result"]["content"][0]["text"],
+            "Library 'Empty' has no documents to export."
+        );
+        assert!(response["result"]["isError"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_tool_call_result_truncated() {
+        // Unknown tool names error without touching the network.
+        let client = SciXClient::new("token");
+        let params = json!({ "name": "x".repeat(20_000), "arguments": {} });
+        let response = handle_tool_call(&client, &json!(1), &params).await;
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.chars().count() <= max_output_chars());
+        assert!(text.ends_with("...(truncated, refine your query)"));
+    }
+
+    #[tokio::test]
+    async fn test_resource_templates_list() {
+        let client = SciXClient::new("token");
+        let request = json!({"jsonrpc": "2.0", "id": 1, "method": "resources/templates/list"});
+        let response = handle_message(&client, &request).await.unwrap();
+        let templates = response["result"]["resourceTemplates"].as_array().unwrap();
+        assert_eq!(templates[0]["uriTemplate"], "scix://paper/{bibcode}");
+    }
+
+    #[tokio::test]
+    async fn test_read_paper_resource() {
+        let mock = MockServer::new()
+            .route(
+                "GET",
+                "/search/query",
+                r#"{"response": {"numFound": 1, "docs": [{"bibcode": "2023ApJ...123..456A", "title": ["A Paper"], "author": ["Smith, J."], "year": "2023"}]}}"#,
+            )
+            .start()
+            .await;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": "resources/read",
+            "params": { "uri": "scix://paper/2023ApJ...123..456A" }
+        });
+        let response = handle_message(&mock.client(), &request).await.unwrap();
+        let contents = &response["result"]["contents"][0];
+        assert_eq!(contents["uri"], "scix://paper/2023ApJ...123..456A");
+        let text = contents["text"].as_str().unwrap();
+        assert!(text.contains("A Paper"));
+        assert!(text.contains("2023ApJ...123..456A"));
+        assert!(mock.requests()[0]
+            .target
+            .contains("q=identifier%3A2023ApJ...123..456A"));
+    }
+
+    #[tokio::test]
+    async fn test_read_unknown_resource() {
+        let client = SciXClient::new("token");
+        for uri in ["scix://nope", "scix://paper/"] {
+            let request = json!({
+                "jsonrpc": "2.0", "id": 1, "method": "resources/read", "params": { "uri": uri }
+            });
+            let response = handle_message(&client, &request).await.unwrap();
+            assert_eq!(response["error"]["code"], -32602);
+        }
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(
+            percent_decode("2020A%26A...641A...6P"),
+            "2020A&A...641A...6P"
+        );
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+
     #[test]
     fn test_format_search_results_basic() {
         let results = SearchResponse {
@@ -1006,6 +1709,7 @@ mod tests {
                 ),
             ],
             num_found: 2,
+            meta: None,
         };
 
         let output = format_search_results(&results, 0);
@@ -1032,6 +1736,7 @@ mod tests {
         let results = SearchResponse {
             papers: vec![paper],
             num_found: 1,
+            meta: None,
         };
 
         let output = format_search_results(&results, 0);
@@ -1050,6 +1755,7 @@ mod tests {
         let results = SearchResponse {
             papers: vec![paper],
             num_found: 1,
+            meta: None,
         };
 
         let output = format_search_results(&results, 0);
@@ -1062,6 +1768,7 @@ mod tests {
         let results = SearchResponse {
             papers: vec![make_paper("2020X...", "Paper", &["Auth, A."], 2020)],
             num_found: 100,
+            meta: None,
         };
 
         let output = format_search_results(&results, 0);
@@ -1073,6 +1780,7 @@ mod tests {
         let results = SearchResponse {
             papers: vec![make_paper("2020X...", "Paper", &["Auth, A."], 2020)],
             num_found: 50,
+            meta: None,
         };
 
         let output = format_search_results(&results, 10);
@@ -1085,6 +1793,7 @@ mod tests {
         let results = SearchResponse {
             papers: vec![],
             num_found: 0,
+            meta: None,
         };
 
         let output = format_search_results(&results, 0);
@@ -0,0 +1,587 @@
+//! MCP Streamable HTTP transport (requires the `http` feature).
+//!
+//! Serves the same JSON-RPC dispatch as the stdio server on a single `/mcp`
+//! endpoint. Clients POST one JSON-RPC message per request and get the
+//! response back as JSON, or as a one-event SSE stream when they only
+//! accept `text/event-stream`.
+//!
+//! `initialize` opens a session: the response carries an `Mcp-Session-Id`
+//! header that every later request must echo. `DELETE /mcp` ends it, and
+//! idle sessions expire. Server-initiated streams (`GET /mcp`) are not
+//! offered, since no tool sends notifications.
+//!
+//! Every tool call runs with this process's ADS token, including library
+//! edits and deletions. Without a bearer token the server therefore only
+//! listens on loopback addresses; see [`HttpServer::bind`].
+
+use crate::client::SciXClient;
+use crate::error::{Result, SciXError};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{
+    AsHeaderName, HeaderValue, ACCEPT, ALLOW, AUTHORIZATION, CACHE_CONTROL, CONTENT_TYPE, ORIGIN,
+    WWW_AUTHENTICATE,
+};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+
+/// Path of the MCP endpoint.
+pub const ENDPOINT: &str = "/mcp";
+
+/// Largest request body accepted, in bytes.
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+/// Most header fields accepted on one request.
+const MAX_HEADERS: usize = 64;
+
+/// Read buffer size, which bounds the request line plus headers, in bytes.
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+
+/// Time allowed to receive a request's headers.
+const HEADER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for a whole connection, including the tool call it makes.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Most sessions open at once.
+const MAX_SESSIONS: usize = 256;
+
+/// Sessions unused for this long are dropped.
+const SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+
+const SESSION_HEADER: &str = "mcp-session-id";
+
+type Body = Full<Bytes>;
+
+/// An MCP server bound to a TCP address.
+pub struct HttpServer {
+    listener: TcpListener,
+    auth_token: Option<String>,
+}
+
+impl HttpServer {
+    /// Bind to `addr` (e.g. `127.0.0.1:8080`).
+    ///
+    /// With `auth_token`, every request must carry
+    /// `Authorization: Bearer <token>`. Without one, only loopback addresses
+    /// are allowed, since anyone who can reach the server acts with this
+    /// process's ADS token.
+    pub async fn bind(addr: &str, auth_token: Option<String>) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|e| SciXError::Config(format!("cannot bind {}: {}", addr, e)))?;
+        Self::from_listener(listener, auth_token)
+    }
+
+    /// Serve on an already-bound listener, under the same rules as [`Self::bind`].
+    pub fn from_listener(listener: TcpListener, auth_token: Option<String>) -> Result<Self> {
+        let auth_token = auth_token.filter(|token| !token.is_empty());
+        let addr = local_addr(&listener)?;
+        if auth_token.is_none() && !addr.ip().is_loopback() {
+            return Err(SciXError::Config(format!(
+                "refusing to serve MCP on non-loopback address {} without an auth token",
+                addr
+            )));
+        }
+        Ok(Self {
+            listener,
+            auth_token,
+        })
+    }
+
+    /// The address the server is listening on.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        local_addr(&self.listener)
+    }
+
+    /// Accept and serve connections until accepting fails.
+    pub async fn run(self, client: SciXClient) -> Result<()> {
+        let state = Arc::new(State {
+            client,
+            auth_token: self.auth_token,
+            sessions: Mutex::default(),
+        });
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .await
+                .map_err(|e| SciXError::Config(format!("accept error: {}", e)))?;
+            let state = state.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(handle_request(&state, request).await) }
+                });
+                let connection = http1::Builder::new()
+                    .timer(TokioTimer::new())
+                    .header_read_timeout(HEADER_TIMEOUT)
+                    .max_headers(MAX_HEADERS)
+                    .max_buf_size(MAX_HEAD_BYTES)
+                    .keep_alive(false)
+                    .serve_connection(TokioIo::new(stream), service);
+                // A broken or stalled connection only affects that client.
+                let _ = tokio::time::timeout(CONNECTION_TIMEOUT, connection).await;
+            });
+        }
+    }
+}
+
+fn local_addr(listener: &TcpListener) -> Result<SocketAddr> {
+    listener
+        .local_addr()
+        .map_err(|e| SciXError::Config(format!("cannot read listener address: {}", e)))
+}
+
+/// State shared by every connection.
+struct State {
+    client: SciXClient,
+    auth_token: Option<String>,
+    sessions: Mutex<Sessions>,
+}
+
+/// Open sessions and when each was last used.
+#[derive(Default)]
+struct Sessions {
+    last_used: HashMap<String, Instant>,
+}
+
+impl Sessions {
+    /// Open a session, or `None` when [`MAX_SESSIONS`] are already open.
+    fn open(&mut self) -> Option<String> {
+        self.expire();
+        if self.last_used.len() >= MAX_SESSIONS {
+            return None;
+        }
+        let id = new_session_id()?;
+        self.last_used.insert(id.clone(), Instant::now());
+        Some(id)
+    }
+
+    /// Mark a session as used; `false` if it doesn't exist or has expired.
+    fn touch(&mut self, id: &str) -> bool {
+        self.expire();
+        match self.last_used.get_mut(id) {
+            Some(used) => {
+                *used = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn close(&mut self, id: &str) -> bool {
+        self.last_used.remove(id).is_some()
+    }
+
+    fn expire(&mut self) {
+        self.last_used
+            .retain(|_, used| used.elapsed() < SESSION_TTL);
+    }
+}
+
+async fn handle_request(state: &State, request: Request<Incoming>) -> Response<Body> {
+    if request.uri().path() != ENDPOINT {
+        return empty(StatusCode::NOT_FOUND);
+    }
+    // Guard against DNS rebinding: browsers send Origin, local tools don't.
+    if let Some(origin) = header(&request, ORIGIN) {
+        if !is_local_origin(origin) {
+            return error(StatusCode::FORBIDDEN, -32600, "Origin not allowed");
+        }
+    }
+    if let Some(token) = &state.auth_token {
+        let authorized = header(&request, AUTHORIZATION)
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()));
+        if !authorized {
+            let mut response = error(StatusCode::UNAUTHORIZED, -32001, "Unauthorized");
+            response
+                .headers_mut()
+                .insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            return response;
+        }
+    }
+
+    match *request.method() {
+        Method::POST => handle_post(state, request).await,
+        Method::DELETE => match header(&request, SESSION_HEADER) {
+            Some(id) if state.sessions.lock().await.close(id) => empty(StatusCode::OK),
+            Some(_) => empty(StatusCode::NOT_FOUND),
+            None => error(
+                StatusCode::BAD_REQUEST,
+                -32600,
+                "Missing Mcp-Session-Id header",
+            ),
+        },
+        _ => {
+            let mut response = empty(StatusCode::METHOD_NOT_ALLOWED);
+            response
+                .headers_mut()
+                .insert(ALLOW, HeaderValue::from_static("POST, DELETE"));
+            response
+        }
+    }
+}
+
+async fn handle_post(state: &State, request: Request<Incoming>) -> Response<Body> {
+    let accept = header(&request, ACCEPT).unwrap_or("").to_string();
+    let session = header(&request, SESSION_HEADER).map(str::to_string);
+
+    let body = match Limited::new(request.into_body(), MAX_BODY_BYTES)
+        .collect()
+        .await
+    {
+        Ok(body) => body.to_bytes(),
+        Err(e) if e.is::<LengthLimitError>() => {
+            return error(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                -32600,
+                "Request body too large",
+            )
+        }
+        Err(_) => return error(StatusCode::BAD_REQUEST, -32600, "Unreadable request body"),
+    };
+    let message: Value = match serde_json::from_slice(&body) {
+        Ok(v) => v,
+        Err(e) => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                -32700,
+                &format!("Parse error: {}", e),
+            )
+        }
+    };
+
+    let mut session_header = None;
+    if message["method"] == "initialize" {
+        let Some(id) = state.sessions.lock().await.open() else {
+            return error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                -32000,
+                "Too many open sessions",
+            );
+        };
+        session_header = Some(id);
+    } else {
+        match session {
+            Some(id) if state.sessions.lock().await.touch(&id) => {}
+            Some(_) => return error(StatusCode::NOT_FOUND, -32001, "Session not found"),
+            None => {
+                return error(
+                    StatusCode::BAD_REQUEST,
+                    -32600,
+                    "Missing Mcp-Session-Id header",
+                )
+            }
+        }
+    }
+
+    let Some(reply) = super::handle_message(&state.client, &message).await else {
+        return empty(StatusCode::ACCEPTED);
+    };
+
+    let mut response =
+        if accept.contains("text/event-stream") && !accept.contains("application/json") {
+            sse(&reply)
+        } else {
+            json_response(StatusCode::OK, &reply)
+        };
+    if let Some(id) = session_header.and_then(|id| HeaderValue::from_str(&id).ok()) {
+        response.headers_mut().insert(SESSION_HEADER, id);
+    }
+    response
+}
+
+fn header<B>(request: &Request<B>, name: impl AsHeaderName) -> Option<&str> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+fn respond(status: StatusCode, content_type: Option<&'static str>, body: String) -> Response<Body> {
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    if let Some(content_type) = content_type {
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    }
+    response
+}
+
+fn empty(status: StatusCode) -> Response<Body> {
+    respond(status, None, String::new())
+}
+
+fn json_response(status: StatusCode, body: &Value) -> Response<Body> {
+    respond(status, Some("application/json"), body.to_string())
+}
+
+fn sse(body: &Value) -> Response<Body> {
+    let mut response = respond(
+        StatusCode::OK,
+        Some("text/event-stream"),
+        format!("event: message\ndata: {}\n\n", body),
+    );
+    response
+        .headers_mut()
+        .insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    response
+}
+
+fn error(status: StatusCode, code: i64, message: &str) -> Response<Body> {
+    json_response(
+        status,
+        &json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": { "code": code, "message": message }
+        }),
+    )
+}
+
+/// Whether an `Origin` header names this machine.
+fn is_local_origin(origin: &str) -> bool {
+    let host = origin
+        .split("://")
+        .nth(1)
+        .unwrap_or(origin)
+        .trim_end_matches('/');
+    let host = match host.strip_prefix('[') {
+        // IPv6 literal, e.g. [::1]:8080
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host.split(':').next().unwrap_or(""),
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "::1")
+}
+
+/// Compare secrets without stopping at the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// A session identifier: 128 bits from the OS random number generator, hex.
+fn new_session_id() -> Option<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).ok()?;
+    Some(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn start_server(auth_token: Option<&str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server = HttpServer::from_listener(listener, auth_token.map(str::to_string)).unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run(SciXClient::new("token")));
+        format!("http://{}{}", addr, ENDPOINT)
+    }
+
+    fn rpc(id: u64, method: &str) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": {} })
+    }
+
+    #[tokio::test]
+    async fn test_initialize_then_tools_list() {
+        let url = start_server(None).await;
+        let http = reqwest::Client::new();
+
+        let init = http
+            .post(&url)
+            .header("Accept", "application/json, text/event-stream")
+            .json(&rpc(1, "initialize"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(init.status(), 200);
+        let session = init.headers()["mcp-session-id"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(session.len(), 32);
+        let body: Value = init.json().await.unwrap();
+        assert_eq!(body["result"]["serverInfo"]["name"], "scix-mcp");
+
+        let initialized = http
+            .post(&url)
+            .header("Mcp-Session-Id", &session)
+            .json(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(initialized.status(), 202);
+
+        let list = http
+            .post(&url)
+            .header("Mcp-Session-Id", &session)
+            .json(&rpc(2, "tools/list"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(list.status(), 200);
+        let body: Value = list.json().await.unwrap();
+        assert_eq!(body["id"], 2);
+        let tools = body["result"]["tools"].as_array().unwrap();
+        let expected = super::super::tool_definitions();
+        assert_eq!(tools.len(), expected.as_array().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_sse_response() {
+        let url = start_server(None).await;
+        let response = reqwest::Client::new()
+            .post(&url)
+            .header("Accept", "text/event-stream")
+            .json(&rpc(1, "initialize"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        let text = response.text().await.unwrap();
+        let data = text
+            .strip_prefix("event: message\ndata: ")
+            .unwrap()
+            .trim_end();
+        let body: Value = serde_json::from_str(data).unwrap();
+        assert_eq!(body["id"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_session_required() {
+        let url = start_server(None).await;
+        let http = reqwest::Client::new();
+
+        let missing = http.post(&url).json(&rpc(1, "tools/list")).send().await;
+        assert_eq!(missing.unwrap().status(), 400);
+
+        let unknown = http
+            .post(&url)
+            .header("Mcp-Session-Id", "nope")
+            .json(&rpc(1, "tools/list"))
+            .send()
+            .await;
+        assert_eq!(unknown.unwrap().status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_delete_ends_session() {
+        let url = start_server(None).await;
+        let http = reqwest::Client::new();
+        let init = http.post(&url).json(&rpc(1, "initialize")).send().await;
+        let session = init.unwrap().headers()["mcp-session-id"].clone();
+
+        let deleted = http
+            .delete(&url)
+            .header("Mcp-Session-Id", session.clone())
+            .send()
+            .await;
+        assert_eq!(deleted.unwrap().status(), 200);
+
+        let after = http
+            .post(&url)
+            .header("Mcp-Session-Id", session)
+            .json(&rpc(2, "tools/list"))
+            .send()
+            .await;
+        assert_eq!(after.unwrap().status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_required() {
+        let url = start_server(Some("s3cret")).await;
+        let http = reqwest::Client::new();
+
+        let anonymous = http.post(&url).json(&rpc(1, "initialize")).send().await;
+        let anonymous = anonymous.unwrap();
+        assert_eq!(anonymous.status(), 401);
+        assert_eq!(anonymous.headers()["www-authenticate"], "Bearer");
+
+        let wrong = http
+            .post(&url)
+            .bearer_auth("guess")
+            .json(&rpc(1, "initialize"))
+            .send()
+            .await;
+        assert_eq!(wrong.unwrap().status(), 401);
+
+        let authorized = http
+            .post(&url)
+            .bearer_auth("s3cret")
+            .json(&rpc(1, "initialize"))
+            .send()
+            .await;
+        assert_eq!(authorized.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_non_loopback_requires_token() {
+        let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+        let err = HttpServer::from_listener(listener, None).err().unwrap();
+        assert!(matches!(err, SciXError::Config(ref msg) if msg.contains("non-loopback")));
+
+        let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+        assert!(HttpServer::from_listener(listener, Some("s3cret".into())).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_too_many_headers_rejected() {
+        let url = start_server(None).await;
+        let mut request = reqwest::Client::new()
+            .post(&url)
+            .json(&rpc(1, "initialize"));
+        for i in 0..MAX_HEADERS {
+            request = request.header(format!("x-filler-{}", i), "1");
+        }
+        let response = request.send().await.unwrap();
+        assert_eq!(response.status(), 431);
+    }
+
+    #[test]
+    fn test_sessions_are_capped_and_expire() {
+        let mut sessions = Sessions::default();
+        let first = sessions.open().unwrap();
+        assert!(sessions.touch(&first));
+        for _ in 1..MAX_SESSIONS {
+            sessions.open().unwrap();
+        }
+        assert!(sessions.open().is_none());
+
+        // Once a session has been idle past the TTL it is gone, freeing a slot.
+        let idle = Instant::now().checked_sub(SESSION_TTL).unwrap();
+        sessions.last_used.insert(first.clone(), idle);
+        assert!(!sessions.touch(&first));
+        assert!(sessions.open().is_some());
+    }
+
+    #[test]
+    fn test_session_ids_are_random() {
+        let a = new_session_id().unwrap();
+        let b = new_session_id().unwrap();
+        assert_eq!(a.len(), 32);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_is_local_origin() {
+        assert!(is_local_origin("http://localhost:3000"));
+        assert!(is_local_origin("http://127.0.0.1"));
+        assert!(is_local_origin("http://[::1]:8080"));
+        assert!(!is_local_origin("https://evil.example.com"));
+        assert!(!is_local_origin("http://localhost.evil.example.com"));
+    }
+}
@@ -2,20 +2,678 @@
 
 use crate::client::SciXClient;
 use crate::error::{Result, SciXError};
-use crate::types::Metrics;
+use crate::types::{
+    BasicStats, BasicStatsEntry, CitationStats, CitationStatsEntry, Histograms, Indicators,
+    MetricType, Metrics, TimeSeriesPoint,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Most bibcodes sent to the metrics endpoint in one request.
+const METRICS_MAX_BIBCODES: usize = 2000;
+
+/// Rows fetched per search page when collecting bibcodes (the ADS maximum).
+const SEARCH_PAGE_ROWS: u32 = 2000;
 
 impl SciXClient {
     /// Get citation metrics for a set of papers.
     ///
     /// Returns h-index, g-index, citation counts, and other bibliometric indicators.
     pub async fn metrics(&self, bibcodes: &[&str]) -> Result<Metrics> {
+        self.metrics_with_types(
+            bibcodes,
+            &[
+                MetricType::Basic,
+                MetricType::Citations,
+                MetricType::Indicators,
+            ],
+        )
+        .await
+    }
+
+    /// Get only the requested groups of metrics for a set of papers.
+    ///
+    /// Only the requested types are computed and sent, so asking for just
+    /// [`MetricType::Indicators`] is much lighter than [`Self::metrics`].
+    /// Groups that weren't requested come back as `None`. Use this for
+    /// [`MetricType::Histograms`] and [`MetricType::TimeSeries`], which
+    /// [`Self::metrics`] leaves out.
+    pub async fn metrics_with_types(
+        &self,
+        bibcodes: &[&str],
+        types: &[MetricType],
+    ) -> Result<Metrics> {
+        let body = metrics_request(bibcodes, types);
+        let response_body = self.post_json("/metrics", &body).await?;
+        serde_json::from_str(&response_body)
+            .map_err(|e| SciXError::parse("/metrics", e, &response_body))
+    }
+
+    /// Get citation metrics for up to `max` papers matching a query.
+    ///
+    /// Runs the search to collect bibcodes (most-cited first), then calls the
+    /// metrics endpoint. Beyond 2000 papers the bibcodes are sent in chunks
+    /// and the results merged: counts and sums are added, means re-weighted,
+    /// and `h`/`g` recomputed from the papers' citation counts. Medians,
+    /// `m`, and `riq` can't be merged and come back as `None`, and
+    /// `number_of_citing_papers` may count a paper citing several chunks
+    /// more than once.
+    ///
+    /// Metrics describe whatever the query matches: an `author:` query
+    /// includes everyone sharing the name. Use `orcid:` or tighter filters
+    /// when the set of papers matters.
+    pub async fn metrics_for_query(&self, query: &str, max: u32) -> Result<Metrics> {
+        self.metrics_for_query_chunked(query, max, METRICS_MAX_BIBCODES)
+            .await
+    }
+
+    async fn metrics_for_query_chunked(
+        &self,
+        query: &str,
+        max: u32,
+        chunk_size: usize,
+    ) -> Result<Metrics> {
+        let papers = self.collect_citation_counts(query, max).await?;
+        if papers.is_empty() {
+            return Ok(Metrics::default());
+        }
+
+        let mut chunks = Vec::new();
+        for chunk in papers.chunks(chunk_size) {
+            let bibcodes: Vec<&str> = chunk.iter().map(|(b, _)| b.as_str()).collect();
+            chunks.push(self.metrics(&bibcodes).await?);
+        }
+        if chunks.len() == 1 {
+            return Ok(chunks.remove(0));
+        }
+
+        let citations: Vec<u32> = papers.iter().map(|(_, c)| *c).collect();
+        Ok(merge_metrics(&chunks, &citations))
+    }
+
+    /// Bibcodes and citation counts of up to `max` papers matching a query.
+    async fn collect_citation_counts(&self, query: &str, max: u32) -> Result<Vec<(String, u32)>> {
+        let mut papers = Vec::new();
+        while (papers.len() as u32) < max {
+            let rows = (max - papers.len() as u32)
+                .min(SEARCH_PAGE_ROWS)
+                .to_string();
+            let start = papers.len().to_string();
+            let params = [
+                ("q", query),
+                ("fl", "bibcode,citation_count"),
+                ("rows", rows.as_str()),
+                ("start", start.as_str()),
+                ("sort", "citation_count desc"),
+            ];
+            let body = self.get("/search/query", &params).await?;
+            let (num_found, page) = parse_citation_counts(&body)?;
+            if page.is_empty() {
+                break;
+            }
+            papers.extend(page);
+            if papers.len() as u64 >= num_found {
+                break;
+            }
+        }
+        papers.truncate(max as usize);
+        Ok(papers)
+    }
+
+    /// Get the cumulative citation count of a single paper, year by year.
+    ///
+    /// Returns `(year, total citations up to and including that year)`,
+    /// sorted by year.
+    pub async fn citation_growth(&self, bibcode: &str) -> Result<Vec<(u16, u32)>> {
         let body = serde_json::json!({
-            "bibcodes": bibcodes,
-            "types": ["basic", "citations", "indicators"],
+            "bibcodes": [bibcode],
+            "types": ["histograms"],
+            "histograms": ["citations"],
         });
 
         let response_body = self.post_json("/metrics", &body).await?;
-        serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid metrics response: {}", e)))
+        parse_citation_growth(&response_body)
+    }
+}
+
+/// Request body asking the metrics endpoint for `types` of `bibcodes`.
+fn metrics_request(bibcodes: &[&str], types: &[MetricType]) -> serde_json::Value {
+    let types: Vec<&str> = types.iter().map(MetricType::as_api_str).collect();
+    serde_json::json!({
+        "bibcodes": bibcodes,
+        "types": types,
+    })
+}
+
+/// Deserialize [`Metrics::histograms`], skipping anything that isn't a
+/// number nested three objects deep.
+pub(crate) fn deserialize_histograms<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Histograms>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(raw) = Option::<serde_json::Value>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let objects = |value: &serde_json::Value| {
+        value
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>()
+    };
+    let mut histograms = Histograms::new();
+    for (name, series) in objects(&raw) {
+        let histogram = histograms.entry(name).or_default();
+        for (series_name, years) in objects(&series) {
+            let counts: BTreeMap<String, f64> = objects(&years)
+                .into_iter()
+                .filter_map(|(year, count)| Some((year, count.as_f64()?)))
+                .collect();
+            histogram.insert(series_name, counts);
+        }
+    }
+    Ok(Some(histograms))
+}
+
+/// Deserialize [`Metrics::time_series`] from either the ADS shape, one
+/// year-keyed map per indicator (`{"h": {"2019": 3, ...}, ...}`), or a list
+/// of points as serialized by this crate.
+pub(crate) fn deserialize_time_series<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<TimeSeriesPoint>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Shape {
+        Points(Vec<TimeSeriesPoint>),
+        ByIndicator(BTreeMap<String, BTreeMap<String, Option<f64>>>),
+    }
+
+    let by_indicator = match Option::<Shape>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(Shape::Points(points)) => return Ok(Some(points)),
+        Some(Shape::ByIndicator(series)) => series,
+    };
+    let mut points: BTreeMap<u16, TimeSeriesPoint> = BTreeMap::new();
+    for (indicator, years) in by_indicator {
+        for (year, value) in years {
+            let Ok(year) = year.parse::<u16>() else {
+                continue;
+            };
+            let point = points.entry(year).or_insert_with(|| TimeSeriesPoint {
+                year,
+                ..TimeSeriesPoint::default()
+            });
+            let count = value.map(|v| v.round().max(0.0) as u32);
+            match indicator.as_str() {
+                "h" => point.h = count,
+                "g" => point.g = count,
+                "i10" => point.i10 = count,
+                "i100" => point.i100 = count,
+                "read10" => point.read10 = value,
+                "tori" => point.tori = value,
+                _ => {}
+            }
+        }
+    }
+    Ok(Some(points.into_values().collect()))
+}
+
+/// Parse a `fl=bibcode,citation_count` search page.
+fn parse_citation_counts(json: &str) -> Result<(u64, Vec<(String, u32)>)> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| SciXError::parse("/search/query", e, json))?;
+    let response = &parsed["response"];
+    let papers = response["docs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|doc| {
+            let bibcode = doc["bibcode"].as_str()?.to_string();
+            let citations = doc["citation_count"].as_u64().unwrap_or(0) as u32;
+            Some((bibcode, citations))
+        })
+        .collect();
+    Ok((response["numFound"].as_u64().unwrap_or(0), papers))
+}
+
+/// Combine metrics computed over disjoint chunks of one set of papers.
+///
+/// `citations` holds the citation count of every paper across all chunks.
+fn merge_metrics(chunks: &[Metrics], citations: &[u32]) -> Metrics {
+    let basic = |pick: fn(&BasicStats) -> &Option<BasicStatsEntry>| {
+        let entries: Vec<(&BasicStatsEntry, Option<u32>)> = chunks
+            .iter()
+            .filter_map(|m| {
+                let stats = m.basic_stats.as_ref()?;
+                let refereed = stats.refereed.as_ref().and_then(|e| e.number_of_papers);
+                Some((pick(stats).as_ref()?, refereed))
+            })
+            .collect();
+        merge_basic(&entries)
+    };
+    let cites = |pick: fn(&CitationStats) -> &Option<CitationStatsEntry>,
+                 papers: fn(&BasicStats) -> &Option<BasicStatsEntry>| {
+        let entries: Vec<(&CitationStatsEntry, Option<u32>)> = chunks
+            .iter()
+            .filter_map(|m| {
+                let entry = pick(m.citation_stats.as_ref()?).as_ref()?;
+                let count = m
+                    .basic_stats
+                    .as_ref()
+                    .and_then(|b| papers(b).as_ref())
+                    .and_then(|e| e.number_of_papers);
+                Some((entry, count))
+            })
+            .collect();
+        merge_citation_stats(&entries)
+    };
+
+    let indicators: Vec<&Indicators> = chunks
+        .iter()
+        .filter_map(|m| m.indicators.as_ref())
+        .collect();
+
+    Metrics {
+        basic_stats: Some(BasicStats {
+            refereed: basic(|b| &b.refereed),
+            total: basic(|b| &b.total),
+        }),
+        citation_stats: Some(CitationStats {
+            refereed: cites(|c| &c.refereed, |b| &b.refereed),
+            total: cites(|c| &c.total, |b| &b.total),
+        }),
+        time_series: None,
+        histograms: None,
+        indicators: Some(Indicators {
+            h: Some(h_index(citations)),
+            g: Some(g_index(citations)),
+            i10: sum_u32(indicators.iter().map(|i| i.i10)),
+            i100: sum_u32(indicators.iter().map(|i| i.i100)),
+            m: None,
+            tori: sum_f64(indicators.iter().map(|i| i.tori)),
+            riq: None,
+            read10: sum_f64(indicators.iter().map(|i| i.read10)),
+        }),
+    }
+}
+
+/// Merge basic stats; each entry comes with its chunk's refereed paper
+/// count, which weights `mean_refereed_citations`.
+fn merge_basic(entries: &[(&BasicStatsEntry, Option<u32>)]) -> Option<BasicStatsEntry> {
+    if entries.is_empty() {
+        return None;
+    }
+    let mean = weighted_mean(
+        entries
+            .iter()
+            .map(|(e, refereed)| (e.mean_refereed_citations, *refereed)),
+    );
+    Some(BasicStatsEntry {
+        number_of_papers: sum_u32(entries.iter().map(|(e, _)| e.number_of_papers)),
+        normalized_paper_count: sum_f64(entries.iter().map(|(e, _)| e.normalized_paper_count)),
+        total_citations: sum_u32(entries.iter().map(|(e, _)| e.total_citations)),
+        total_normalized_citations: sum_f64(
+            entries.iter().map(|(e, _)| e.total_normalized_citations),
+        ),
+        median_refereed_citations: None,
+        mean_refereed_citations: mean,
+    })
+}
+
+fn merge_citation_stats(
+    entries: &[(&CitationStatsEntry, Option<u32>)],
+) -> Option<CitationStatsEntry> {
+    if entries.is_empty() {
+        return None;
+    }
+    Some(CitationStatsEntry {
+        number_of_citing_papers: sum_u32(entries.iter().map(|(e, _)| e.number_of_citing_papers)),
+        total_citations: sum_u32(entries.iter().map(|(e, _)| e.total_citations)),
+        number_of_self_citations: sum_u32(entries.iter().map(|(e, _)| e.number_of_self_citations)),
+        average_citations: weighted_mean(entries.iter().map(|(e, n)| (e.average_citations, *n))),
+        normalized_citations: sum_f64(entries.iter().map(|(e, _)| e.normalized_citations)),
+    })
+}
+
+/// Sum of the present values; `None` if none are present.
+fn sum_u32(values: impl Iterator<Item = Option<u32>>) -> Option<u32> {
+    values
+        .flatten()
+        .fold(None, |acc, v| Some(acc.unwrap_or(0) + v))
+}
+
+/// Sum of the present values; `None` if none are present.
+fn sum_f64(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    values
+        .flatten()
+        .fold(None, |acc, v| Some(acc.unwrap_or(0.0) + v))
+}
+
+/// Mean of per-chunk means weighted by chunk size; `None` if any is missing.
+fn weighted_mean(values: impl Iterator<Item = (Option<f64>, Option<u32>)>) -> Option<f64> {
+    let mut total = 0.0;
+    let mut weight = 0.0;
+    for (mean, n) in values {
+        let n = f64::from(n?);
+        total += mean? * n;
+        weight += n;
+    }
+    (weight > 0.0).then(|| total / weight)
+}
+
+/// Largest `h` such that `h` papers have at least `h` citations each.
+fn h_index(citations: &[u32]) -> u32 {
+    let mut sorted = citations.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted
+        .iter()
+        .enumerate()
+        .take_while(|(i, &c)| c as usize > *i)
+        .count() as u32
+}
+
+/// Largest `g` such that the top `g` papers have at least `g²` citations together.
+fn g_index(citations: &[u32]) -> u32 {
+    let mut sorted = citations.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let mut total: u64 = 0;
+    let mut g = 0;
+    for (i, &c) in sorted.iter().enumerate() {
+        total += u64::from(c);
+        let rank = i as u64 + 1;
+        if total >= rank * rank {
+            g = rank as u32;
+        }
+    }
+    g
+}
+
+/// Sum the raw (non-normalized) citation histogram series into a
+/// cumulative per-year count.
+fn parse_citation_growth(json: &str) -> Result<Vec<(u16, u32)>> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| SciXError::parse("/metrics", e, json))?;
+    let series = parsed["histograms"]["citations"]
+        .as_object()
+        .ok_or_else(|| SciXError::parse("/metrics", "missing citation histogram", json))?;
+
+    // ADS splits citations into refereed/non-refereed source and target
+    // series, plus normalized variants that would double-count.
+    let mut per_year: BTreeMap<u16, u32> = BTreeMap::new();
+    for (name, counts) in series {
+        if name.contains("normalized") {
+            continue;
+        }
+        for (year, count) in counts.as_object().into_iter().flatten() {
+            let Ok(year) = year.parse::<u16>() else {
+                continue;
+            };
+            *per_year.entry(year).or_insert(0) += count.as_f64().unwrap_or(0.0) as u32;
+        }
+    }
+
+    let mut total = 0;
+    Ok(per_year
+        .into_iter()
+        .map(|(year, count)| {
+            total += count;
+            (year, total)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    const SAMPLE_HISTOGRAM: &str = r#"{
+        "skipped bibcodes": [],
+        "histograms": {
+            "citations": {
+                "refereed to refereed": {"2016": 10, "2017": 40, "2018": 25},
+                "refereed to nonrefereed": {"2016": 0, "2017": 2, "2018": 1},
+                "nonrefereed to refereed": {"2016": 3, "2017": 5, "2018": 0},
+                "nonrefereed to nonrefereed": {"2016": 1, "2017": 0, "2018": 4},
+                "refereed to refereed normalized": {"2016": 0.5, "2017": 2.0, "2018": 1.25},
+                "nonrefereed to nonrefereed normalized": {"2016": 0.05, "2017": 0.0, "2018": 0.2}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_histograms() {
+        let metrics: Metrics = serde_json::from_str(SAMPLE_HISTOGRAM).unwrap();
+        let histograms = metrics.histograms.unwrap();
+        let citations = &histograms["citations"];
+        assert_eq!(citations["refereed to refereed"]["2017"], 40.0);
+        assert_eq!(citations["refereed to refereed normalized"]["2018"], 1.25);
+        assert!(metrics.basic_stats.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_with_types_dry_run_body() {
+        let client = SciXClient::new("token").with_dry_run(true);
+        let body = metrics_request(
+            &["2016PhRvL.116f1102A"],
+            &[MetricType::Indicators, MetricType::Histograms],
+        );
+        let out = client.post_json("/metrics", &body).await.unwrap();
+        let described: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            described["body"]["types"],
+            serde_json::json!(["indicators", "histograms"])
+        );
+        assert_eq!(
+            described["body"]["bibcodes"],
+            serde_json::json!(["2016PhRvL.116f1102A"])
+        );
+    }
+
+    #[test]
+    fn test_parse_citation_growth_cumulative() {
+        let growth = parse_citation_growth(SAMPLE_HISTOGRAM).unwrap();
+        assert_eq!(growth, vec![(2016, 14), (2017, 61), (2018, 91)]);
+    }
+
+    const SEARCH_PAGE: &str = r#"{"response": {"numFound": 3, "docs": [
+        {"bibcode": "2016PhRvL.116f1102A", "citation_count": 10},
+        {"bibcode": "2017PhRvL.119p1101A", "citation_count": 5},
+        {"bibcode": "2019PhRvX...9c1040A", "citation_count": 1}
+    ]}}"#;
+
+    const METRICS: &str = r#"{
+        "basic_stats": {
+            "total": {"number_of_papers": 3, "total_citations": 16, "mean_refereed_citations": 5.0},
+            "refereed": {"number_of_papers": 2, "total_citations": 15, "mean_refereed_citations": 5.0}
+        },
+        "citation_stats": {
+            "total": {"number_of_citing_papers": 14, "total_citations": 16, "average_citations": 5.0}
+        },
+        "indicators": {"h": 2, "g": 3, "i10": 1, "i100": 0, "tori": 1.5}
+    }"#;
+
+    #[tokio::test]
+    async fn test_metrics_for_query_round_trip() {
+        let mock = MockServer::new()
+            .route("GET", "/search/query", SEARCH_PAGE)
+            .route("POST", "/metrics", METRICS)
+            .start()
+            .await;
+
+        let metrics = mock
+            .client()
+            .metrics_for_query("gravitational waves", 10)
+            .await
+            .unwrap();
+        let expected: Metrics = serde_json::from_str(METRICS).unwrap();
+        assert_eq!(
+            serde_json::to_value(&metrics).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].target.contains("fl=bibcode%2Ccitation_count"));
+        assert!(requests[0].target.contains("rows=10"));
+        let body = requests[1].json();
+        assert_eq!(body["bibcodes"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_for_query_merges_chunks() {
+        let mock = MockServer::new()
+            .route("GET", "/search/query", SEARCH_PAGE)
+            .route("POST", "/metrics", METRICS)
+            .start()
+            .await;
+
+        let metrics = mock
+            .client()
+            .metrics_for_query_chunked("gravitational waves", 10, 2)
+            .await
+            .unwrap();
+
+        let requests = mock.requests();
+        let metrics_calls: Vec<_> = requests.iter().filter(|r| r.target == "/metrics").collect();
+        assert_eq!(metrics_calls.len(), 2);
+        assert_eq!(
+            metrics_calls[0].json()["bibcodes"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            metrics_calls[1].json()["bibcodes"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Each chunk got the same canned response, so sums double.
+        let indicators = metrics.indicators.unwrap();
+        assert_eq!(indicators.i10, Some(2));
+        assert_eq!(indicators.tori, Some(3.0));
+        let basic = metrics.basic_stats.unwrap().total.unwrap();
+        assert_eq!(basic.number_of_papers, Some(6));
+        assert_eq!(basic.mean_refereed_citations, Some(5.0));
+        let cites = metrics.citation_stats.unwrap().total.unwrap();
+        assert_eq!(cites.total_citations, Some(32));
+        assert_eq!(cites.average_citations, Some(5.0));
+        // h and g come from the papers' own citation counts (10, 5, 1).
+        assert_eq!(indicators.h, Some(2));
+        assert_eq!(indicators.g, Some(3));
+        assert_eq!(indicators.m, None);
+    }
+
+    #[test]
+    fn test_merge_basic_weights_mean_by_refereed_papers() {
+        let entry = |papers: u32, mean: f64| BasicStatsEntry {
+            number_of_papers: Some(papers),
+            mean_refereed_citations: Some(mean),
+            ..Default::default()
+        };
+        let (a, b) = (entry(10, 2.0), entry(10, 6.0));
+
+        // 1 and 3 refereed papers: (1 * 2 + 3 * 6) / 4.
+        let merged = merge_basic(&[(&a, Some(1)), (&b, Some(3))]).unwrap();
+        assert_eq!(merged.number_of_papers, Some(20));
+        assert_eq!(merged.mean_refereed_citations, Some(5.0));
+
+        // Without a chunk's refereed count there is no sound weighting.
+        let merged = merge_basic(&[(&a, Some(1)), (&b, None)]).unwrap();
+        assert_eq!(merged.mean_refereed_citations, None);
+    }
+
+    const TIME_SERIES: &str = r#"{
+        "skipped bibcodes": [],
+        "time series": {
+            "h": {"2018": 1, "2017": 0, "2019": 2},
+            "g": {"2017": 0, "2018": 1, "2019": 3},
+            "i10": {"2017": 0, "2018": 0, "2019": 1},
+            "tori": {"2017": 0.0, "2018": 0.4, "2019": 1.5}
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_time_series() {
+        let metrics: Metrics = serde_json::from_str(TIME_SERIES).unwrap();
+        assert!(metrics.indicators.is_none());
+        let series = metrics.time_series.unwrap();
+        let years: Vec<u16> = series.iter().map(|p| p.year).collect();
+        assert_eq!(years, vec![2017, 2018, 2019]);
+        assert_eq!(series[2].h, Some(2));
+        assert_eq!(series[2].g, Some(3));
+        assert_eq!(series[2].i10, Some(1));
+        assert_eq!(series[2].i100, None);
+        assert_eq!(series[1].tori, Some(0.4));
+
+        // Serialized points read back as the same series.
+        let json = serde_json::to_string(&series).unwrap();
+        let back: Metrics = serde_json::from_str(&format!(r#"{{"time_series": {json}}}"#)).unwrap();
+        assert_eq!(back.time_series.unwrap(), series);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_with_types_requests_only_those_types() {
+        let mock = MockServer::new()
+            .route("POST", "/metrics", TIME_SERIES)
+            .start()
+            .await;
+
+        let metrics = mock
+            .client()
+            .metrics_with_types(&["2019PhRvX...9c1040A"], &[MetricType::TimeSeries])
+            .await
+            .unwrap();
+        assert_eq!(metrics.time_series.unwrap().len(), 3);
+        assert_eq!(
+            mock.requests()[0].json()["types"],
+            serde_json::json!(["timeseries"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metrics_leaves_out_time_series() {
+        let mock = MockServer::new()
+            .route("POST", "/metrics", METRICS)
+            .start()
+            .await;
+
+        let metrics = mock.client().metrics(&["a"]).await.unwrap();
+        assert!(metrics.time_series.is_none());
+        assert_eq!(
+            mock.requests()[0].json()["types"],
+            serde_json::json!(["basic", "citations", "indicators"])
+        );
+    }
+
+    #[test]
+    fn test_h_and_g_index() {
+        assert_eq!(h_index(&[]), 0);
+        assert_eq!(h_index(&[10, 8, 5, 4, 3]), 4);
+        assert_eq!(h_index(&[25, 8, 5, 3, 3]), 3);
+        assert_eq!(g_index(&[10, 5, 1]), 3);
+        assert_eq!(g_index(&[0, 0]), 0);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let mean = weighted_mean([(Some(2.0), Some(1)), (Some(5.0), Some(3))].into_iter());
+        assert_eq!(mean, Some(4.25));
+        assert_eq!(weighted_mean([(None, Some(1))].into_iter()), None);
+    }
+
+    #[test]
+    fn test_parse_citation_growth_missing_histogram() {
+        assert!(parse_citation_growth(r#"{"histograms": {}}"#).is_err());
     }
 }
@@ -0,0 +1,238 @@
+//! Canned-response HTTP server standing in for the ADS API in tests.
+//!
+//! Routes match on method and path (query string ignored). Every request is
+//! recorded so tests can assert on what the client sent.
+
+use crate::client::SciXClient;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// A request received by the mock server.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedRequest {
+    pub method: String,
+    /// Path including the query string.
+    pub target: String,
+    /// Header names (lowercased) and values, in arrival order.
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// The body parsed as JSON (`Null` if it isn't JSON).
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(&self.body).unwrap_or_default()
+    }
+
+    /// The value of header `name` (lowercase), if sent.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+struct Route {
+    method: &'static str,
+    path: String,
+    status: u16,
+    body: String,
+}
+
+/// Builder and handle for the mock server.
+#[derive(Default)]
+pub(crate) struct MockServer {
+    routes: Vec<Route>,
+    delay: Duration,
+    fail_after: Option<usize>,
+    gzip: bool,
+}
+
+/// A running mock server.
+pub(crate) struct RunningMock {
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respond to `method path` with status 200 and `body`.
+    pub fn route(self, method: &'static str, path: &str, body: impl Into<String>) -> Self {
+        self.route_status(method, path, 200, body)
+    }
+
+    /// Respond to `method path` with the given status and body.
+    pub fn route_status(
+        mut self,
+        method: &'static str,
+        path: &str,
+        status: u16,
+        body: impl Into<String>,
+    ) -> Self {
+        self.routes.push(Route {
+            method,
+            path: path.to_string(),
+            status,
+            body: body.into(),
+        });
+        self
+    }
+
+    /// Wait this long before answering each request.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Answer every request after the first `n` with HTTP 500.
+    pub fn fail_after(mut self, n: usize) -> Self {
+        self.fail_after = Some(n);
+        self
+    }
+
+    /// Gzip response bodies for requests that accept gzip.
+    pub fn gzip(mut self) -> Self {
+        self.gzip = true;
+        self
+    }
+
+    /// Start serving on an ephemeral local port. Unmatched requests get 404.
+    pub async fn start(self) -> RunningMock {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes = Arc::new(self.routes);
+        let requests: Arc<Mutex<Vec<RecordedRequest>>> = Arc::default();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let delay = self.delay;
+        let fail_after = self.fail_after;
+        let gzip = self.gzip;
+
+        let recorded = requests.clone();
+        let max = max_in_flight.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let routes = routes.clone();
+                let recorded = recorded.clone();
+                let in_flight = in_flight.clone();
+                let max = max.clone();
+                tokio::spawn(async move {
+                    let mut reader = BufReader::new(stream);
+                    let Some(request) = read_request(&mut reader).await else {
+                        return;
+                    };
+                    let path = request.target.split('?').next().unwrap_or("");
+                    let seen = recorded.lock().unwrap().len();
+                    let (status, body) = match fail_after {
+                        Some(n) if seen >= n => (500, r#"{"error": "mock failure"}"#.to_string()),
+                        _ => routes
+                            .iter()
+                            .find(|r| r.method == request.method && r.path == path)
+                            .map(|r| (r.status, r.body.clone()))
+                            .unwrap_or((404, String::new())),
+                    };
+                    let compress = gzip
+                        && request
+                            .header("accept-encoding")
+                            .is_some_and(|v| v.contains("gzip"));
+                    recorded.lock().unwrap().push(request);
+
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(delay).await;
+
+                    let (body, encoding) = if compress {
+                        (gzip_bytes(body.as_bytes()), "Content-Encoding: gzip\r\n")
+                    } else {
+                        (body.into_bytes(), "")
+                    };
+                    let mut response = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                        status,
+                        encoding,
+                        body.len()
+                    )
+                    .into_bytes();
+                    response.extend(body);
+                    let _ = reader.get_mut().write_all(&response).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+
+        RunningMock {
+            url,
+            requests,
+            max_in_flight,
+        }
+    }
+}
+
+impl RunningMock {
+    /// A client pointed at this server, without client-side rate limiting.
+    pub fn client(&self) -> SciXClient {
+        SciXClient::new("test-token")
+            .with_base_url(&self.url)
+            .with_rate_limit(1000.0)
+    }
+
+    /// The most requests the server was handling at the same time.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Requests received so far, in arrival order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(reader: &mut BufReader<tokio::net::TcpStream>) -> Option<RecordedRequest> {
+    let mut line = String::new();
+    reader.read_line(&mut line).await.ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut length = 0;
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).await.ok()?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let (name, value) = (name.to_ascii_lowercase(), value.trim().to_string());
+            if name == "content-length" {
+                length = value.parse().ok()?;
+            }
+            headers.push((name, value));
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await.ok()?;
+    Some(RecordedRequest {
+        method,
+        target,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn gzip_bytes(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
@@ -4,6 +4,9 @@
 
 use crate::client::SciXClient;
 use crate::error::{Result, SciXError};
+use crate::types::{NetworkGraph, NetworkLink, NetworkNode};
+use serde_json::Value;
+use std::collections::HashMap;
 
 impl SciXClient {
     /// Get author collaboration network for a set of papers.
@@ -15,7 +18,32 @@ impl SciXClient {
 
         let response_body = self.post_json("/vis/author-network", &body).await?;
         serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid network response: {}", e)))
+            .map_err(|e| SciXError::parse("/vis/author-network", e, &response_body))
+    }
+
+    /// Get author collaboration network as a typed graph.
+    ///
+    /// Nodes are authors, tagged with the collaboration group ADS placed them
+    /// in; links connect co-authors.
+    pub async fn author_network_typed(&self, bibcodes: &[&str]) -> Result<NetworkGraph> {
+        let raw = self.author_network(bibcodes).await?;
+        parse_author_network(&raw)
+    }
+
+    /// Rank the authors of `bibcodes`' collaboration network by how often
+    /// they co-author, returning at most `limit` `(name, count)` pairs.
+    ///
+    /// Counts come from the node weights ADS assigns (the number of shared
+    /// papers), falling back to the length of each node's paper list. An
+    /// author appearing in several groups is counted once with the weights
+    /// summed. Ties are broken alphabetically.
+    pub async fn top_coauthors(
+        &self,
+        bibcodes: &[&str],
+        limit: usize,
+    ) -> Result<Vec<(String, u32)>> {
+        let graph = self.author_network_typed(bibcodes).await?;
+        Ok(rank_coauthors(&graph, limit))
     }
 
     /// Get paper citation/reference network for a set of papers.
@@ -27,7 +55,16 @@ impl SciXClient {
 
         let response_body = self.post_json("/vis/paper-network", &body).await?;
         serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid network response: {}", e)))
+            .map_err(|e| SciXError::parse("/vis/paper-network", e, &response_body))
+    }
+
+    /// Get paper network as a typed graph.
+    ///
+    /// Uses the ADS summary graph: nodes are clusters of papers sharing
+    /// references, links connect clusters.
+    pub async fn paper_network_typed(&self, bibcodes: &[&str]) -> Result<NetworkGraph> {
+        let raw = self.paper_network(bibcodes).await?;
+        parse_paper_network(&raw)
     }
 
     /// Get co-citation suggestions: papers frequently cited alongside the given set
@@ -39,6 +76,273 @@ impl SciXClient {
 
         let response_body = self.post_json("/citation_helper", &body).await?;
         serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid citation helper response: {}", e)))
+            .map_err(|e| SciXError::parse("/citation_helper", e, &response_body))
+    }
+}
+
+/// Parse an author network response (`data.root` tree plus `data.link_data`).
+///
+/// The root nests groups of authors, possibly several levels deep. Leaves
+/// are authors, numbered in depth-first order to match the `link_data`
+/// indices.
+pub fn parse_author_network(raw: &Value) -> Result<NetworkGraph> {
+    let root = &raw["data"]["root"];
+    if !root.is_object() {
+        return Err(SciXError::parse(
+            "/vis/author-network",
+            "missing data.root",
+            &raw.to_string(),
+        ));
+    }
+
+    let mut nodes = Vec::new();
+    for child in root["children"].as_array().into_iter().flatten() {
+        collect_author_nodes(child, None, &mut nodes);
+    }
+
+    let links = raw["data"]["link_data"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|link| {
+            let link = link.as_array()?;
+            Some(NetworkLink {
+                source: link.first()?.as_u64()? as usize,
+                target: link.get(1)?.as_u64()? as usize,
+                weight: link.get(2).and_then(Value::as_f64).unwrap_or(1.0),
+            })
+        })
+        .collect();
+
+    Ok(NetworkGraph { nodes, links })
+}
+
+fn collect_author_nodes(value: &Value, group: Option<&str>, nodes: &mut Vec<NetworkNode>) {
+    if let Some(children) = value["children"].as_array() {
+        let name = value_to_string(&value["name"]);
+        let group = name.as_deref().or(group);
+        for child in children {
+            collect_author_nodes(child, group, nodes);
+        }
+        return;
+    }
+
+    let Some(name) =
+        value_to_string(&value["nodeName"]).or_else(|| value_to_string(&value["name"]))
+    else {
+        return;
+    };
+    nodes.push(NetworkNode {
+        id: nodes.len(),
+        name,
+        group: group.map(String::from),
+        weight: value["nodeWeight"]
+            .as_f64()
+            .or_else(|| value["size"].as_f64()),
+        papers: string_array(&value["papers"]),
+    });
+}
+
+fn rank_coauthors(graph: &NetworkGraph, limit: usize) -> Vec<(String, u32)> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for node in &graph.nodes {
+        let weight = node
+            .weight
+            .map(|w| w.max(0.0).round() as u32)
+            .unwrap_or(node.papers.len() as u32);
+        *counts.entry(node.name.as_str()).or_insert(0) += weight;
+    }
+    let mut ranked: Vec<(String, u32)> = counts
+        .into_iter()
+        .map(|(name, count)| (name.to_string(), count))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Parse a paper network response (`data.summaryGraph`).
+pub fn parse_paper_network(raw: &Value) -> Result<NetworkGraph> {
+    let graph = &raw["data"]["summaryGraph"];
+    if !graph.is_object() {
+        return Err(SciXError::parse(
+            "/vis/paper-network",
+            "missing data.summaryGraph",
+            &raw.to_string(),
+        ));
+    }
+
+    let nodes = graph["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(id, node)| NetworkNode {
+            id,
+            name: value_to_string(&node["node_name"]).unwrap_or_else(|| id.to_string()),
+            group: None,
+            weight: node["paper_count"].as_f64(),
+            papers: string_array(&node["papers"]),
+        })
+        .collect();
+
+    let links = graph["links"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|link| {
+            Some(NetworkLink {
+                source: link["source"].as_u64()? as usize,
+                target: link["target"].as_u64()? as usize,
+                weight: link["weight"].as_f64().unwrap_or(1.0),
+            })
+        })
+        .collect();
+
+    Ok(NetworkGraph { nodes, links })
+}
+
+/// ADS uses both strings and numbers for node and group names.
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn string_array(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str().map(String::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_author_network_nested_groups() {
+        let raw = serde_json::json!({
+            "msg": {"numFound": 3, "rows": 3},
+            "data": {
+                "root": {
+                    "name": [{"nodeName": "root"}],
+                    "children": [
+                        {
+                            "name": 0,
+                            "children": [
+                                {
+                                    "name": "Abbott, B",
+                                    "children": [
+                                        {"nodeName": "Abbott, B", "nodeWeight": 12, "papers": ["2016PhRvL.116f1102A"]}
+                                    ]
+                                },
+                                {"nodeName": "Abbott, R", "nodeWeight": 9, "papers": ["2016PhRvL.116f1102A"]}
+                            ]
+                        },
+                        {
+                            "name": 1,
+                            "children": [
+                                {"nodeName": "Riess, A", "nodeWeight": 4, "papers": []}
+                            ]
+                        }
+                    ]
+                },
+                "link_data": [[0, 1, 7], [1, 2, 1]]
+            }
+        });
+
+        let graph = parse_author_network(&raw).unwrap();
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.nodes[0].name, "Abbott, B");
+        assert_eq!(graph.nodes[0].group.as_deref(), Some("Abbott, B"));
+        assert_eq!(graph.nodes[0].weight, Some(12.0));
+        assert_eq!(graph.nodes[0].papers, vec!["2016PhRvL.116f1102A"]);
+        assert_eq!(graph.nodes[1].group.as_deref(), Some("0"));
+        assert_eq!(graph.nodes[2].id, 2);
+        assert_eq!(graph.nodes[2].group.as_deref(), Some("1"));
+
+        assert_eq!(graph.links.len(), 2);
+        assert_eq!(graph.links[0].source, 0);
+        assert_eq!(graph.links[0].target, 1);
+        assert_eq!(graph.links[0].weight, 7.0);
+    }
+
+    #[test]
+    fn test_rank_coauthors_orders_by_weight() {
+        let raw = serde_json::json!({
+            "data": {
+                "root": {
+                    "name": [{"nodeName": "root"}],
+                    "children": [
+                        {
+                            "name": 0,
+                            "children": [
+                                {"nodeName": "Riess, A", "nodeWeight": 4, "papers": []},
+                                {"nodeName": "Abbott, R", "nodeWeight": 9, "papers": []},
+                                {"nodeName": "Abbott, B", "nodeWeight": 12, "papers": []}
+                            ]
+                        },
+                        {
+                            "name": 1,
+                            "children": [
+                                {"nodeName": "Riess, A", "nodeWeight": 5, "papers": []},
+                                {"nodeName": "Perlmutter, S", "papers": ["1999ApJ...517..565P"]},
+                                {"nodeName": "Schmidt, B", "nodeWeight": 9, "papers": []}
+                            ]
+                        }
+                    ]
+                },
+                "link_data": []
+            }
+        });
+
+        let graph = parse_author_network(&raw).unwrap();
+        let ranked = rank_coauthors(&graph, 10);
+        assert_eq!(
+            ranked,
+            vec![
+                ("Abbott, B".to_string(), 12),
+                ("Abbott, R".to_string(), 9),
+                ("Riess, A".to_string(), 9),
+                ("Schmidt, B".to_string(), 9),
+                ("Perlmutter, S".to_string(), 1),
+            ]
+        );
+        assert_eq!(rank_coauthors(&graph, 2).len(), 2);
+    }
+
+    #[test]
+    fn test_parse_paper_network_summary_graph() {
+        let raw = serde_json::json!({
+            "data": {
+                "summaryGraph": {
+                    "nodes": [
+                        {"node_name": 1, "paper_count": 14, "node_label": {"cosmology": 0.4}},
+                        {"node_name": 2, "paper_count": 6}
+                    ],
+                    "links": [{"source": 0, "target": 1, "weight": 33}]
+                },
+                "fullGraph": {"nodes": [], "links": []}
+            }
+        });
+
+        let graph = parse_paper_network(&raw).unwrap();
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].name, "1");
+        assert_eq!(graph.nodes[0].weight, Some(14.0));
+        assert_eq!(graph.links[0].target, 1);
+        assert_eq!(graph.links[0].weight, 33.0);
+    }
+
+    #[test]
+    fn test_parse_network_missing_data() {
+        let raw = serde_json::json!({"Error": "no data"});
+        assert!(parse_author_network(&raw).is_err());
+        assert!(parse_paper_network(&raw).is_err());
     }
 }
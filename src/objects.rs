@@ -5,6 +5,8 @@
 
 use crate::client::SciXClient;
 use crate::error::{Result, SciXError};
+use crate::query::quote_phrase;
+use crate::types::ObjectResult;
 
 impl SciXClient {
     /// Resolve astronomical object names to associated bibcodes.
@@ -19,6 +21,82 @@ impl SciXClient {
 
         let response_body = self.post_json("/objects", &body).await?;
         serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid objects response: {}", e)))
+            .map_err(|e| SciXError::parse("/objects", e, &response_body))
+    }
+
+    /// Find papers about each astronomical object.
+    ///
+    /// This does not call `/objects` or type its response: it runs one
+    /// `object:"..."` search per name, so results can differ from
+    /// [`Self::resolve_objects`]. Returns up to `rows` bibcodes for each
+    /// name, in input order.
+    pub async fn resolve_objects_typed(
+        &self,
+        objects: &[&str],
+        rows: u32,
+    ) -> Result<Vec<ObjectResult>> {
+        let mut results = Vec::with_capacity(objects.len());
+        for object in objects {
+            let query = format!("object:{}", quote_phrase(object));
+            let response = self
+                .search_with_options(&query, "bibcode,title", None, rows, 0, &[])
+                .await?;
+            results.push(ObjectResult {
+                object: object.to_string(),
+                bibcodes: response.papers.into_iter().map(|p| p.bibcode).collect(),
+            });
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::MockServer;
+
+    #[tokio::test]
+    async fn test_resolve_objects_typed_searches_each_object() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2006A&A...445..423S", "title": ["The Crab Nebula"]}]}}"#;
+        let server = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let results = server
+            .client()
+            .resolve_objects_typed(&["M31", "Crab Nebula"], 5)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].object, "M31");
+        assert_eq!(results[1].object, "Crab Nebula");
+        assert_eq!(results[1].bibcodes, vec!["2006A&A...445..423S"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].target.contains("object%3A%22Crab+Nebula%22"));
+        assert!(requests[1].target.contains("rows=5"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_objects_typed_escapes_quotes() {
+        let body = r#"{"response": {"numFound": 0, "start": 0, "docs": []}}"#;
+        let server = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        server
+            .client()
+            .resolve_objects_typed(&["Hoag's \"Object\""], 5)
+            .await
+            .unwrap();
+        let target = &server.requests()[0].target;
+        assert!(
+            target.contains("object%3A%22Hoag%27s+%5C%22Object%5C%22%22"),
+            "{}",
+            target
+        );
     }
 }
@@ -3,26 +3,109 @@
 //! Ported from imbib-core/src/sources/ads.rs — this is the canonical implementation.
 
 use crate::error::SciXError;
-use crate::types::{Author, Paper, PdfLink, SearchResponse};
+use crate::types::{Author, BibEntry, FieldStats, Paper, PdfLink, ResponseMeta, SearchResponse};
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
 /// Default fields requested in search queries.
 pub const DEFAULT_SEARCH_FIELDS: &str =
     "bibcode,title,author,year,pub,abstract,doi,identifier,doctype,esources,citation_count,property";
 
+/// Rich fields for the single-paper detail view.
+pub const RICH_FIELDS: &str = "bibcode,title,author,year,pub,abstract,doi,identifier,doctype,esources,citation_count,property,read_count,volume,page,keyword,aff,lang";
+
+/// Just enough to list papers: identifier, title, and year.
+pub const MINIMAL_FIELDS: &str = "bibcode,title,year";
+
+/// Identifier plus the citation and read counts, for ranking or metrics.
+pub const METRICS_FIELDS: &str = "bibcode,citation_count,read_count";
+
 /// ADS API search response wrapper.
+///
+/// `/search/query` nests results under `response`; some `/search/bigquery`
+/// responses put `docs` and `numFound` at the top level instead, so both
+/// places are read.
 #[derive(Debug, Deserialize)]
 pub(crate) struct AdsApiResponse {
-    pub response: AdsApiResponseBody,
+    pub response: Option<AdsApiResponseBody>,
+    #[serde(rename = "responseHeader")]
+    pub response_header: Option<AdsResponseHeader>,
+    pub docs: Option<Vec<AdsApiDocument>>,
+    #[serde(rename = "numFound", default, deserialize_with = "deserialize_count")]
+    pub num_found: Option<u64>,
+}
+
+/// Solr's `responseHeader` block.
+#[derive(Debug, Deserialize)]
+pub(crate) struct AdsResponseHeader {
+    pub status: Option<i32>,
+    #[serde(rename = "QTime")]
+    pub qtime: Option<u64>,
+    #[serde(default)]
+    pub params: HashMap<String, serde_json::Value>,
+}
+
+impl AdsResponseHeader {
+    fn into_meta(self) -> ResponseMeta {
+        let params = self
+            .params
+            .into_iter()
+            .map(|(key, value)| {
+                let values = match value {
+                    serde_json::Value::Array(items) => items.iter().map(param_string).collect(),
+                    other => vec![param_string(&other)],
+                };
+                (key, values)
+            })
+            .collect();
+        ResponseMeta {
+            status: self.status,
+            qtime_ms: self.qtime,
+            params,
+        }
+    }
+}
+
+/// Render an echoed Solr parameter value as a string.
+fn param_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct AdsApiResponseBody {
+    #[serde(default)]
     pub docs: Vec<AdsApiDocument>,
-    #[serde(rename = "numFound")]
+    #[serde(rename = "numFound", default, deserialize_with = "deserialize_count")]
     pub num_found: Option<u64>,
 }
 
+/// Deserialize a count sent either as a JSON number or a numeric string.
+fn deserialize_count<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u64),
+        Text(String),
+    }
+
+    match Option::<Count>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Count::Number(n)) => Ok(Some(n)),
+        Some(Count::Text(s)) => s
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("invalid count \"{}\"", s))),
+    }
+}
+
 /// Custom deserializer for year field that accepts both string and integer.
 fn deserialize_year_option<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
@@ -120,10 +203,91 @@ pub(crate) struct AdsApiDocument {
     pub doctype: Option<String>,
     pub esources: Option<Vec<String>>,
     pub citation_count: Option<i32>,
+    /// ADS returns reads as a float.
+    pub read_count: Option<f64>,
     #[serde(rename = "reference")]
     #[allow(dead_code)]
     pub references: Option<Vec<String>>,
     pub property: Option<Vec<String>>,
+    pub volume: Option<String>,
+    /// A list in ADS, though only the first page is ever meaningful.
+    pub page: Option<OneOrMany>,
+    pub keyword: Option<Vec<String>>,
+    pub aff: Option<Vec<String>>,
+    pub lang: Option<OneOrMany>,
+}
+
+/// Document fields [`AdsApiDocument`] reads; strict parsing rejects others.
+const DOCUMENT_FIELDS: &[&str] = &[
+    "bibcode",
+    "title",
+    "author",
+    "year",
+    "pub",
+    "abstract",
+    "doi",
+    "identifier",
+    "doctype",
+    "esources",
+    "citation_count",
+    "read_count",
+    "reference",
+    "property",
+    "volume",
+    "page",
+    "keyword",
+    "aff",
+    "lang",
+];
+
+/// A Solr field that may come back as a single value or a list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_first(self) -> Option<String> {
+        match self {
+            Self::One(value) => Some(value),
+            Self::Many(values) => values.into_iter().next(),
+        }
+    }
+}
+
+/// ADS search response reduced to its result count.
+#[derive(Debug, Deserialize)]
+struct AdsCountResponse {
+    response: AdsCountResponseBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdsCountResponseBody {
+    #[serde(rename = "numFound", default, deserialize_with = "deserialize_count")]
+    num_found: Option<u64>,
+}
+
+/// ADS search response with just the identifiers of each document.
+#[derive(Debug, Deserialize)]
+struct AdsIdentifierResponse {
+    response: AdsIdentifierResponseBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdsIdentifierResponseBody {
+    #[serde(default)]
+    docs: Vec<AdsIdentifierDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdsIdentifierDocument {
+    bibcode: String,
+    #[serde(default)]
+    alternate_bibcode: Vec<String>,
+    #[serde(default)]
+    identifier: Vec<String>,
 }
 
 /// ADS BibTeX export response.
@@ -133,30 +297,406 @@ pub(crate) struct AdsExportResponse {
 }
 
 /// Parse an ADS search/query JSON response into a [`SearchResponse`].
+///
+/// Accepts both the `/search/query` shape (results under `response`) and the
+/// flatter shape some `/search/bigquery` responses use, with `numFound` as a
+/// number or a numeric string. A missing `numFound` counts the returned docs.
 pub fn parse_search_response(json: &str) -> crate::error::Result<SearchResponse> {
-    let response: AdsApiResponse = serde_json::from_str(json)
-        .map_err(|e| SciXError::Parse(format!("Invalid ADS JSON: {}", e)))?;
+    let response: AdsApiResponse = serde_json::from_str(json).map_err(|e| search_error(json, e))?;
 
-    let papers = response
-        .response
-        .docs
-        .into_iter()
-        .filter_map(document_to_paper)
-        .collect();
+    let (docs, num_found) = match (response.response, response.docs) {
+        (Some(body), _) => (body.docs, body.num_found.or(response.num_found)),
+        (None, Some(docs)) => (docs, response.num_found),
+        (None, None) => return Err(search_error(json, "missing response.docs")),
+    };
+    let num_found = num_found.unwrap_or(docs.len() as u64);
+    let papers = docs.into_iter().filter_map(document_to_paper).collect();
 
     Ok(SearchResponse {
-        num_found: response.response.num_found.unwrap_or(0),
+        num_found,
         papers,
+        meta: response.response_header.map(AdsResponseHeader::into_meta),
     })
 }
 
+/// Parse an ADS search response, failing where [`parse_search_response`]
+/// would quietly carry on.
+///
+/// The lenient parser ignores document fields it doesn't model, drops
+/// documents without a title, and counts the documents when `numFound` is
+/// missing. Here each of those is a [`SciXError::Parse`], so upstream
+/// schema changes show up in tests and monitoring instead of as missing
+/// data. Fields outside the ones [`RICH_FIELDS`] can request count as
+/// unknown, so only use it with those field lists.
+pub fn parse_search_response_strict(json: &str) -> crate::error::Result<SearchResponse> {
+    let results = parse_search_response(json)?;
+    let raw: serde_json::Value = serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+    let body = if raw["response"].is_object() {
+        &raw["response"]
+    } else {
+        &raw
+    };
+    if body["numFound"].is_null() {
+        return Err(SciXError::parse("/search/query", "missing numFound", json));
+    }
+
+    let docs = body["docs"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    for doc in docs {
+        let bibcode = doc["bibcode"].as_str().unwrap_or("?");
+        let mut fields = doc.as_object().into_iter().flat_map(|d| d.keys());
+        if let Some(field) = fields.find(|field| !DOCUMENT_FIELDS.contains(&field.as_str())) {
+            return Err(SciXError::parse(
+                "/search/query",
+                format!("unknown field \"{}\" in document {}", field, bibcode),
+                json,
+            ));
+        }
+        if doc["title"][0].as_str().map_or(true, str::is_empty) {
+            return Err(SciXError::parse(
+                "/search/query",
+                format!("document {} has no title", bibcode),
+                json,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+/// Parse a cursor-paged ADS search response into its page and `nextCursorMark`.
+///
+/// Solr only sends `nextCursorMark` when the request carried a `cursorMark`,
+/// so a body without one is a parse error.
+pub fn parse_cursor_response(json: &str) -> crate::error::Result<(SearchResponse, String)> {
+    Ok((parse_search_response(json)?, next_cursor_mark(json)?))
+}
+
+/// The `nextCursorMark` of a cursor-paged search response.
+pub(crate) fn next_cursor_mark(json: &str) -> crate::error::Result<String> {
+    #[derive(Deserialize)]
+    struct CursorMark {
+        #[serde(rename = "nextCursorMark")]
+        next_cursor_mark: Option<String>,
+    }
+
+    let cursor: CursorMark = serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+    cursor
+        .next_cursor_mark
+        .ok_or_else(|| search_error(json, "missing nextCursorMark"))
+}
+
+/// The error for a `/search/query` body that doesn't have the expected shape.
+///
+/// ADS sometimes answers HTTP 200 with `{"error": ...}` instead of results,
+/// either as a plain message or Solr's `{"msg": ..., "code": ...}` object.
+/// That error is surfaced as [`SciXError::InvalidQuery`] for Solr syntax
+/// errors and [`SciXError::Api`] otherwise, rather than a parse error about
+/// a missing `response` field. Any other body gives [`SciXError::Parse`].
+pub(crate) fn search_error(json: &str, message: impl ToString) -> SciXError {
+    let body = serde_json::from_str::<serde_json::Value>(json).unwrap_or_default();
+    let Some(error) = body.get("error") else {
+        return SciXError::parse("/search/query", message, json);
+    };
+    let message = json_error_message(&body).unwrap_or_else(|| error.to_string());
+    let code = error.get("code").and_then(|c| c.as_u64());
+    if message.contains("SyntaxError") {
+        SciXError::InvalidQuery(message)
+    } else {
+        SciXError::Api {
+            status: code.and_then(|c| u16::try_from(c).ok()).unwrap_or(200),
+            message,
+            error_detail: Some(body),
+        }
+    }
+}
+
+/// The human-readable message in an ADS JSON error body.
+///
+/// Recognizes `{"error": "..."}`, Solr's `{"error": {"msg": "..."}}`, and
+/// `{"message": "..."}` (or `{"msg": "..."}`); `None` for anything else.
+pub(crate) fn json_error_message(body: &serde_json::Value) -> Option<String> {
+    let error = &body["error"];
+    [
+        error.as_str(),
+        error["msg"].as_str(),
+        body["message"].as_str(),
+        body["msg"].as_str(),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|m| !m.trim().is_empty())
+    .map(str::to_string)
+}
+
+/// Parse only `numFound` from an ADS search/query JSON response.
+///
+/// Documents are never deserialized, so this is cheap even if some are returned.
+pub fn parse_count_response(json: &str) -> crate::error::Result<u64> {
+    let response: AdsCountResponse =
+        serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+    Ok(response.response.num_found.unwrap_or(0))
+}
+
+/// Parse the `stats.stats_fields.<field>` block of an ADS search response.
+///
+/// Solr reports `min`/`max`/`mean` as null (or `"NaN"`) when no document has
+/// the field; those become `None`.
+pub fn parse_field_stats_response(json: &str, field: &str) -> crate::error::Result<FieldStats> {
+    let response: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+    let stats = &response["stats"]["stats_fields"][field];
+    if !stats.is_object() {
+        return Err(search_error(
+            json,
+            format!("missing stats for field '{}'", field),
+        ));
+    }
+
+    Ok(FieldStats {
+        min: stats["min"].as_f64(),
+        max: stats["max"].as_f64(),
+        mean: stats["mean"].as_f64(),
+        sum: stats["sum"].as_f64().unwrap_or(0.0),
+        count: stats["count"].as_u64().unwrap_or(0),
+    })
+}
+
+/// Map each input bibcode to the canonical bibcode of the document it names.
+///
+/// Expects a search response with `bibcode`, `alternate_bibcode`, and
+/// `identifier` fields. Inputs matching no document are left out.
+pub fn parse_canonical_response(
+    json: &str,
+    inputs: &[&str],
+) -> crate::error::Result<HashMap<String, String>> {
+    let response: AdsIdentifierResponse =
+        serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+
+    let mut canonical = HashMap::new();
+    for doc in &response.response.docs {
+        for input in inputs {
+            let matches = doc.bibcode == *input
+                || doc.alternate_bibcode.iter().any(|b| b == input)
+                || doc.identifier.iter().any(|b| b == input);
+            if matches {
+                canonical.insert(input.to_string(), doc.bibcode.clone());
+            }
+        }
+    }
+    Ok(canonical)
+}
+
+/// Map each document's bibcode to the other identifiers it is known by.
+///
+/// Expects a search response with `bibcode` and `identifier` fields;
+/// `alternate_bibcode` is included when requested. Used to tell which
+/// documents are versions of the same paper.
+pub(crate) fn parse_version_keys(json: &str) -> crate::error::Result<HashMap<String, Vec<String>>> {
+    let response: AdsIdentifierResponse =
+        serde_json::from_str(json).map_err(|e| search_error(json, e))?;
+
+    Ok(response
+        .response
+        .docs
+        .into_iter()
+        .map(|doc| {
+            let mut keys = doc.alternate_bibcode;
+            keys.extend(doc.identifier);
+            (doc.bibcode, keys)
+        })
+        .collect())
+}
+
 /// Parse an ADS BibTeX export JSON response.
 pub fn parse_export_response(json: &str) -> crate::error::Result<String> {
-    let response: AdsExportResponse = serde_json::from_str(json)
-        .map_err(|e| SciXError::Parse(format!("Invalid export response: {}", e)))?;
+    let response: AdsExportResponse =
+        serde_json::from_str(json).map_err(|e| SciXError::parse("/export", e, json))?;
     Ok(response.export)
 }
 
+/// Parse a CSL-JSON export response into its list of items.
+///
+/// The `export` field may hold the CSL-JSON as a string or as decoded JSON,
+/// and the items may be a bare array or an object's `items` array.
+pub fn parse_csl_json_response(json: &str) -> crate::error::Result<Vec<serde_json::Value>> {
+    let response: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| SciXError::parse("/export/csl", e, json))?;
+    let export = match response.get("export") {
+        Some(serde_json::Value::String(text)) => {
+            serde_json::from_str(text).map_err(|e| SciXError::parse("/export/csl", e, json))?
+        }
+        Some(value) => value.clone(),
+        None => {
+            return Err(SciXError::parse(
+                "/export/csl",
+                "missing export field",
+                json,
+            ))
+        }
+    };
+    match export {
+        serde_json::Value::Array(items) => Ok(items),
+        serde_json::Value::Object(mut object) => match object.remove("items") {
+            Some(serde_json::Value::Array(items)) => Ok(items),
+            _ => Err(SciXError::parse(
+                "/export/csl",
+                "expected a CSL-JSON array or an object with an items array",
+                json,
+            )),
+        },
+        _ => Err(SciXError::parse(
+            "/export/csl",
+            "expected a CSL-JSON array",
+            json,
+        )),
+    }
+}
+
+/// Parse BibTeX text, such as an ADS export, into typed entries.
+///
+/// Field values may be braced (with nesting), quoted, or bare (numbers and
+/// month macros like `feb`), and `#` concatenations are joined. Outer
+/// delimiters are removed while inner braces such as `{LaTeX}` are kept,
+/// and whitespace runs (including line breaks) collapse to single spaces.
+/// Entry types and field names are lowercased. `@comment`, `@preamble`,
+/// and `@string` blocks are skipped.
+pub fn parse_bibtex(bibtex: &str) -> Vec<BibEntry> {
+    parse_bibtex_with_key_spans(bibtex)
+        .into_iter()
+        .map(|(entry, _)| entry)
+        .collect()
+}
+
+/// [`parse_bibtex`], also returning the byte range of each entry's key in
+/// `bibtex`, so keys can be rewritten in place.
+pub(crate) fn parse_bibtex_with_key_spans(bibtex: &str) -> Vec<(BibEntry, Range<usize>)> {
+    let mut entries = Vec::new();
+    let mut rest = bibtex;
+    while let Some(at) = rest.find('@') {
+        let offset = bibtex.len() - rest.len() + at + 1;
+        let (entry, remaining) = parse_bibtex_entry(&rest[at + 1..]);
+        entries.extend(entry.map(|(entry, key)| (entry, offset + key.start..offset + key.end)));
+        rest = remaining;
+    }
+    entries
+}
+
+/// Parse the entry following an `@`, returning it and its key's byte range
+/// in `input`, along with the input after its closing delimiter.
+fn parse_bibtex_entry(input: &str) -> (Option<(BibEntry, Range<usize>)>, &str) {
+    let type_end = input
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(input.len());
+    let entry_type = input[..type_end].to_lowercase();
+    let rest = input[type_end..].trim_start();
+    let close = match rest.chars().next() {
+        Some('{') => '}',
+        Some('(') => ')',
+        _ => return (None, rest),
+    };
+    let mut rest = &rest[1..];
+    if entry_type.is_empty() {
+        return (None, rest);
+    }
+    if matches!(entry_type.as_str(), "comment" | "preamble" | "string") {
+        return (None, skip_bibtex_group(rest, close));
+    }
+
+    let key_end = rest.find([',', close]).unwrap_or(rest.len());
+    let raw_key = &rest[..key_end];
+    let key_start = input.len() - rest.len() + (raw_key.len() - raw_key.trim_start().len());
+    let key_span = key_start..key_start + raw_key.trim().len();
+    let mut entry = BibEntry {
+        entry_type,
+        key: rest[..key_end].trim().to_string(),
+        fields: BTreeMap::new(),
+    };
+    rest = &rest[key_end..];
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if let Some(after) = rest.strip_prefix(close) {
+            return (Some((entry, key_span)), after);
+        }
+        // An unterminated entry ends where the next one starts.
+        let Some(eq) = rest.find('=').filter(|&eq| !rest[..eq].contains('@')) else {
+            return (Some((entry, key_span)), rest);
+        };
+        let name = rest[..eq].trim().to_lowercase();
+        rest = &rest[eq + 1..];
+
+        let mut value = String::new();
+        loop {
+            let (piece, after) = bibtex_value_piece(rest.trim_start(), close);
+            value.push_str(piece);
+            rest = after.trim_start();
+            match rest.strip_prefix('#') {
+                Some(after) => rest = after,
+                None => break,
+            }
+        }
+        if !name.is_empty() {
+            let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            entry.fields.insert(name, value);
+        }
+    }
+}
+
+/// Read one value (braced, quoted, or bare) and return it without its
+/// delimiters, along with the input after it.
+fn bibtex_value_piece(input: &str, close: char) -> (&str, &str) {
+    let mut chars = input.char_indices();
+    match chars.next() {
+        Some((_, '{')) => {
+            let mut depth = 1;
+            for (i, c) in chars {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return (&input[1..i], &input[i + 1..]);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            (&input[1..], "")
+        }
+        Some((_, '"')) => {
+            let mut depth = 0;
+            for (i, c) in chars {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    '"' if depth == 0 => return (&input[1..i], &input[i + 1..]),
+                    _ => {}
+                }
+            }
+            (&input[1..], "")
+        }
+        _ => {
+            let end = input
+                .find(|c: char| c == ',' || c == '#' || c == close || c.is_whitespace())
+                .unwrap_or(input.len());
+            (&input[..end], &input[end..])
+        }
+    }
+}
+
+/// Skip past the closing delimiter of a block, honouring nested braces.
+fn skip_bibtex_group(input: &str, close: char) -> &str {
+    let mut depth = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            c if c == close && depth == 0 => return &input[i + 1..],
+            _ => {}
+        }
+    }
+    ""
+}
+
 /// Convert an ADS API document to a [`Paper`].
 fn document_to_paper(doc: AdsApiDocument) -> Option<Paper> {
     let bibcode = doc.bibcode;
@@ -169,15 +709,25 @@ fn document_to_paper(doc: AdsApiDocument) -> Option<Paper> {
         return None;
     }
 
+    let affiliations = doc.aff.unwrap_or_default();
     let authors: Vec<Author> = doc
         .author
         .unwrap_or_default()
         .into_iter()
-        .map(|name| Author::from_ads_format(&name))
+        .enumerate()
+        .map(|(i, name)| Author {
+            affiliation: affiliations
+                .get(i)
+                .map(|aff| aff.trim())
+                .filter(|aff| !aff.is_empty() && *aff != "-")
+                .map(str::to_string),
+            ..Author::from_ads_format(&name)
+        })
         .collect();
 
     let year = doc.year.as_ref().and_then(|y| y.parse().ok());
-    let doi = doc.doi.and_then(|d| d.into_iter().next());
+    let dois = doc.doi.unwrap_or_default();
+    let doi = dois.first().cloned();
     let identifiers = doc.identifier.unwrap_or_default();
     let arxiv_id = extract_arxiv_id(&identifiers);
     let esources = doc.esources.unwrap_or_default();
@@ -192,14 +742,21 @@ fn document_to_paper(doc: AdsApiDocument) -> Option<Paper> {
         authors,
         year,
         publication: doc.publication,
+        volume: doc.volume,
+        page: doc.page.and_then(OneOrMany::into_first),
         abstract_text: doc.abstract_text,
         doi,
+        dois,
         arxiv_id,
         identifiers,
         esources,
         citation_count: doc.citation_count.map(|c| c.max(0) as u32),
+        read_count: doc.read_count.map(|r| r.round().max(0.0) as u32),
         doctype: doc.doctype,
         properties: doc.property.unwrap_or_default(),
+        abstract_language: doc.lang.and_then(OneOrMany::into_first),
+        keywords: doc.keyword.unwrap_or_default(),
+        affiliations,
         pdf_links,
         url,
         bibcode,
@@ -246,6 +803,81 @@ fn is_bare_new_arxiv_id(s: &str) -> bool {
         && suffix.chars().all(|c| c.is_ascii_digit())
 }
 
+/// arXiv archives, the part of a category before the dot.
+const ARXIV_ARCHIVES: &[&str] = &[
+    "astro-ph", "cond-mat", "gr-qc", "hep-ex", "hep-lat", "hep-ph", "hep-th", "math-ph", "nlin",
+    "nucl-ex", "nucl-th", "physics", "quant-ph", "math", "cs", "q-bio", "q-fin", "stat", "eess",
+    "econ",
+];
+
+/// The descriptive names ADS uses as keywords for arXiv e-prints.
+const ARXIV_SUBJECT_NAMES: &[(&str, &str)] = &[
+    ("Astrophysics", "astro-ph"),
+    ("Astrophysics - Astrophysics of Galaxies", "astro-ph.GA"),
+    (
+        "Astrophysics - Cosmology and Nongalactic Astrophysics",
+        "astro-ph.CO",
+    ),
+    (
+        "Astrophysics - Earth and Planetary Astrophysics",
+        "astro-ph.EP",
+    ),
+    (
+        "Astrophysics - High Energy Astrophysical Phenomena",
+        "astro-ph.HE",
+    ),
+    (
+        "Astrophysics - Instrumentation and Methods for Astrophysics",
+        "astro-ph.IM",
+    ),
+    (
+        "Astrophysics - Solar and Stellar Astrophysics",
+        "astro-ph.SR",
+    ),
+    ("General Relativity and Quantum Cosmology", "gr-qc"),
+    ("High Energy Physics - Experiment", "hep-ex"),
+    ("High Energy Physics - Phenomenology", "hep-ph"),
+    ("High Energy Physics - Theory", "hep-th"),
+    ("Nuclear Experiment", "nucl-ex"),
+    ("Nuclear Theory", "nucl-th"),
+    ("Quantum Physics", "quant-ph"),
+];
+
+/// The arXiv category a keyword names, if any.
+///
+/// Accepts category codes (`astro-ph.GA`, `gr-qc`, `physics.ins-det`,
+/// optionally prefixed with `arXiv:`) and the descriptive names ADS attaches
+/// to e-prints (`Astrophysics - Astrophysics of Galaxies`). UAT, PACS, and
+/// free-text keywords give `None`.
+pub fn arxiv_category(keyword: &str) -> Option<String> {
+    let keyword = keyword.trim();
+    if let Some((_, code)) = ARXIV_SUBJECT_NAMES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+    {
+        return Some(code.to_string());
+    }
+
+    let code = keyword.strip_prefix("arXiv:").unwrap_or(keyword);
+    let (archive, subject) = match code.split_once('.') {
+        Some((archive, subject)) => (archive, Some(subject)),
+        None => (code, None),
+    };
+    if !ARXIV_ARCHIVES.contains(&archive) {
+        return None;
+    }
+    match subject {
+        None => Some(code.to_string()),
+        Some(subject)
+            if !subject.is_empty()
+                && subject.chars().all(|c| c.is_ascii_alphabetic() || c == '-') =>
+        {
+            Some(code.to_string())
+        }
+        Some(_) => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +912,282 @@ mod tests {
         assert_eq!(paper.bibcode, "2023ApJ...123..456A");
         assert_eq!(paper.doi, Some("10.3847/1234-5678".to_string()));
         assert_eq!(paper.arxiv_id, Some("2301.12345".to_string()));
+        assert_eq!(paper.dois, vec!["10.3847/1234-5678"]);
+    }
+
+    #[test]
+    fn test_parse_response_header() {
+        let json = r#"{
+            "responseHeader": {
+                "status": 0,
+                "QTime": 42,
+                "params": {"q": "author:\"Einstein\"", "rows": "10", "fq": ["database:astronomy", "property:refereed"]}
+            },
+            "response": {"numFound": 0, "docs": []}
+        }"#;
+        let meta = parse_search_response(json).unwrap().meta.unwrap();
+        assert_eq!(meta.status, Some(0));
+        assert_eq!(meta.qtime_ms, Some(42));
+        assert_eq!(meta.query(), Some("author:\"Einstein\""));
+        assert_eq!(meta.params["rows"], vec!["10"]);
+        assert_eq!(
+            meta.params["fq"],
+            vec!["database:astronomy", "property:refereed"]
+        );
+
+        let without = parse_search_response(SAMPLE_RESPONSE).unwrap();
+        assert!(without.meta.is_none());
+    }
+
+    #[test]
+    fn test_parse_csl_json_response_wrappings() {
+        let items = r#"[{"id": "2016PhRvL.116f1102A", "type": "article-journal"}, {"id": "1998AJ....116.1009R", "type": "article-journal"}]"#;
+        let as_string = serde_json::json!({ "export": items }).to_string();
+        let as_array = format!(r#"{{"export": {}}}"#, items);
+        let as_object = format!(r#"{{"export": {{"items": {}}}}}"#, items);
+
+        for json in [as_string, as_array, as_object] {
+            let parsed = parse_csl_json_response(&json).unwrap();
+            assert_eq!(parsed.len(), 2);
+            assert_eq!(parsed[1]["id"], "1998AJ....116.1009R");
+        }
+        assert!(parse_csl_json_response(r#"{"export": "@ARTICLE{x,}"}"#).is_err());
+        assert!(parse_csl_json_response(r#"{"msg": "no export"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_read_count() {
+        let json = r#"{"response": {"numFound": 2, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"], "read_count": 811.6},
+            {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}
+        ]}}"#;
+        let papers = parse_search_response(json).unwrap().papers;
+        assert_eq!(papers[0].read_count, Some(812));
+        assert_eq!(papers[1].read_count, None);
+    }
+
+    #[test]
+    fn test_parse_author_affiliations_aligned() {
+        let json = r#"{"response": {"numFound": 1, "docs": [
+            {"bibcode": "2017ApJ...848L..13A", "title": ["GW170817 and GRB 170817A"],
+             "author": ["Abbott, B. P.", "Savchenko, V.", "Smith, J."],
+             "aff": ["LIGO, California Institute of Technology", "-"]}
+        ]}}"#;
+        let authors = &parse_search_response(json).unwrap().papers[0].authors;
+        assert_eq!(
+            authors[0].affiliation.as_deref(),
+            Some("LIGO, California Institute of Technology")
+        );
+        // `-` is the ADS placeholder for a missing affiliation.
+        assert_eq!(authors[1].affiliation, None);
+        // A short aff array leaves the remaining authors without one.
+        assert_eq!(authors[2].affiliation, None);
+        assert_eq!(authors[2].family_name, "Smith");
+    }
+
+    #[test]
+    fn test_parse_rich_fields() {
+        let json = r#"{"response": {"numFound": 2, "docs": [
+            {"bibcode": "2017ApJ...848L..13A", "title": ["GW170817 and GRB 170817A"],
+             "author": ["Abbott, B. P.", "Savchenko, V."],
+             "read_count": 95.0, "volume": "848", "page": ["L13"],
+             "keyword": ["gamma-ray burst: general", "gravitational waves"],
+             "aff": ["LIGO, California Institute of Technology", "-"]},
+            {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"], "page": "1009"}
+        ]}}"#;
+        let papers = parse_search_response(json).unwrap().papers;
+        let rich = &papers[0];
+        assert_eq!(rich.read_count, Some(95));
+        assert_eq!(rich.volume.as_deref(), Some("848"));
+        assert_eq!(rich.page.as_deref(), Some("L13"));
+        assert_eq!(
+            rich.keywords,
+            ["gamma-ray burst: general", "gravitational waves"]
+        );
+        assert_eq!(
+            rich.affiliations,
+            ["LIGO, California Institute of Technology", "-"]
+        );
+
+        let plain = &papers[1];
+        assert_eq!(plain.page.as_deref(), Some("1009"));
+        assert!(plain.volume.is_none());
+        assert!(plain.keywords.is_empty() && plain.affiliations.is_empty());
+    }
+
+    #[test]
+    fn test_arxiv_category() {
+        let cases = [
+            ("astro-ph.GA", Some("astro-ph.GA")),
+            ("arXiv:cs.LG", Some("cs.LG")),
+            ("gr-qc", Some("gr-qc")),
+            ("physics.ins-det", Some("physics.ins-det")),
+            (
+                "Astrophysics - Cosmology and Nongalactic Astrophysics",
+                Some("astro-ph.CO"),
+            ),
+            ("High Energy Physics - Phenomenology", Some("hep-ph")),
+            ("galaxies: evolution", None),
+            ("98.80.Es", None),
+            ("Galaxy evolution", None),
+            ("math.", None),
+            ("astro-ph.2", None),
+        ];
+        for (keyword, expected) in cases {
+            assert_eq!(arxiv_category(keyword).as_deref(), expected, "{}", keyword);
+        }
+    }
+
+    #[test]
+    fn test_parse_language() {
+        let json = r#"{"response": {"numFound": 3, "docs": [
+            {"bibcode": "1905AnP...322..891E", "title": ["Zur Elektrodynamik bewegter Körper"], "lang": "de"},
+            {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"], "lang": ["en"]},
+            {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}
+        ]}}"#;
+        let papers = parse_search_response(json).unwrap().papers;
+        assert_eq!(papers[0].abstract_language.as_deref(), Some("de"));
+        assert_eq!(papers[1].abstract_language.as_deref(), Some("en"));
+        assert_eq!(papers[2].abstract_language, None);
+    }
+
+    #[test]
+    fn test_parse_keeps_all_dois() {
+        let json = r#"{"response": {"numFound": 1, "docs": [{
+            "bibcode": "2020MNRAS.491.1234B",
+            "title": ["A Paper With Data"],
+            "doi": ["10.1093/mnras/stz3456", "10.5281/zenodo.123456"]
+        }]}}"#;
+        let paper = &parse_search_response(json).unwrap().papers[0];
+        assert_eq!(paper.doi.as_deref(), Some("10.1093/mnras/stz3456"));
+        assert_eq!(
+            paper.dois,
+            vec!["10.1093/mnras/stz3456", "10.5281/zenodo.123456"]
+        );
+    }
+
+    #[test]
+    fn test_parse_cursor_response() {
+        let json = r#"{
+            "response": {"numFound": 2, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}]},
+            "nextCursorMark": "AoIIP4AAACgyMDE2UGhSdkwuMTE2ZjExMDJB"
+        }"#;
+        let (results, next) = parse_cursor_response(json).unwrap();
+        assert_eq!(results.papers[0].bibcode, "2016PhRvL.116f1102A");
+        assert_eq!(next, "AoIIP4AAACgyMDE2UGhSdkwuMTE2ZjExMDJB");
+
+        assert!(parse_cursor_response(SAMPLE_RESPONSE).is_err());
+    }
+
+    #[test]
+    fn test_strict_parsing_rejects_what_lenient_drops() {
+        let untitled = r#"{"response": {"numFound": 2, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]},
+            {"bibcode": "2016PhRvL.116x1103A"}
+        ]}}"#;
+        assert_eq!(parse_search_response(untitled).unwrap().papers.len(), 1);
+        let err = parse_search_response_strict(untitled).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("document 2016PhRvL.116x1103A has no title"),
+            "{}",
+            err
+        );
+
+        let unknown = r#"{"response": {"numFound": 1, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"], "citation_cnt": 5}
+        ]}}"#;
+        assert!(parse_search_response(unknown).is_ok());
+        let err = parse_search_response_strict(unknown).unwrap_err();
+        assert!(err.to_string().contains(r#"unknown field "citation_cnt""#));
+
+        let uncounted = r#"{"response": {"docs": []}}"#;
+        assert_eq!(parse_search_response(uncounted).unwrap().num_found, 0);
+        assert!(matches!(
+            parse_search_response_strict(uncounted),
+            Err(SciXError::Parse { .. })
+        ));
+
+        let strict = parse_search_response_strict(SAMPLE_RESPONSE).unwrap();
+        assert_eq!(
+            strict.papers.len(),
+            parse_search_response(SAMPLE_RESPONSE).unwrap().papers.len()
+        );
+    }
+
+    #[test]
+    fn test_parse_search_response_embedded_error() {
+        let err = parse_search_response(r#"{"error": "unknown field: foo"}"#).unwrap_err();
+        match err {
+            SciXError::Api {
+                status,
+                message,
+                error_detail,
+            } => {
+                assert_eq!(status, 200);
+                assert_eq!(message, "unknown field: foo");
+                assert_eq!(
+                    error_detail,
+                    Some(serde_json::json!({"error": "unknown field: foo"}))
+                );
+            }
+            other => panic!("expected Api error, got {:?}", other),
+        }
+
+        let body = r#"{"responseHeader": {"status": 400},
+            "error": {"msg": "org.apache.solr.search.SyntaxError: Cannot parse 'author:('", "code": 400}}"#;
+        let err = parse_count_response(body).unwrap_err();
+        assert!(
+            matches!(err, SciXError::InvalidQuery(ref msg) if msg.contains("Cannot parse 'author:('")),
+            "{:?}",
+            err
+        );
+
+        let err = parse_search_response(r#"{"responseHeader": {}}"#).unwrap_err();
+        assert!(matches!(err, SciXError::Parse { .. }));
+    }
+
+    #[test]
+    fn test_parse_search_and_bigquery_shapes_agree() {
+        let search = r#"{
+            "responseHeader": {"status": 0, "QTime": 4},
+            "response": {"numFound": 2, "start": 0, "docs": [
+                {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]},
+                {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]}
+        }"#;
+        // Bigquery-style: no header, numFound as a string, docs at the top level.
+        let bigquery = r#"{
+            "numFound": "2",
+            "docs": [
+                {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]},
+                {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]
+        }"#;
+
+        let from_search = parse_search_response(search).unwrap();
+        let from_bigquery = parse_search_response(bigquery).unwrap();
+        assert!(from_search.meta.is_some());
+        assert!(from_bigquery.meta.is_none());
+        for result in [&from_search, &from_bigquery] {
+            assert_eq!(result.num_found, 2);
+            assert_eq!(result.papers[1].bibcode, "1998AJ....116.1009R");
+        }
+    }
+
+    #[test]
+    fn test_parse_search_response_lenient_counts() {
+        let json = r#"{"response": {"numFound": "7", "docs": []}}"#;
+        assert_eq!(parse_search_response(json).unwrap().num_found, 7);
+        assert_eq!(parse_count_response(json).unwrap(), 7);
+
+        // Without numFound, the count falls back to the documents returned.
+        let json = r#"{"response": {"docs": [{"bibcode": "2016PhRvL.116f1102A"}]}}"#;
+        assert_eq!(parse_search_response(json).unwrap().num_found, 1);
+
+        let json = r#"{"response": {"numFound": "many", "docs": []}}"#;
+        assert!(matches!(
+            parse_search_response(json),
+            Err(SciXError::Parse { .. })
+        ));
     }
 
     #[test]
@@ -302,6 +1210,73 @@ mod tests {
         assert_eq!(result.papers[0].year, Some(2024));
     }
 
+    #[test]
+    fn test_parse_count_response_empty_docs() {
+        let json = r#"{
+            "responseHeader": {"status": 0, "params": {"q": "dark matter", "rows": "0"}},
+            "response": {"numFound": 123456, "start": 0, "docs": []}
+        }"#;
+        assert_eq!(parse_count_response(json).unwrap(), 123456);
+    }
+
+    #[test]
+    fn test_parse_field_stats_citation_count() {
+        let json = r#"{
+            "responseHeader": {"status": 0, "params": {"stats": "true", "stats.field": "citation_count"}},
+            "response": {"numFound": 4, "start": 0, "docs": []},
+            "stats": {"stats_fields": {"citation_count": {
+                "min": 0.0, "max": 120.0, "count": 4, "missing": 0,
+                "sum": 150.0, "sumOfSquares": 14500.0, "mean": 37.5, "stddev": 55.0
+            }}}
+        }"#;
+        let stats = parse_field_stats_response(json, "citation_count").unwrap();
+        assert_eq!(stats.min, Some(0.0));
+        assert_eq!(stats.max, Some(120.0));
+        assert_eq!(stats.mean, Some(37.5));
+        assert_eq!(stats.sum, 150.0);
+        assert_eq!(stats.count, 4);
+    }
+
+    #[test]
+    fn test_parse_field_stats_no_matches() {
+        let json = r#"{
+            "response": {"numFound": 0, "docs": []},
+            "stats": {"stats_fields": {"citation_count": {
+                "min": null, "max": null, "count": 0, "missing": 0,
+                "sum": 0.0, "mean": "NaN"
+            }}}
+        }"#;
+        let stats = parse_field_stats_response(json, "citation_count").unwrap();
+        assert_eq!(stats.min, None);
+        assert_eq!(stats.mean, None);
+        assert_eq!(stats.count, 0);
+        assert!(parse_field_stats_response(json, "read_count").is_err());
+    }
+
+    #[test]
+    fn test_parse_canonical_response_alternate() {
+        let json = r#"{
+            "response": {"numFound": 2, "docs": [
+                {
+                    "bibcode": "2016PhRvL.116f1102A",
+                    "alternate_bibcode": ["2016arXiv160203837T"],
+                    "identifier": ["2016PhRvL.116f1102A", "2016arXiv160203837T", "10.1103/PhysRevLett.116.061102"]
+                },
+                {"bibcode": "1998AJ....116.1009R"}
+            ]}
+        }"#;
+        let inputs = [
+            "2016arXiv160203837T",
+            "1998AJ....116.1009R",
+            "2000XXX.....1....1X",
+        ];
+        let map = parse_canonical_response(json, &inputs).unwrap();
+
+        assert_eq!(map["2016arXiv160203837T"], "2016PhRvL.116f1102A");
+        assert_eq!(map["1998AJ....116.1009R"], "1998AJ....116.1009R");
+        assert!(!map.contains_key("2000XXX.....1....1X"));
+    }
+
     #[test]
     fn test_parse_export_response() {
         let json = r#"{"export": "@article{2023ApJ...123..456A,\n  title={A Paper}\n}"}"#;
@@ -309,6 +1284,59 @@ mod tests {
         assert!(bibtex.contains("@article"));
     }
 
+    #[test]
+    fn test_parse_bibtex_multiple_entries() {
+        let bibtex = r#"@comment{exported from ADS}
+
+@ARTICLE{2016PhRvL.116f1102A,
+       author = {{Abbott}, B.~P. and
+         {Abbott}, R.},
+        title = "{Observation of Gravitational Waves from a Binary Black Hole Merger}",
+      journal = {\prl},
+         year = 2016,
+        month = feb,
+}
+
+@INPROCEEDINGS{2020ASPC..527..123S,
+       author = "Smith, J.",
+        title = {{{LaTeX} Typesetting in Astronomy}},
+         note = "Part " # {II},
+          doi = {10.1234/x}}
+"#;
+
+        let entries = parse_bibtex(bibtex);
+        assert_eq!(entries.len(), 2);
+
+        let first = &entries[0];
+        assert_eq!(first.entry_type, "article");
+        assert_eq!(first.key, "2016PhRvL.116f1102A");
+        assert_eq!(first.fields["author"], "{Abbott}, B.~P. and {Abbott}, R.");
+        assert_eq!(
+            first.fields["title"],
+            "{Observation of Gravitational Waves from a Binary Black Hole Merger}"
+        );
+        assert_eq!(first.fields["journal"], "\\prl");
+        assert_eq!(first.fields["year"], "2016");
+        assert_eq!(first.fields["month"], "feb");
+
+        let second = &entries[1];
+        assert_eq!(second.entry_type, "inproceedings");
+        assert_eq!(second.key, "2020ASPC..527..123S");
+        assert_eq!(second.fields["author"], "Smith, J.");
+        assert_eq!(second.fields["title"], "{{LaTeX} Typesetting in Astronomy}");
+        assert_eq!(second.fields["note"], "Part II");
+        assert_eq!(second.fields["doi"], "10.1234/x");
+    }
+
+    #[test]
+    fn test_parse_bibtex_unterminated_entry() {
+        let entries = parse_bibtex("@article{a,\n title = {One}\n\n@misc(b, year = 1999)");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].fields["title"], "One");
+        assert_eq!(entries[1].entry_type, "misc");
+        assert_eq!(entries[1].fields["year"], "1999");
+    }
+
     #[test]
     fn test_build_pdf_links() {
         let esources = vec!["EPRINT_PDF".to_string(), "PUB_PDF".to_string()];
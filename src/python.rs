@@ -4,15 +4,22 @@
 //! All types from `types.rs` are automatically exposed via `#[pyclass(get_all)]`
 //! annotations — adding a field to a Rust struct automatically exposes it to Python.
 //!
-//! Only `SciXClient` (async→sync bridge) and `QueryBuilder` (ownership→mutation bridge)
-//! need explicit wrapper types here.
+//! Only `SciXClient` (async→sync bridge), `AsyncSciXClient` (tokio→asyncio bridge),
+//! and `QueryBuilder` (ownership→mutation bridge) need explicit wrapper types here.
+//!
+//! Type hints live in the checked-in `scix_client.pyi` at the repository root;
+//! `tests/test_stub.py` fails when a field or method here is missing from it.
 
 use pyo3::prelude::*;
+use pyo3::BoundObject;
 
 use crate::client::SciXClient;
 use crate::error::SciXError;
 use crate::query::QueryBuilder;
 use crate::types::*;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::OnceLock;
 
 /// Convert a SciXError into a Python exception.
 fn to_py_err(e: SciXError) -> PyErr {
@@ -67,6 +74,43 @@ impl Paper {
     fn __repr__(&self) -> String {
         format!("Paper(bibcode='{}', title='{}')", self.bibcode, self.title)
     }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    /// Full reference line: authors, year, title, publication, DOI or bibcode.
+    #[pyo3(name = "citation_line")]
+    fn py_citation_line(&self) -> String {
+        self.citation_line()
+    }
+
+    /// arXiv categories among the keywords, e.g. ["astro-ph.GA"].
+    #[pyo3(name = "arxiv_categories")]
+    fn py_arxiv_categories(&self) -> Vec<String> {
+        self.arxiv_categories()
+    }
+
+    /// Whether the abstract appears to be English; None if undecidable.
+    #[pyo3(name = "looks_english")]
+    fn py_looks_english(&self) -> Option<bool> {
+        self.looks_english()
+    }
+
+    /// Keywords that are not arXiv categories (UAT, PACS, free text).
+    #[pyo3(name = "subject_keywords")]
+    fn py_subject_keywords(&self) -> Vec<String> {
+        self.subject_keywords()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Author family names in citation style ("Abbott et al.").
+    #[pyo3(name = "short_authors")]
+    fn py_short_authors(&self) -> String {
+        self.short_authors()
+    }
 }
 
 #[pymethods]
@@ -82,6 +126,43 @@ impl SearchResponse {
     fn __len__(&self) -> usize {
         self.papers.len()
     }
+
+    /// New response with the papers of both, duplicates by bibcode removed.
+    ///
+    /// `num_found` becomes an approximation after merging.
+    #[pyo3(name = "merge")]
+    fn py_merge(&self, other: SearchResponse) -> SearchResponse {
+        self.clone().merge(other)
+    }
+
+    /// Remove repeated bibcodes in place, keeping the richest record.
+    #[pyo3(name = "dedup_by_bibcode")]
+    fn py_dedup_by_bibcode(&mut self) {
+        self.dedup_by_bibcode()
+    }
+
+    /// Collaborators ranked by shared papers, as (family_name, count) pairs.
+    #[pyo3(name = "coauthors")]
+    fn py_coauthors(&self, exclude: &str) -> Vec<(String, usize)> {
+        self.coauthors(exclude)
+    }
+}
+
+#[pymethods]
+impl ResponseMeta {
+    /// The query string Solr received (`params["q"]`).
+    #[pyo3(name = "query")]
+    fn py_query(&self) -> Option<&str> {
+        self.query()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ResponseMeta(qtime_ms={:?}, query={:?})",
+            self.qtime_ms,
+            self.query()
+        )
+    }
 }
 
 #[pymethods]
@@ -92,6 +173,12 @@ impl ExportFormat {
         self.as_api_str()
     }
 
+    /// Conventional file extension without the dot (e.g., "bib", "ris").
+    #[pyo3(name = "file_extension")]
+    fn py_file_extension(&self) -> &'static str {
+        self.file_extension()
+    }
+
     /// Parse from string (case-insensitive).
     #[staticmethod]
     #[pyo3(name = "from_str")]
@@ -106,6 +193,20 @@ impl ExportFormat {
     // Note: __str__ is auto-generated from Display impl
 }
 
+#[pymethods]
+impl KeyStyle {
+    fn __repr__(&self) -> String {
+        format!("KeyStyle.{:?}", self)
+    }
+}
+
+#[pymethods]
+impl MetricType {
+    fn __repr__(&self) -> String {
+        format!("MetricType.{:?}", self)
+    }
+}
+
 #[pymethods]
 impl PdfLinkType {
     fn __repr__(&self) -> String {
@@ -122,6 +223,13 @@ impl Sort {
         Sort { field, direction }
     }
 
+    /// Sort on a known field.
+    #[staticmethod]
+    #[pyo3(name = "by", signature = (field, direction=SortDirection::Desc))]
+    fn py_by(field: SortField, direction: SortDirection) -> Self {
+        Sort::by(field, direction)
+    }
+
     /// Sort by date descending.
     #[staticmethod]
     #[pyo3(name = "date_desc")]
@@ -143,6 +251,20 @@ impl Sort {
         Sort::new("score", SortDirection::Desc)
     }
 
+    /// Best matches first (score desc); searches otherwise default to date desc.
+    #[staticmethod]
+    #[pyo3(name = "relevance")]
+    fn py_relevance() -> Self {
+        Sort::relevance()
+    }
+
+    /// Map a phrase like "most cited" or "newest" to a sort, or None.
+    #[staticmethod]
+    #[pyo3(name = "from_human")]
+    fn py_from_human(s: &str) -> Option<Self> {
+        Sort::from_human(s)
+    }
+
     fn __repr__(&self) -> String {
         format!("Sort('{}', {:?})", self.field, self.direction)
     }
@@ -150,6 +272,26 @@ impl Sort {
     // Note: __str__ is auto-generated from Display impl
 }
 
+#[pymethods]
+impl SortField {
+    /// ADS field name as used in the `sort` parameter.
+    #[pyo3(name = "as_api_str")]
+    fn py_as_api_str(&self) -> &'static str {
+        self.as_api_str()
+    }
+
+    /// Parse from string (case-insensitive), accepting aliases like "citations".
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(s: &str) -> Option<Self> {
+        SortField::from_str_loose(s)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SortField.{:?}", self)
+    }
+}
+
 #[pymethods]
 impl SortDirection {
     fn __repr__(&self) -> String {
@@ -159,11 +301,116 @@ impl SortDirection {
 
 #[pymethods]
 impl Metrics {
+    /// Citations excluding self-citations, for all and for refereed papers.
+    #[pyo3(name = "external_citation_summary")]
+    fn py_external_citation_summary(&self) -> ExternalCitationSummary {
+        self.external_citation_summary()
+    }
+
     fn __repr__(&self) -> String {
         "Metrics(...)".to_string()
     }
 }
 
+#[pymethods]
+impl CitationStatsEntry {
+    /// Citations excluding self-citations, or None when either count is missing.
+    #[pyo3(name = "external_citations")]
+    fn py_external_citations(&self) -> Option<u32> {
+        self.external_citations()
+    }
+}
+
+#[pymethods]
+impl ExternalCitationSummary {
+    fn __repr__(&self) -> String {
+        format!(
+            "ExternalCitationSummary(total={:?}, refereed={:?})",
+            self.total, self.refereed
+        )
+    }
+}
+
+#[pymethods]
+impl Neighborhood {
+    /// Every neighboring paper once, references first.
+    #[pyo3(name = "papers")]
+    fn py_papers(&self) -> Vec<Paper> {
+        self.papers().into_iter().cloned().collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Neighborhood(bibcode={:?}, references=[{} of {}], citations=[{} of {}])",
+            self.bibcode,
+            self.references.len(),
+            self.num_references,
+            self.citations.len(),
+            self.num_citations
+        )
+    }
+}
+
+#[pymethods]
+impl SyncReport {
+    fn __repr__(&self) -> String {
+        format!(
+            "SyncReport(added=[{} bibcodes], removed=[{} bibcodes])",
+            self.added.len(),
+            self.removed.len()
+        )
+    }
+}
+
+#[pymethods]
+impl ExportResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "ExportResult(succeeded=[{} bibcodes], failed={:?})",
+            self.succeeded.len(),
+            self.failed
+        )
+    }
+}
+
+#[pymethods]
+impl BibEntry {
+    fn __repr__(&self) -> String {
+        format!(
+            "BibEntry(@{}{{{}}}, {} fields)",
+            self.entry_type,
+            self.key,
+            self.fields.len()
+        )
+    }
+}
+
+#[pymethods]
+impl FieldStats {
+    fn __repr__(&self) -> String {
+        format!(
+            "FieldStats(count={}, min={:?}, max={:?}, mean={:?}, sum={})",
+            self.count, self.min, self.max, self.mean, self.sum
+        )
+    }
+}
+
+#[pymethods]
+impl RateLimitStatus {
+    fn __repr__(&self) -> String {
+        format!(
+            "RateLimitStatus(remaining={:?}, limit={:?}, reset_at={:?})",
+            self.remaining, self.limit, self.reset_at
+        )
+    }
+
+    /// Requests spent in the current window, or None when either count is missing.
+    #[pyo3(name = "used")]
+    fn py_used(&self) -> Option<u32> {
+        self.used()
+    }
+}
+
 #[pymethods]
 impl Library {
     fn __repr__(&self) -> String {
@@ -171,6 +418,24 @@ impl Library {
     }
 }
 
+#[pymethods]
+impl ObjectResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "ObjectResult(object='{}', bibcodes=[{} bibcodes])",
+            self.object,
+            self.bibcodes.len()
+        )
+    }
+}
+
+#[pymethods]
+impl ResolvedLink {
+    fn __repr__(&self) -> String {
+        format!("ResolvedLink(url='{}')", self.url)
+    }
+}
+
 #[pymethods]
 impl ResolvedReference {
     fn __repr__(&self) -> String {
@@ -187,16 +452,44 @@ impl ResolvedReference {
 
 /// SciX (NASA ADS) API client.
 ///
-/// Provides synchronous access to all SciX endpoints.
+/// Provides synchronous access to all SciX endpoints. The most common
+/// methods also have awaitable `*_async` variants (`search_async`, ...).
+///
+/// All clients share one background Tokio runtime. Blocking calls release
+/// the GIL while waiting, so a client can be shared between threads and
+/// used from several at once.
 ///
 /// Example:
 ///     client = scix_client.SciXClient()          # reads SCIX_API_TOKEN env var
 ///     client = scix_client.SciXClient("token")   # explicit token
 ///     results = client.search("dark matter", rows=10)
+///
+///     with scix_client.SciXClient() as client:   # closes pooled connections on exit
+///         results = client.search("dark matter")
 #[pyclass(name = "SciXClient")]
 struct PySciXClient {
     client: SciXClient,
-    runtime: tokio::runtime::Runtime,
+    runtime: &'static tokio::runtime::Runtime,
+}
+
+impl PySciXClient {
+    /// Run `fut` to completion with the GIL released, so other Python
+    /// threads (including ones serving the request) keep running.
+    fn block_on<F>(&self, fut: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        Python::with_gil(|py| py.allow_threads(|| self.runtime.block_on(fut)))
+    }
+
+    /// An `AsyncSciXClient` sharing this client's settings, connection
+    /// pool, and rate limiter, which the `*_async` methods delegate to.
+    fn as_async(&self) -> PyAsyncSciXClient {
+        PyAsyncSciXClient {
+            client: self.client.clone(),
+        }
+    }
 }
 
 #[pymethods]
@@ -211,18 +504,44 @@ impl PySciXClient {
             Some(t) => SciXClient::new(t),
             None => SciXClient::from_env().map_err(to_py_err)?,
         };
-        let runtime = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {}",
-                e
-            ))
-        })?;
-        Ok(Self { client, runtime })
+        Ok(Self {
+            client,
+            runtime: shared_runtime()?,
+        })
     }
 
     /// Set a custom base URL (e.g., for testing).
-    fn set_base_url(&mut self, url: String) {
-        self.client.base_url = url;
+    fn set_base_url(&mut self, url: String) -> PyResult<()> {
+        self.client.base_url = crate::client::validate_base_url(url)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Close pooled HTTP connections.
+    ///
+    /// The client stays usable; later calls open new connections.
+    fn close(&mut self) {
+        self.client.http = crate::client::http_client(self.client.compression);
+    }
+
+    /// ADS quota reported by the most recent response, or None before any.
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.block_on(self.client.rate_limit_status())
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) -> bool {
+        self.close();
+        false
     }
 
     // -- Search endpoints --
@@ -232,13 +551,52 @@ impl PySciXClient {
     /// Example: client.search('author:"Einstein" year:1905', rows=10)
     #[pyo3(signature = (query, rows=10))]
     fn search(&self, query: &str, rows: u32) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(self.client.search(query, rows))
+        self.block_on(self.client.search(query, rows))
             .map_err(to_py_err)
     }
 
-    /// Search with full control over fields, sort, and pagination.
-    #[pyo3(signature = (query, fields="bibcode,title,author,year,pub,abstract,doi,identifier,esources,citation_count,doctype,property", sort=None, rows=10, start=0))]
+    /// Search SciX with the best matches first instead of the newest.
+    #[pyo3(signature = (query, rows=10))]
+    fn search_relevant(&self, query: &str, rows: u32) -> PyResult<SearchResponse> {
+        self.block_on(self.client.search_relevant(query, rows))
+            .map_err(to_py_err)
+    }
+
+    /// Fetch a single paper with rich metadata by bibcode, DOI, or arXiv ID.
+    fn get_paper(&self, identifier: &str) -> PyResult<Paper> {
+        self.block_on(self.client.get_paper(identifier))
+            .map_err(to_py_err)
+    }
+
+    /// Fetch just a paper's abstract, or None if it has none.
+    fn abstract_of(&self, bibcode: &str) -> PyResult<Option<String>> {
+        self.block_on(self.client.abstract_of(bibcode))
+            .map_err(to_py_err)
+    }
+
+    /// Count the papers matching a query without fetching them.
+    fn count(&self, query: &str) -> PyResult<u64> {
+        self.block_on(self.client.count(query)).map_err(to_py_err)
+    }
+
+    /// Map bibcodes to their current canonical bibcode. Returns a dict;
+    /// bibcodes ADS doesn't recognize are omitted.
+    fn canonicalize(&self, bibcodes: Vec<String>) -> PyResult<HashMap<String, String>> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.canonicalize(&refs))
+            .map_err(to_py_err)
+    }
+
+    /// Compute min/max/mean/sum of a numeric field over a query's matches.
+    fn field_stats(&self, query: &str, field: &str) -> PyResult<FieldStats> {
+        self.block_on(self.client.field_stats(query, field))
+            .map_err(to_py_err)
+    }
+
+    /// Search with full control over fields, sort, pagination, and filters.
+    ///
+    /// `filters` are Solr filter queries, e.g. ["database:astronomy"].
+    #[pyo3(signature = (query, fields="bibcode,title,author,year,pub,abstract,doi,identifier,esources,citation_count,doctype,property", sort=None, rows=10, start=0, filters=None))]
     fn search_with_options(
         &self,
         query: &str,
@@ -246,12 +604,53 @@ impl PySciXClient {
         sort: Option<&Sort>,
         rows: u32,
         start: u32,
+        filters: Option<Vec<String>>,
     ) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(
-                self.client
-                    .search_with_options(query, fields, sort, rows, start),
-            )
+        let filters = filters.unwrap_or_default();
+        let refs: Vec<&str> = filters.iter().map(|s| s.as_str()).collect();
+        self.block_on(
+            self.client
+                .search_with_options(query, fields, sort, rows, start, &refs),
+        )
+        .map_err(to_py_err)
+    }
+
+    /// Search and return the raw ADS response as a dict.
+    ///
+    /// For Solr fields that `Paper` doesn't model; include "bibcode" in `fields`.
+    #[pyo3(signature = (query, fields, sort=None, rows=10, start=0))]
+    fn search_raw(
+        &self,
+        py: Python<'_>,
+        query: &str,
+        fields: &str,
+        sort: Option<&Sort>,
+        rows: u32,
+        start: u32,
+    ) -> PyResult<PyObject> {
+        let result = self
+            .block_on(self.client.search_raw(query, fields, sort, rows, start))
+            .map_err(to_py_err)?;
+        json_to_py(py, &result)
+    }
+
+    /// Show how ADS parses a query, as the decoded query tree.
+    fn explain_query(&self, py: Python<'_>, query: &str) -> PyResult<PyObject> {
+        let tree = self
+            .block_on(self.client.explain_query(query))
+            .map_err(to_py_err)?;
+        json_to_py(py, &tree)
+    }
+
+    /// Search, returning one record per preprint/published version group.
+    #[pyo3(signature = (query, rows=10, sort=None))]
+    fn search_collapse_versions(
+        &self,
+        query: &str,
+        rows: u32,
+        sort: Option<&Sort>,
+    ) -> PyResult<SearchResponse> {
+        self.block_on(self.client.search_collapse_versions(query, rows, sort))
             .map_err(to_py_err)
     }
 
@@ -266,40 +665,85 @@ impl PySciXClient {
         rows: Option<u32>,
     ) -> PyResult<SearchResponse> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.bigquery(&refs, query, fields, sort, rows))
+        self.block_on(self.client.bigquery(&refs, query, fields, sort, rows))
             .map_err(to_py_err)
     }
 
     /// Get papers referenced by the given paper.
     #[pyo3(signature = (bibcode, rows=25))]
     fn references(&self, bibcode: &str, rows: u32) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(self.client.references(bibcode, rows))
+        self.block_on(self.client.references(bibcode, rows))
             .map_err(to_py_err)
     }
 
     /// Get papers that cite the given paper.
     #[pyo3(signature = (bibcode, rows=25))]
     fn citations(&self, bibcode: &str, rows: u32) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(self.client.citations(bibcode, rows))
+        self.block_on(self.client.citations(bibcode, rows))
+            .map_err(to_py_err)
+    }
+
+    /// Get a paper's references and citations together.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn neighborhood(&self, bibcode: &str, rows: u32) -> PyResult<Neighborhood> {
+        self.block_on(self.client.neighborhood(bibcode, rows))
             .map_err(to_py_err)
     }
 
+    /// Get a paper's metadata, metrics, references, citations, and links together.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn dossier(&self, bibcode: &str, rows: u32) -> Dossier {
+        self.block_on(self.client.dossier(bibcode, rows))
+    }
+
+    /// Get papers that cite the given paper, excluding those by `author_name`.
+    #[pyo3(signature = (bibcode, author_name, rows=25))]
+    fn citations_excluding_self(
+        &self,
+        bibcode: &str,
+        author_name: &str,
+        rows: u32,
+    ) -> PyResult<SearchResponse> {
+        self.block_on(
+            self.client
+                .citations_excluding_self(bibcode, author_name, rows),
+        )
+        .map_err(to_py_err)
+    }
+
     /// Get papers similar to the given paper (content-based).
     #[pyo3(signature = (bibcode, rows=10))]
     fn similar(&self, bibcode: &str, rows: u32) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(self.client.similar(bibcode, rows))
+        self.block_on(self.client.similar(bibcode, rows))
             .map_err(to_py_err)
     }
 
     /// Get co-reads (trending papers read by the same audience).
     #[pyo3(signature = (bibcode, rows=10))]
     fn coreads(&self, bibcode: &str, rows: u32) -> PyResult<SearchResponse> {
-        self.runtime
-            .block_on(self.client.coreads(bibcode, rows))
+        self.block_on(self.client.coreads(bibcode, rows))
+            .map_err(to_py_err)
+    }
+
+    /// Get review papers citing the given paper.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn reviews(&self, bibcode: &str, rows: u32) -> PyResult<SearchResponse> {
+        self.block_on(self.client.reviews(bibcode, rows))
+            .map_err(to_py_err)
+    }
+
+    /// Get one author's papers by ORCID iD (all three ADS ORCID fields).
+    #[pyo3(signature = (orcid, rows=50, sort=None))]
+    fn author_orcid(&self, orcid: &str, rows: u32, sort: Option<Sort>) -> PyResult<SearchResponse> {
+        self.block_on(self.client.author_orcid(orcid, rows, sort.as_ref()))
+            .map_err(to_py_err)
+    }
+
+    /// Get the papers most useful for understanding a set of papers.
+    #[pyo3(signature = (bibcodes, rows=25))]
+    fn useful(&self, bibcodes: Vec<String>, rows: u32) -> PyResult<SearchResponse> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.useful(&refs, rows))
             .map_err(to_py_err)
     }
 
@@ -314,42 +758,123 @@ impl PySciXClient {
         sort: Option<&Sort>,
     ) -> PyResult<String> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.export(&refs, format, sort))
+        self.block_on(self.client.export(&refs, format, sort))
+            .map_err(to_py_err)
+    }
+
+    /// Export papers to a file (atomically), returning the path written.
+    ///
+    /// Without `path`, or with a directory, the file is `export.<ext>`.
+    #[pyo3(signature = (bibcodes, format=ExportFormat::BibTeX, path=None))]
+    fn export_to_file(
+        &self,
+        bibcodes: Vec<String>,
+        format: ExportFormat,
+        path: Option<std::path::PathBuf>,
+    ) -> PyResult<String> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        let written = self
+            .block_on(self.client.export_to_file(&refs, format, path.as_deref()))
+            .map_err(to_py_err)?;
+        Ok(written.to_string_lossy().into_owned())
+    }
+
+    /// Export papers, reporting which requested bibcodes are missing from the output.
+    #[pyo3(signature = (bibcodes, format=ExportFormat::BibTeX, sort=None))]
+    fn export_checked(
+        &self,
+        bibcodes: Vec<String>,
+        format: ExportFormat,
+        sort: Option<&Sort>,
+    ) -> PyResult<ExportResult> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.export_checked(&refs, format, sort))
             .map_err(to_py_err)
     }
 
     /// Export papers as BibTeX.
     fn export_bibtex(&self, bibcodes: Vec<String>) -> PyResult<String> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.export_bibtex(&refs))
+        self.block_on(self.client.export_bibtex(&refs))
             .map_err(to_py_err)
     }
 
-    // -- Metrics --
+    /// Export papers as a list of CSL-JSON items (dicts).
+    fn export_csl_json(&self, py: Python<'_>, bibcodes: Vec<String>) -> PyResult<PyObject> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        let items = self
+            .block_on(self.client.export_csl_json(&refs))
+            .map_err(to_py_err)?;
+        json_to_py(py, &serde_json::Value::Array(items))
+    }
 
-    /// Get citation metrics (h-index, g-index, etc.) for papers.
-    fn metrics(&self, bibcodes: Vec<String>) -> PyResult<Metrics> {
+    /// Export papers as BibTeX parsed into BibEntry objects.
+    fn export_bibtex_parsed(&self, bibcodes: Vec<String>) -> PyResult<Vec<BibEntry>> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.metrics(&refs))
+        self.block_on(self.client.export_bibtex_parsed(&refs))
             .map_err(to_py_err)
     }
 
-    // -- Libraries --
-
-    /// List all libraries for the authenticated user.
-    fn list_libraries(&self) -> PyResult<Vec<Library>> {
-        self.runtime
-            .block_on(self.client.list_libraries())
+    /// Export papers as BibTeX with citation keys rewritten to `key_style`.
+    fn export_bibtex_with_keys(
+        &self,
+        bibcodes: Vec<String>,
+        key_style: KeyStyle,
+    ) -> PyResult<String> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.export_bibtex_with_keys(&refs, key_style))
+            .map_err(to_py_err)
+    }
+
+    // -- Metrics --
+
+    /// Get citation metrics (h-index, g-index, etc.) for papers.
+    fn metrics(&self, bibcodes: Vec<String>) -> PyResult<Metrics> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.metrics(&refs)).map_err(to_py_err)
+    }
+
+    /// Get only the requested groups of metrics, e.g. `[MetricType.TimeSeries]`.
+    fn metrics_with_types(
+        &self,
+        bibcodes: Vec<String>,
+        types: Vec<MetricType>,
+    ) -> PyResult<Metrics> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.metrics_with_types(&refs, &types))
+            .map_err(to_py_err)
+    }
+
+    /// Get citation metrics for up to `max` papers matching a query.
+    #[pyo3(signature = (query, max=2000))]
+    fn metrics_for_query(&self, query: &str, max: u32) -> PyResult<Metrics> {
+        self.block_on(self.client.metrics_for_query(query, max))
+            .map_err(to_py_err)
+    }
+
+    /// Get a paper's cumulative citation count per year as `[(year, total), ...]`.
+    fn citation_growth(&self, bibcode: &str) -> PyResult<Vec<(u16, u32)>> {
+        self.block_on(self.client.citation_growth(bibcode))
+            .map_err(to_py_err)
+    }
+
+    // -- Libraries --
+
+    /// List all libraries for the authenticated user.
+    fn list_libraries(&self) -> PyResult<Vec<Library>> {
+        self.block_on(self.client.list_libraries())
             .map_err(to_py_err)
     }
 
     /// Get a library with its documents.
     fn get_library(&self, id: &str) -> PyResult<LibraryDetail> {
-        self.runtime
-            .block_on(self.client.get_library(id))
+        self.block_on(self.client.get_library(id))
+            .map_err(to_py_err)
+    }
+
+    /// Find a library by exact name; None if there is no such library.
+    fn get_library_by_name(&self, name: &str) -> PyResult<Option<LibraryDetail>> {
+        self.block_on(self.client.get_library_by_name(name))
             .map_err(to_py_err)
     }
 
@@ -366,12 +891,11 @@ impl PySciXClient {
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
         let refs_slice: Option<&[&str]> = owned_refs.as_deref();
-        self.runtime
-            .block_on(
-                self.client
-                    .create_library(name, description, public, refs_slice),
-            )
-            .map_err(to_py_err)
+        self.block_on(
+            self.client
+                .create_library(name, description, public, refs_slice),
+        )
+        .map_err(to_py_err)
     }
 
     /// Edit a library's metadata.
@@ -383,38 +907,46 @@ impl PySciXClient {
         description: Option<&str>,
         public: Option<bool>,
     ) -> PyResult<()> {
-        self.runtime
-            .block_on(self.client.edit_library(id, name, description, public))
+        self.block_on(self.client.edit_library(id, name, description, public))
             .map_err(to_py_err)
     }
 
     /// Delete a library.
     fn delete_library(&self, id: &str) -> PyResult<()> {
-        self.runtime
-            .block_on(self.client.delete_library(id))
+        self.block_on(self.client.delete_library(id))
             .map_err(to_py_err)
     }
 
     /// Add documents (bibcodes) to a library.
     fn add_documents(&self, library_id: &str, bibcodes: Vec<String>) -> PyResult<()> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.add_documents(library_id, &refs))
+        self.block_on(self.client.add_documents(library_id, &refs))
             .map_err(to_py_err)
     }
 
     /// Remove documents (bibcodes) from a library.
     fn remove_documents(&self, library_id: &str, bibcodes: Vec<String>) -> PyResult<()> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.remove_documents(library_id, &refs))
+        self.block_on(self.client.remove_documents(library_id, &refs))
+            .map_err(to_py_err)
+    }
+
+    /// Every bibcode saved in any of the user's libraries.
+    fn all_saved_bibcodes(&self) -> PyResult<HashSet<String>> {
+        self.block_on(self.client.all_saved_bibcodes())
+            .map_err(to_py_err)
+    }
+
+    /// Add and remove documents so the library holds exactly `bibcodes`.
+    fn sync_library(&self, library_id: &str, bibcodes: Vec<String>) -> PyResult<SyncReport> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.sync_library(library_id, &refs))
             .map_err(to_py_err)
     }
 
     /// Get permissions for a library.
     fn get_permissions(&self, py: Python<'_>, library_id: &str) -> PyResult<PyObject> {
         let result = self
-            .runtime
             .block_on(self.client.get_permissions(library_id))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
@@ -422,39 +954,34 @@ impl PySciXClient {
 
     /// Update permissions for a collaborator on a library.
     fn update_permissions(&self, library_id: &str, email: &str, permission: &str) -> PyResult<()> {
-        self.runtime
-            .block_on(
-                self.client
-                    .update_permissions(library_id, email, permission),
-            )
-            .map_err(to_py_err)
+        self.block_on(
+            self.client
+                .update_permissions(library_id, email, permission),
+        )
+        .map_err(to_py_err)
     }
 
     /// Transfer ownership of a library.
     fn transfer_library(&self, library_id: &str, email: &str) -> PyResult<()> {
-        self.runtime
-            .block_on(self.client.transfer_library(library_id, email))
+        self.block_on(self.client.transfer_library(library_id, email))
             .map_err(to_py_err)
     }
 
     /// Get a note/annotation on a paper in a library.
     fn get_annotation(&self, library_id: &str, bibcode: &str) -> PyResult<String> {
-        self.runtime
-            .block_on(self.client.get_annotation(library_id, bibcode))
+        self.block_on(self.client.get_annotation(library_id, bibcode))
             .map_err(to_py_err)
     }
 
     /// Set a note/annotation on a paper in a library.
     fn set_annotation(&self, library_id: &str, bibcode: &str, content: &str) -> PyResult<()> {
-        self.runtime
-            .block_on(self.client.set_annotation(library_id, bibcode, content))
+        self.block_on(self.client.set_annotation(library_id, bibcode, content))
             .map_err(to_py_err)
     }
 
     /// Delete a note/annotation on a paper in a library.
     fn delete_annotation(&self, library_id: &str, bibcode: &str) -> PyResult<()> {
-        self.runtime
-            .block_on(self.client.delete_annotation(library_id, bibcode))
+        self.block_on(self.client.delete_annotation(library_id, bibcode))
             .map_err(to_py_err)
     }
 
@@ -472,7 +999,6 @@ impl PySciXClient {
             .map(|v| v.iter().map(|s| s.as_str()).collect());
         let refs_slice: Option<&[&str]> = owned_refs.as_deref();
         let result = self
-            .runtime
             .block_on(
                 self.client
                     .library_operation(library_id, action, refs_slice),
@@ -489,11 +1015,27 @@ impl PySciXClient {
         query: &str,
         rows: Option<u32>,
     ) -> PyResult<u32> {
-        self.runtime
-            .block_on(self.client.add_documents_by_query(library_id, query, rows))
+        self.block_on(self.client.add_documents_by_query(library_id, query, rows))
             .map_err(to_py_err)
     }
 
+    /// Create a library filled with up to `max` papers matching `query`.
+    #[pyo3(signature = (name, query, description="", public=false, max=50))]
+    fn create_library_from_query(
+        &self,
+        name: &str,
+        query: &str,
+        description: &str,
+        public: bool,
+        max: u32,
+    ) -> PyResult<Library> {
+        self.block_on(
+            self.client
+                .create_library_from_query(name, description, public, query, max),
+        )
+        .map_err(to_py_err)
+    }
+
     // -- Reference & object resolution --
 
     /// Resolve free-text references to ADS bibcodes.
@@ -501,8 +1043,7 @@ impl PySciXClient {
     /// Example: client.resolve_references(["Einstein 1905 Annalen der Physik 17 891"])
     fn resolve_references(&self, references: Vec<String>) -> PyResult<Vec<ResolvedReference>> {
         let refs: Vec<&str> = references.iter().map(|s| s.as_str()).collect();
-        self.runtime
-            .block_on(self.client.resolve_references(&refs))
+        self.block_on(self.client.resolve_references(&refs))
             .map_err(to_py_err)
     }
 
@@ -512,12 +1053,24 @@ impl PySciXClient {
     fn resolve_objects(&self, py: Python<'_>, objects: Vec<String>) -> PyResult<PyObject> {
         let refs: Vec<&str> = objects.iter().map(|s| s.as_str()).collect();
         let result = self
-            .runtime
             .block_on(self.client.resolve_objects(&refs))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
     }
 
+    /// Find papers about each astronomical object with one `object:` search
+    /// per name (not the `/objects` endpoint). Returns one ObjectResult per name.
+    #[pyo3(signature = (objects, rows=25))]
+    fn resolve_objects_typed(
+        &self,
+        objects: Vec<String>,
+        rows: u32,
+    ) -> PyResult<Vec<ObjectResult>> {
+        let refs: Vec<&str> = objects.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.resolve_objects_typed(&refs, rows))
+            .map_err(to_py_err)
+    }
+
     /// Resolve links for a paper (full-text, datasets, citations, references).
     ///
     /// Returns a dict (raw JSON from ADS).
@@ -529,19 +1082,28 @@ impl PySciXClient {
         link_type: Option<&str>,
     ) -> PyResult<PyObject> {
         let result = self
-            .runtime
             .block_on(self.client.resolve_links(bibcode, link_type))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
     }
 
+    /// Resolve links for a paper as a list of ResolvedLink.
+    #[pyo3(signature = (bibcode, link_type=None))]
+    fn resolve_links_typed(
+        &self,
+        bibcode: &str,
+        link_type: Option<&str>,
+    ) -> PyResult<Vec<ResolvedLink>> {
+        self.block_on(self.client.resolve_links_typed(bibcode, link_type))
+            .map_err(to_py_err)
+    }
+
     // -- Network visualization --
 
     /// Get author collaboration network for papers. Returns a dict.
     fn author_network(&self, py: Python<'_>, bibcodes: Vec<String>) -> PyResult<PyObject> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
         let result = self
-            .runtime
             .block_on(self.client.author_network(&refs))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
@@ -551,38 +1113,542 @@ impl PySciXClient {
     fn paper_network(&self, py: Python<'_>, bibcodes: Vec<String>) -> PyResult<PyObject> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
         let result = self
-            .runtime
             .block_on(self.client.paper_network(&refs))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
     }
 
+    /// Get author collaboration network as a typed NetworkGraph.
+    fn author_network_typed(&self, bibcodes: Vec<String>) -> PyResult<NetworkGraph> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.author_network_typed(&refs))
+            .map_err(to_py_err)
+    }
+
+    /// Rank collaborators in the author network as (name, count) pairs.
+    #[pyo3(signature = (bibcodes, limit=10))]
+    fn top_coauthors(&self, bibcodes: Vec<String>, limit: usize) -> PyResult<Vec<(String, u32)>> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.top_coauthors(&refs, limit))
+            .map_err(to_py_err)
+    }
+
+    /// Get paper network (summary graph) as a typed NetworkGraph.
+    fn paper_network_typed(&self, bibcodes: Vec<String>) -> PyResult<NetworkGraph> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        self.block_on(self.client.paper_network_typed(&refs))
+            .map_err(to_py_err)
+    }
+
     /// Get co-citation suggestions. Returns a dict.
     fn citation_helper(&self, py: Python<'_>, bibcodes: Vec<String>) -> PyResult<PyObject> {
         let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
         let result = self
-            .runtime
             .block_on(self.client.citation_helper(&refs))
             .map_err(to_py_err)?;
         json_to_py(py, &result)
     }
 
+    // -- Async variants --
+    //
+    // Return awaitables by delegating to `AsyncSciXClient`. Must be called
+    // from a running event loop.
+
+    /// Awaitable `search`.
+    #[pyo3(signature = (query, rows=10))]
+    fn search_async<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().search(py, query, rows)
+    }
+
+    /// Awaitable `search_relevant`.
+    #[pyo3(signature = (query, rows=10))]
+    fn search_relevant_async<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().search_relevant(py, query, rows)
+    }
+
+    /// Awaitable `search_with_options`.
+    #[pyo3(signature = (query, fields="bibcode,title,author,year,pub,abstract,doi,identifier,esources,citation_count,doctype,property".to_string(), sort=None, rows=10, start=0, filters=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn search_with_options_async<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        fields: String,
+        sort: Option<Sort>,
+        rows: u32,
+        start: u32,
+        filters: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async()
+            .search_with_options(py, query, fields, sort, rows, start, filters)
+    }
+
+    /// Awaitable `get_paper`.
+    fn get_paper_async<'py>(
+        &self,
+        py: Python<'py>,
+        identifier: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().get_paper(py, identifier)
+    }
+
+    /// Awaitable `count`.
+    fn count_async<'py>(&self, py: Python<'py>, query: String) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().count(py, query)
+    }
+
+    /// Awaitable `references`.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn references_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().references(py, bibcode, rows)
+    }
+
+    /// Awaitable `citations`.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn citations_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().citations(py, bibcode, rows)
+    }
+
+    /// Awaitable `export`.
+    #[pyo3(signature = (bibcodes, format=ExportFormat::BibTeX, sort=None))]
+    fn export_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+        format: ExportFormat,
+        sort: Option<Sort>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().export(py, bibcodes, format, sort)
+    }
+
+    /// Awaitable `export_bibtex`.
+    fn export_bibtex_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().export_bibtex(py, bibcodes)
+    }
+
+    /// Awaitable `metrics`.
+    fn metrics_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().metrics(py, bibcodes)
+    }
+
+    /// Awaitable `metrics_with_types`.
+    fn metrics_with_types_async<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+        types: Vec<MetricType>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.as_async().metrics_with_types(py, bibcodes, types)
+    }
+
     fn __repr__(&self) -> String {
         format!("SciXClient(base_url='{}')", self.client.base_url)
     }
 }
 
+// ---------------------------------------------------------------------------
+// PyAsyncSciXClient — awaitable wrapper around the async SciXClient
+// ---------------------------------------------------------------------------
+
+/// Runtime driving requests for every `SciXClient` and `AsyncSciXClient`.
+///
+/// Process-wide and never dropped: dropping a runtime while a task waits
+/// for the GIL held by the dropping thread would deadlock.
+fn shared_runtime() -> PyResult<&'static tokio::runtime::Runtime> {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Failed to create async runtime: {}",
+            e
+        ))
+    })?;
+    Ok(RUNTIME.get_or_init(|| runtime))
+}
+
+/// Run `fut` on the tokio runtime and return an asyncio future for its result.
+///
+/// Must be called from a coroutine (it needs the running event loop). The
+/// result is handed back to the loop thread with `call_soon_threadsafe`.
+///
+/// This is the one piece `pyo3-async-runtimes` would replace. That crate is
+/// released in lockstep with pyo3's minor versions, and pythonize is too, so
+/// adopting it means moving all three together; until then this bridge
+/// stays deliberately small, and everything async goes through it.
+fn future_into_py<'py, F, T>(py: Python<'py>, fut: F) -> PyResult<Bound<'py, PyAny>>
+where
+    F: Future<Output = crate::error::Result<T>> + Send + 'static,
+    T: for<'a> IntoPyObject<'a> + Send + 'static,
+{
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let py_future = event_loop.call_method0("create_future")?;
+    let loop_ref = event_loop.unbind();
+    let future_ref = py_future.clone().unbind();
+
+    shared_runtime()?.spawn(async move {
+        let result = fut.await;
+        Python::with_gil(|py| {
+            let (value, is_error) = match result {
+                Ok(v) => match v.into_pyobject(py) {
+                    Ok(obj) => (obj.into_any().unbind(), false),
+                    Err(e) => (e.into().into_value(py).into_any(), true),
+                },
+                Err(e) => (to_py_err(e).into_value(py).into_any(), true),
+            };
+            // If the loop has closed there is no one left to notify.
+            if let Ok(callback) = wrap_pyfunction!(resolve_future, py) {
+                let _ = loop_ref.call_method1(
+                    py,
+                    "call_soon_threadsafe",
+                    (callback, future_ref, value, is_error),
+                );
+            }
+        });
+    });
+
+    Ok(py_future)
+}
+
+/// Complete an asyncio future on its loop, unless it was cancelled meanwhile.
+#[pyfunction]
+fn resolve_future(future: &Bound<'_, PyAny>, value: PyObject, is_error: bool) -> PyResult<()> {
+    if future.call_method0("cancelled")?.is_truthy()? {
+        return Ok(());
+    }
+    let method = if is_error {
+        "set_exception"
+    } else {
+        "set_result"
+    };
+    future.call_method1(method, (value,))?;
+    Ok(())
+}
+
+/// Asyncio SciX (NASA ADS) API client.
+///
+/// Same endpoints as `SciXClient`, but every method returns an awaitable,
+/// so requests don't block the event loop.
+///
+/// Example:
+///     client = scix_client.AsyncSciXClient()
+///     results = await client.search("dark matter", rows=10)
+#[pyclass(name = "AsyncSciXClient")]
+struct PyAsyncSciXClient {
+    client: SciXClient,
+}
+
+#[pymethods]
+impl PyAsyncSciXClient {
+    /// Create a new async SciX client.
+    ///
+    /// If `token` is None, reads from the `SCIX_API_TOKEN` (or `ADS_API_TOKEN`) environment variable.
+    #[new]
+    #[pyo3(signature = (token=None))]
+    fn new(token: Option<String>) -> PyResult<Self> {
+        let client = match token {
+            Some(t) => SciXClient::new(t),
+            None => SciXClient::from_env().map_err(to_py_err)?,
+        };
+        Ok(Self { client })
+    }
+
+    /// Set a custom base URL (e.g., for testing).
+    fn set_base_url(&mut self, url: String) -> PyResult<()> {
+        self.client.base_url = crate::client::validate_base_url(url)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(())
+    }
+
+    // -- Search endpoints --
+
+    /// Search SciX using query syntax.
+    #[pyo3(signature = (query, rows=10))]
+    fn search<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.search(&query, rows).await })
+    }
+
+    /// Search SciX with the best matches first instead of the newest.
+    #[pyo3(signature = (query, rows=10))]
+    fn search_relevant<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(
+            py,
+            async move { client.search_relevant(&query, rows).await },
+        )
+    }
+
+    /// Search with full control over fields, sort, and pagination.
+    #[pyo3(signature = (query, fields="bibcode,title,author,year,pub,abstract,doi,identifier,esources,citation_count,doctype,property".to_string(), sort=None, rows=10, start=0, filters=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn search_with_options<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        fields: String,
+        sort: Option<Sort>,
+        rows: u32,
+        start: u32,
+        filters: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let filters = filters.unwrap_or_default();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = filters.iter().map(|s| s.as_str()).collect();
+            client
+                .search_with_options(&query, &fields, sort.as_ref(), rows, start, &refs)
+                .await
+        })
+    }
+
+    /// Fetch a single paper with rich metadata by bibcode, DOI, or arXiv ID.
+    fn get_paper<'py>(&self, py: Python<'py>, identifier: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.get_paper(&identifier).await })
+    }
+
+    /// Count the papers matching a query without fetching them.
+    fn count<'py>(&self, py: Python<'py>, query: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.count(&query).await })
+    }
+
+    /// Compute min/max/mean/sum of a numeric field over a query's matches.
+    fn field_stats<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        field: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.field_stats(&query, &field).await })
+    }
+
+    /// Get papers referenced by the given paper.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn references<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.references(&bibcode, rows).await })
+    }
+
+    /// Get papers that cite the given paper.
+    #[pyo3(signature = (bibcode, rows=25))]
+    fn citations<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.citations(&bibcode, rows).await })
+    }
+
+    /// Get papers similar to the given paper.
+    #[pyo3(signature = (bibcode, rows=10))]
+    fn similar<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+        rows: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.similar(&bibcode, rows).await })
+    }
+
+    /// Map bibcodes to their current canonical bibcode.
+    fn canonicalize<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.canonicalize(&refs).await
+        })
+    }
+
+    // -- Export and metrics --
+
+    /// Export papers in the specified citation format.
+    #[pyo3(signature = (bibcodes, format=ExportFormat::BibTeX, sort=None))]
+    fn export<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+        format: ExportFormat,
+        sort: Option<Sort>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.export(&refs, format, sort.as_ref()).await
+        })
+    }
+
+    /// Export papers as BibTeX.
+    fn export_bibtex<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.export_bibtex(&refs).await
+        })
+    }
+
+    /// Get citation metrics (h-index, g-index, etc.) for papers.
+    fn metrics<'py>(&self, py: Python<'py>, bibcodes: Vec<String>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.metrics(&refs).await
+        })
+    }
+
+    /// Get only the requested groups of metrics, e.g. `[MetricType.TimeSeries]`.
+    fn metrics_with_types<'py>(
+        &self,
+        py: Python<'py>,
+        bibcodes: Vec<String>,
+        types: Vec<MetricType>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.metrics_with_types(&refs, &types).await
+        })
+    }
+
+    /// Get a paper's cumulative citation count per year as `[(year, total), ...]`.
+    fn citation_growth<'py>(
+        &self,
+        py: Python<'py>,
+        bibcode: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.citation_growth(&bibcode).await })
+    }
+
+    // -- Libraries --
+
+    /// List all libraries for the authenticated user.
+    fn list_libraries<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.list_libraries().await })
+    }
+
+    /// Get a library with its documents.
+    fn get_library<'py>(&self, py: Python<'py>, id: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move { client.get_library(&id).await })
+    }
+
+    /// Add documents (bibcodes) to a library.
+    fn add_documents<'py>(
+        &self,
+        py: Python<'py>,
+        library_id: String,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.add_documents(&library_id, &refs).await
+        })
+    }
+
+    /// Remove documents (bibcodes) from a library.
+    fn remove_documents<'py>(
+        &self,
+        py: Python<'py>,
+        library_id: String,
+        bibcodes: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+            client.remove_documents(&library_id, &refs).await
+        })
+    }
+
+    // -- Resolution --
+
+    /// Resolve free-text references to ADS bibcodes.
+    fn resolve_references<'py>(
+        &self,
+        py: Python<'py>,
+        references: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let refs: Vec<&str> = references.iter().map(|s| s.as_str()).collect();
+            client.resolve_references(&refs).await
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AsyncSciXClient(base_url='{}')", self.client.base_url)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // PyQueryBuilder — mutation-based wrapper for the ownership-based QueryBuilder
 // ---------------------------------------------------------------------------
 
 /// Type-safe SciX query builder.
 ///
+/// Methods modify the builder in place and return it, so calls chain.
+///
 /// Example:
-///     q = scix_client.QueryBuilder()
-///     q.author("Einstein")
-///     q.and_()
-///     q.year_range(1905, 1910)
+///     q = scix_client.QueryBuilder().author("Einstein").and_().year_range(1905, 1910)
 ///     results = client.search(q.build(), rows=10)
 #[pyclass(name = "QueryBuilder")]
 #[derive(Default)]
@@ -598,113 +1664,264 @@ impl PyQueryBuilder {
     }
 
     /// Add an author search term.
-    fn author(&mut self, name: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.author(name);
+    fn author<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.author(name);
+        slf
     }
 
     /// Add a first-author search term.
-    fn first_author(&mut self, name: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.first_author(name);
+    fn first_author<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.first_author(name);
+        slf
     }
 
     /// Add a title search term.
-    fn title(&mut self, text: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.title(text);
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.title(text);
+        slf
     }
 
     /// Add an abstract search term.
-    fn abstract_contains(&mut self, text: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.abstract_contains(text);
+    fn abstract_contains<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.abstract_contains(text);
+        slf
+    }
+
+    /// Add a full-text search term (`full:`). Much slower than metadata searches.
+    fn full_text<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.full_text(text);
+        slf
+    }
+
+    /// Add a keyword search term (`keyword:`).
+    fn keyword<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.keyword(text);
+        slf
+    }
+
+    /// Restrict to records ADS tags with a language (`lang:`), e.g. "en".
+    fn language<'py>(mut slf: PyRefMut<'py, Self>, lang: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.language(lang);
+        slf
+    }
+
+    /// Add a free-text affiliation search term (`aff:`).
+    fn affiliation<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.affiliation(text);
+        slf
+    }
+
+    /// Add a canonical affiliation ID filter (`aff_id:`).
+    fn affiliation_id<'py>(mut slf: PyRefMut<'py, Self>, id: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.affiliation_id(id);
+        slf
+    }
+
+    /// Add a curated institution filter (`inst:`, e.g. "CfA").
+    fn institution<'py>(mut slf: PyRefMut<'py, Self>, id: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.institution(id);
+        slf
+    }
+
+    /// Add a body-only full-text search term (`body:`).
+    fn body<'py>(mut slf: PyRefMut<'py, Self>, text: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.body(text);
+        slf
     }
 
     /// Add an exact year filter.
-    fn year(&mut self, year: u16) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.year(year);
+    fn year<'py>(mut slf: PyRefMut<'py, Self>, year: u16) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.year(year);
+        slf
     }
 
     /// Add a year range filter (inclusive).
-    fn year_range(&mut self, from: u16, to: u16) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.year_range(from, to);
+    fn year_range<'py>(mut slf: PyRefMut<'py, Self>, from: u16, to: u16) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.year_range(from, to);
+        slf
+    }
+
+    /// Add a month-granularity publication-date range (`YYYY-MM`, inclusive).
+    ///
+    /// Raises ValueError if either month isn't `YYYY-MM` or `from` is after `to`.
+    fn pubdate_range<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        from: &str,
+        to: &str,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        slf.inner = slf
+            .inner
+            .clone()
+            .pubdate_range(from, to)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(slf)
     }
 
     /// Add a bibcode filter.
-    fn bibcode(&mut self, bibcode: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.bibcode(bibcode);
+    fn bibcode<'py>(mut slf: PyRefMut<'py, Self>, bibcode: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.bibcode(bibcode);
+        slf
+    }
+
+    /// Match any of several bibcodes in a single grouped clause.
+    fn bibcode_list<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        bibcodes: Vec<String>,
+    ) -> PyRefMut<'py, Self> {
+        let refs: Vec<&str> = bibcodes.iter().map(|s| s.as_str()).collect();
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.bibcode_list(&refs);
+        slf
+    }
+
+    /// Match any of several identifiers (bibcodes, DOIs, arXiv IDs).
+    fn identifier_list<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        identifiers: Vec<String>,
+    ) -> PyRefMut<'py, Self> {
+        let refs: Vec<&str> = identifiers.iter().map(|s| s.as_str()).collect();
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.identifier_list(&refs);
+        slf
     }
 
     /// Add a DOI filter.
-    fn doi(&mut self, doi: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.doi(doi);
+    fn doi<'py>(mut slf: PyRefMut<'py, Self>, doi: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.doi(doi);
+        slf
     }
 
     /// Add an arXiv ID filter.
-    fn arxiv(&mut self, arxiv_id: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.arxiv(arxiv_id);
+    fn arxiv<'py>(mut slf: PyRefMut<'py, Self>, arxiv_id: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.arxiv(arxiv_id);
+        slf
     }
 
     /// Add an astronomical object filter.
-    fn object(&mut self, name: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.object(name);
+    fn object<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.object(name);
+        slf
     }
 
     /// Add a journal abbreviation filter.
-    fn bibstem(&mut self, stem: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.bibstem(stem);
+    fn bibstem<'py>(mut slf: PyRefMut<'py, Self>, stem: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.bibstem(stem);
+        slf
+    }
+
+    /// Add a collection filter (`database:`), e.g. "astronomy" or "physics".
+    fn database<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.database(name);
+        slf
     }
 
     /// Add a property filter (e.g., "refereed", "openaccess").
-    fn property(&mut self, prop: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.property(prop);
+    fn property<'py>(mut slf: PyRefMut<'py, Self>, prop: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.property(prop);
+        slf
     }
 
     /// Add a doctype filter (e.g., "article", "inproceedings").
-    fn doctype(&mut self, dtype: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.doctype(dtype);
+    fn doctype<'py>(mut slf: PyRefMut<'py, Self>, dtype: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.doctype(dtype);
+        slf
     }
 
     /// Add an ORCID filter.
-    fn orcid(&mut self, orcid: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.orcid(orcid);
+    fn orcid<'py>(mut slf: PyRefMut<'py, Self>, orcid: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.orcid(orcid);
+        slf
+    }
+
+    /// Match an ORCID in orcid_pub, orcid_user, or orcid_other.
+    fn orcid_any<'py>(mut slf: PyRefMut<'py, Self>, orcid: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.orcid_any(orcid);
+        slf
     }
 
     /// Add an AND operator.
     #[pyo3(name = "and_")]
-    fn py_and(&mut self) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.and();
+    fn py_and<'py>(mut slf: PyRefMut<'py, Self>) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.and();
+        slf
     }
 
     /// Add an OR operator.
     #[pyo3(name = "or_")]
-    fn py_or(&mut self) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.or();
+    fn py_or<'py>(mut slf: PyRefMut<'py, Self>) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.or();
+        slf
     }
 
     /// Add a NOT operator (exclude the following term).
-    fn exclude(&mut self) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.exclude();
+    fn exclude<'py>(mut slf: PyRefMut<'py, Self>) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.exclude();
+        slf
+    }
+
+    /// Exclude papers matching a field term, emitting `-field:value`.
+    fn not_field<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        field: &str,
+        value: &str,
+    ) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.not_field(field, value);
+        slf
+    }
+
+    /// Exclude papers by an author (`-author:"..."`).
+    fn not_author<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.not_author(name);
+        slf
+    }
+
+    /// Exclude papers with a property (e.g., "nonarticle", "notrefereed").
+    fn not_property<'py>(mut slf: PyRefMut<'py, Self>, prop: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.not_property(prop);
+        slf
+    }
+
+    /// Exclude a doctype (e.g., "proposal", "erratum").
+    fn not_doctype<'py>(mut slf: PyRefMut<'py, Self>, dtype: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.not_doctype(dtype);
+        slf
     }
 
     /// Add a raw query fragment (no escaping).
-    fn raw(&mut self, query: &str) {
-        let inner = std::mem::take(&mut self.inner);
-        self.inner = inner.raw(query);
+    fn raw<'py>(mut slf: PyRefMut<'py, Self>, query: &str) -> PyRefMut<'py, Self> {
+        let inner = std::mem::take(&mut slf.inner);
+        slf.inner = inner.raw(query);
+        slf
     }
 
     /// Build a citations-of query.
@@ -723,6 +1940,14 @@ impl PyQueryBuilder {
         }
     }
 
+    /// Build a reviews-of query (review papers citing the given paper).
+    #[staticmethod]
+    fn reviews_of(bibcode: &str) -> Self {
+        Self {
+            inner: QueryBuilder::reviews_of(bibcode),
+        }
+    }
+
     /// Build a similar-to query.
     #[staticmethod]
     fn similar_to(bibcode: &str) -> Self {
@@ -766,11 +1991,15 @@ impl PyQueryBuilder {
 ///     import scix_client
 ///     client = scix_client.SciXClient()
 ///     results = client.search("dark matter", rows=10)
+///
+///     q = scix_client.QueryBuilder().author("Weinberg").and_().year(1989)
+///     results = client.search(q.build(), rows=10)
 #[pymodule]
 #[pyo3(name = "scix_client")]
 pub fn init_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Wrapper types
     m.add_class::<PySciXClient>()?;
+    m.add_class::<PyAsyncSciXClient>()?;
     m.add_class::<PyQueryBuilder>()?;
 
     // Data types (auto-exposed fields via get_all)
@@ -779,18 +2008,35 @@ pub fn init_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PdfLink>()?;
     m.add_class::<PdfLinkType>()?;
     m.add_class::<SearchResponse>()?;
+    m.add_class::<ResponseMeta>()?;
     m.add_class::<ExportFormat>()?;
+    m.add_class::<ExportResult>()?;
+    m.add_class::<BibEntry>()?;
+    m.add_class::<SyncReport>()?;
+    m.add_class::<Neighborhood>()?;
+    m.add_class::<Dossier>()?;
+    m.add_class::<KeyStyle>()?;
     m.add_class::<Metrics>()?;
     m.add_class::<BasicStats>()?;
     m.add_class::<BasicStatsEntry>()?;
     m.add_class::<CitationStats>()?;
     m.add_class::<CitationStatsEntry>()?;
     m.add_class::<Indicators>()?;
+    m.add_class::<ExternalCitationSummary>()?;
+    m.add_class::<MetricType>()?;
+    m.add_class::<TimeSeriesPoint>()?;
     m.add_class::<Library>()?;
     m.add_class::<LibraryDetail>()?;
+    m.add_class::<FieldStats>()?;
+    m.add_class::<RateLimitStatus>()?;
+    m.add_class::<NetworkGraph>()?;
+    m.add_class::<NetworkNode>()?;
+    m.add_class::<NetworkLink>()?;
     m.add_class::<ObjectResult>()?;
     m.add_class::<ResolvedReference>()?;
+    m.add_class::<ResolvedLink>()?;
     m.add_class::<Sort>()?;
+    m.add_class::<SortField>()?;
     m.add_class::<SortDirection>()?;
 
     Ok(())
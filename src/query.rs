@@ -15,6 +15,8 @@
 //! assert_eq!(query, "author:\"Einstein\" AND year:[1905 TO 1910]");
 //! ```
 
+use crate::error::{Result, SciXError};
+
 /// Builder for constructing ADS query strings.
 #[derive(Debug, Clone, Default)]
 pub struct QueryBuilder {
@@ -51,6 +53,24 @@ impl QueryBuilder {
         self
     }
 
+    /// Add a full-text search term (`full:`).
+    ///
+    /// Matches title, abstract, keywords, acknowledgements, and body text.
+    /// Full-text queries are much slower than metadata searches and match
+    /// passing mentions, so expect more, less relevant results.
+    pub fn full_text(mut self, text: &str) -> Self {
+        self.parts.push(format!("full:{}", quote_phrase(text)));
+        self
+    }
+
+    /// Add a body-only full-text search term (`body:`).
+    ///
+    /// Like [`Self::full_text`] but restricted to the article body. Equally slow.
+    pub fn body(mut self, text: &str) -> Self {
+        self.parts.push(format!("body:{}", quote_phrase(text)));
+        self
+    }
+
     /// Add an exact year filter.
     pub fn year(mut self, year: u16) -> Self {
         self.parts.push(format!("year:{}", year));
@@ -63,12 +83,62 @@ impl QueryBuilder {
         self
     }
 
+    /// Add a publication-date range filter with month granularity (inclusive).
+    ///
+    /// `from` and `to` are `YYYY-MM` months, e.g. `pubdate_range("2020-01",
+    /// "2020-06")` emits `pubdate:[2020-01 TO 2020-06]`. Fails with
+    /// [`SciXError::InvalidQuery`] if either isn't a `YYYY-MM` month or
+    /// `from` is after `to`.
+    pub fn pubdate_range(mut self, from: &str, to: &str) -> Result<Self> {
+        for month in [from, to] {
+            if !is_year_month(month) {
+                return Err(SciXError::InvalidQuery(format!(
+                    "pubdate {:?} is not a YYYY-MM month",
+                    month
+                )));
+            }
+        }
+        if from > to {
+            return Err(SciXError::InvalidQuery(format!(
+                "pubdate range starts after it ends: {} > {}",
+                from, to
+            )));
+        }
+        self.parts.push(format!("pubdate:[{} TO {}]", from, to));
+        Ok(self)
+    }
+
     /// Add a bibcode filter.
     pub fn bibcode(mut self, bibcode: &str) -> Self {
         self.parts.push(format!("bibcode:{}", bibcode));
         self
     }
 
+    /// Match any of several bibcodes in a single grouped clause.
+    ///
+    /// Emits `identifier:(A OR B OR C)`; an empty slice adds nothing.
+    pub fn bibcode_list(mut self, bibcodes: &[&str]) -> Self {
+        if !bibcodes.is_empty() {
+            self.parts
+                .push(format!("identifier:({})", bibcodes.join(" OR ")));
+        }
+        self
+    }
+
+    /// Match any of several identifiers (bibcodes, DOIs, arXiv IDs) in a
+    /// single grouped clause.
+    ///
+    /// Each identifier is quoted, so DOIs and `arXiv:` prefixes are safe.
+    /// An empty slice adds nothing.
+    pub fn identifier_list(mut self, identifiers: &[&str]) -> Self {
+        if !identifiers.is_empty() {
+            let quoted: Vec<String> = identifiers.iter().map(|id| format!("\"{}\"", id)).collect();
+            self.parts
+                .push(format!("identifier:({})", quoted.join(" OR ")));
+        }
+        self
+    }
+
     /// Add a DOI filter.
     pub fn doi(mut self, doi: &str) -> Self {
         self.parts.push(format!("doi:\"{}\"", doi));
@@ -81,6 +151,44 @@ impl QueryBuilder {
         self
     }
 
+    /// Add a keyword search term (`keyword:`).
+    pub fn keyword(mut self, text: &str) -> Self {
+        self.parts.push(format!("keyword:{}", quote_phrase(text)));
+        self
+    }
+
+    /// Restrict to records ADS tags with a language (`lang:`), e.g. `en`.
+    ///
+    /// Most records carry no language, so this also drops untagged papers.
+    pub fn language(mut self, lang: &str) -> Self {
+        self.parts.push(format!("lang:{}", quote_phrase(lang)));
+        self
+    }
+
+    /// Add a free-text affiliation search term (`aff:`).
+    ///
+    /// Matches affiliation strings as printed, so spelling variants of the
+    /// same institution need separate terms; see [`Self::institution`].
+    pub fn affiliation(mut self, text: &str) -> Self {
+        self.parts.push(format!("aff:{}", quote_phrase(text)));
+        self
+    }
+
+    /// Add a canonical affiliation ID filter (`aff_id:`, e.g. "A00211").
+    pub fn affiliation_id(mut self, id: &str) -> Self {
+        self.parts.push(format!("aff_id:{}", quote_phrase(id)));
+        self
+    }
+
+    /// Add a curated institution filter (`inst:`, e.g. "CfA" or "Harvard U").
+    ///
+    /// Uses ADS's normalized institution abbreviations, which gather
+    /// affiliation variants together.
+    pub fn institution(mut self, id: &str) -> Self {
+        self.parts.push(format!("inst:{}", quote_phrase(id)));
+        self
+    }
+
     /// Add an astronomical object filter.
     pub fn object(mut self, name: &str) -> Self {
         self.parts.push(format!("object:\"{}\"", name));
@@ -93,6 +201,17 @@ impl QueryBuilder {
         self
     }
 
+    /// Add a collection filter (`database:`): "astronomy", "physics",
+    /// "general", or "earthscience".
+    ///
+    /// For restricting a search, prefer passing this as a filter query to
+    /// [`SciXClient::search_with_options`](crate::SciXClient::search_with_options),
+    /// which doesn't affect relevance scores.
+    pub fn database(mut self, name: &str) -> Self {
+        self.parts.push(format!("database:{}", name));
+        self
+    }
+
     /// Add a property filter (e.g., "refereed", "openaccess").
     pub fn property(mut self, prop: &str) -> Self {
         self.parts.push(format!("property:{}", prop));
@@ -111,6 +230,21 @@ impl QueryBuilder {
         self
     }
 
+    /// Match an ORCID in any of the three ADS ORCID fields, as one group.
+    ///
+    /// ADS records ORCIDs from publishers (`orcid_pub`), from authors
+    /// claiming papers (`orcid_user`), and from other sources
+    /// (`orcid_other`). Searching all three finds one person's papers
+    /// without the ambiguity of name search, where `author:"Smith, J"`
+    /// matches every J. Smith.
+    pub fn orcid_any(mut self, orcid: &str) -> Self {
+        self.parts.push(format!(
+            "(orcid_pub:{0} OR orcid_user:{0} OR orcid_other:{0})",
+            orcid
+        ));
+        self
+    }
+
     /// Add an AND operator.
     pub fn and(mut self) -> Self {
         self.parts.push("AND".to_string());
@@ -129,6 +263,38 @@ impl QueryBuilder {
         self
     }
 
+    /// Exclude papers matching a field term, emitting `-field:value`.
+    ///
+    /// Values containing whitespace are quoted. Placed after another term
+    /// with no operator between them, ADS combines the two with an implicit
+    /// AND, so `title("x").not_property("nonarticle")` keeps matches of the
+    /// first term that lack the property.
+    pub fn not_field(mut self, field: &str, value: &str) -> Self {
+        let value = if value.contains(char::is_whitespace) {
+            quote_phrase(value)
+        } else {
+            value.to_string()
+        };
+        self.parts.push(format!("-{}:{}", field, value));
+        self
+    }
+
+    /// Exclude papers by an author (`-author:"..."`).
+    pub fn not_author(mut self, name: &str) -> Self {
+        self.parts.push(format!("-author:\"{}\"", name));
+        self
+    }
+
+    /// Exclude papers with a property (e.g., "nonarticle", "notrefereed").
+    pub fn not_property(self, prop: &str) -> Self {
+        self.not_field("property", prop)
+    }
+
+    /// Exclude a doctype (e.g., "proposal", "erratum").
+    pub fn not_doctype(self, dtype: &str) -> Self {
+        self.not_field("doctype", dtype)
+    }
+
     /// Add a raw query fragment (no escaping).
     pub fn raw(mut self, query: &str) -> Self {
         self.parts.push(query.to_string());
@@ -149,6 +315,13 @@ impl QueryBuilder {
         qb
     }
 
+    /// Build a reviews-of query (review papers citing the given paper).
+    pub fn reviews_of(bibcode: &str) -> Self {
+        let mut qb = Self::new();
+        qb.parts.push(format!("reviews(bibcode:{})", bibcode));
+        qb
+    }
+
     /// Build a similar-to query.
     pub fn similar_to(bibcode: &str) -> Self {
         let mut qb = Self::new();
@@ -175,10 +348,39 @@ impl std::fmt::Display for QueryBuilder {
     }
 }
 
+/// Quote a phrase, escaping backslashes and embedded double quotes.
+pub(crate) fn quote_phrase(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Whether `s` is `YYYY-MM` with a month from 01 to 12.
+fn is_year_month(s: &str) -> bool {
+    let Some((year, month)) = s.split_once('-') else {
+        return false;
+    };
+    year.len() == 4
+        && month.len() == 2
+        && year.bytes().all(|b| b.is_ascii_digit())
+        && matches!(month.parse::<u8>(), Ok(1..=12))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_orcid_any() {
+        let q = QueryBuilder::new()
+            .orcid_any("0000-0002-1825-0097")
+            .and()
+            .year(2020)
+            .build();
+        assert_eq!(
+            q,
+            "(orcid_pub:0000-0002-1825-0097 OR orcid_user:0000-0002-1825-0097 OR orcid_other:0000-0002-1825-0097) AND year:2020"
+        );
+    }
+
     #[test]
     fn test_simple_author_query() {
         let q = QueryBuilder::new().author("Einstein").build();
@@ -226,6 +428,166 @@ mod tests {
         assert_eq!(q, "full_text:\"galaxy cluster\" AND year:2023");
     }
 
+    #[test]
+    fn test_bibcode_list_grouped() {
+        let q = QueryBuilder::new()
+            .bibcode_list(&["2016PhRvL.116f1102A", "1998AJ....116.1009R"])
+            .and()
+            .property("refereed")
+            .build();
+        assert_eq!(
+            q,
+            "identifier:(2016PhRvL.116f1102A OR 1998AJ....116.1009R) AND property:refereed"
+        );
+    }
+
+    #[test]
+    fn test_identifier_list_quotes_values() {
+        let q = QueryBuilder::new()
+            .identifier_list(&["10.1086/300499", "arXiv:1602.03837"])
+            .build();
+        assert_eq!(q, "identifier:(\"10.1086/300499\" OR \"arXiv:1602.03837\")");
+    }
+
+    #[test]
+    fn test_empty_lists_add_no_clause() {
+        let q = QueryBuilder::new()
+            .bibcode_list(&[])
+            .identifier_list(&[])
+            .author("Einstein")
+            .build();
+        assert_eq!(q, "author:\"Einstein\"");
+    }
+
+    #[test]
+    fn test_full_text_and_body() {
+        let q = QueryBuilder::new()
+            .full_text("dark matter")
+            .and()
+            .body("fast radio bursts")
+            .build();
+        assert_eq!(q, r#"full:"dark matter" AND body:"fast radio bursts""#);
+    }
+
+    #[test]
+    fn test_full_text_escapes_quotes() {
+        let q = QueryBuilder::new()
+            .full_text(r#"the "Hubble tension" \ H0"#)
+            .build();
+        assert_eq!(q, r#"full:"the \"Hubble tension\" \\ H0""#);
+    }
+
+    #[test]
+    fn test_language_field() {
+        let q = QueryBuilder::new()
+            .abstract_contains("dark matter")
+            .and()
+            .language("en")
+            .build();
+        assert_eq!(q, r#"abs:"dark matter" AND lang:"en""#);
+    }
+
+    #[test]
+    fn test_keyword_and_affiliation_fields() {
+        let q = QueryBuilder::new()
+            .keyword("galaxies: evolution")
+            .and()
+            .affiliation("Harvard-Smithsonian")
+            .and()
+            .affiliation_id("A00211")
+            .or()
+            .institution("CfA")
+            .build();
+        assert_eq!(
+            q,
+            r#"keyword:"galaxies: evolution" AND aff:"Harvard-Smithsonian" AND aff_id:"A00211" OR inst:"CfA""#
+        );
+    }
+
+    #[test]
+    fn test_database_filter() {
+        let q = QueryBuilder::new()
+            .title("neutrino")
+            .and()
+            .database("astronomy")
+            .build();
+        assert_eq!(q, r#"title:"neutrino" AND database:astronomy"#);
+    }
+
+    #[test]
+    fn test_pubdate_range() {
+        let q = QueryBuilder::new()
+            .title("fast radio burst")
+            .and()
+            .pubdate_range("2020-01", "2020-06")
+            .unwrap()
+            .build();
+        assert_eq!(
+            q,
+            r#"title:"fast radio burst" AND pubdate:[2020-01 TO 2020-06]"#
+        );
+
+        let same_month = QueryBuilder::new()
+            .pubdate_range("2023-12", "2023-12")
+            .unwrap()
+            .build();
+        assert_eq!(same_month, "pubdate:[2023-12 TO 2023-12]");
+    }
+
+    #[test]
+    fn test_pubdate_range_rejects_bad_input() {
+        for (from, to) in [
+            ("2020", "2021-01"),
+            ("2020-1", "2020-06"),
+            ("2020-00", "2020-06"),
+            ("2020-01", "2020-13"),
+            ("20x0-01", "2020-06"),
+            ("2020-01-15", "2020-06"),
+            ("2021-01", "2020-06"),
+        ] {
+            let result = QueryBuilder::new().pubdate_range(from, to);
+            assert!(
+                matches!(result, Err(SciXError::InvalidQuery(_))),
+                "{} TO {} should be rejected",
+                from,
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_property_mid_query() {
+        let q = QueryBuilder::new()
+            .abstract_contains("exoplanet")
+            .not_property("nonarticle")
+            .and()
+            .year(2023)
+            .build();
+        assert_eq!(q, r#"abs:"exoplanet" -property:nonarticle AND year:2023"#);
+    }
+
+    #[test]
+    fn test_not_doctype_and_author() {
+        let q = QueryBuilder::new()
+            .title("dark energy")
+            .not_doctype("proposal")
+            .not_author("Smith, J")
+            .build();
+        assert_eq!(
+            q,
+            r#"title:"dark energy" -doctype:proposal -author:"Smith, J""#
+        );
+    }
+
+    #[test]
+    fn test_not_field_quotes_phrases() {
+        let q = QueryBuilder::new()
+            .not_field("keyword", "galaxies: evolution")
+            .not_field("bibstem", "arXiv")
+            .build();
+        assert_eq!(q, r#"-keyword:"galaxies: evolution" -bibstem:arXiv"#);
+    }
+
     #[test]
     fn test_display_trait() {
         let q = QueryBuilder::new().author("Hawking").and().year(1974);
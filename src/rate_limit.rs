@@ -1,82 +1,223 @@
 //! Token-bucket rate limiter for SciX API requests.
 
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use crate::types::RateLimitStatus;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use tokio::time::{Duration, Instant};
 
+/// Minimum time between state file writes while the quota only ticks down.
+const STATE_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Rate limiter that enforces a maximum request rate.
 ///
-/// Uses a token-bucket algorithm. Also tracks ADS rate limit headers
-/// to respect the server-reported quotas.
+/// Uses a token bucket: up to `burst` requests go out immediately, after
+/// which the bucket refills at `max_per_second`. Also tracks ADS rate limit
+/// headers to respect the server-reported quotas.
+///
+/// Clones share one bucket, so a limiter handed to several clients with
+/// [`SciXClient::with_shared_rate_limiter`](crate::SciXClient::with_shared_rate_limiter)
+/// throttles their combined traffic.
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
+    // A std mutex: it is never held across an await, and builder methods
+    // can take it without a runtime.
     inner: Arc<Mutex<RateLimiterInner>>,
 }
 
 #[derive(Debug)]
 struct RateLimiterInner {
-    /// Maximum requests per second.
-    max_per_second: f64,
-    /// Time of the last request.
-    last_request: Option<Instant>,
+    /// Tokens added per second (the sustained request rate).
+    refill_rate: f64,
+    /// Bucket size: how many requests may be sent back to back.
+    capacity: f64,
+    /// Tokens currently available.
+    tokens: f64,
+    /// When `tokens` was last topped up.
+    last_refill: Instant,
     /// Remaining requests from ADS rate limit headers.
     server_remaining: Option<u32>,
+    /// Requests allowed per window, from ADS rate limit headers.
+    server_limit: Option<u32>,
     /// Server-reported rate limit reset time.
     server_reset: Option<Instant>,
+    /// Server-reported reset as a Unix timestamp, kept for persistence.
+    server_reset_unix: Option<u64>,
+    /// File the server-reported quota is persisted to, if any.
+    state_file: Option<PathBuf>,
+    /// The state last written to `state_file`, and when.
+    saved: Option<(RateLimitState, Instant)>,
+}
+
+/// Server-reported quota as persisted between process runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RateLimitState {
+    server_remaining: u32,
+    #[serde(default)]
+    limit: Option<u32>,
+    /// Unix timestamp at which the quota resets.
+    reset: u64,
+}
+
+impl RateLimitState {
+    /// Read a state file, ignoring missing, unreadable, or expired state.
+    fn load(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let state: Self = serde_json::from_str(&content).ok()?;
+        (state.reset > unix_now()).then_some(state)
+    }
+
+    /// Write the state file, creating its parent directory if needed.
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
 }
 
 impl RateLimiter {
     /// Create a new rate limiter with the given maximum requests per second.
+    ///
+    /// Requests are evenly spaced, with no burst allowance.
     pub fn new(max_per_second: f64) -> Self {
+        Self::with_burst(max_per_second, 1)
+    }
+
+    /// Create a rate limiter that allows `burst` back-to-back requests, then
+    /// refills at `max_per_second`.
+    ///
+    /// The bucket starts full. A burst of 0 is treated as 1.
+    pub fn with_burst(max_per_second: f64, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
         Self {
             inner: Arc::new(Mutex::new(RateLimiterInner {
-                max_per_second,
-                last_request: None,
+                refill_rate: max_per_second,
+                capacity,
+                tokens: capacity,
+                last_refill: Instant::now(),
                 server_remaining: None,
+                server_limit: None,
                 server_reset: None,
+                server_reset_unix: None,
+                state_file: None,
+                saved: None,
             })),
         }
     }
 
+    /// Persist the server-reported quota to `path` across process runs.
+    ///
+    /// Any unexpired state already in the file is loaded immediately. The
+    /// file is rewritten when a response changes the reset time or exhausts
+    /// the quota, and otherwise at most every few seconds. Short-lived
+    /// processes such as CLI invocations use this to avoid starting each run
+    /// with a fresh view of the quota.
+    ///
+    /// Clones share the setting, like they share the bucket.
+    pub fn with_state_file(self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let mut inner = self.lock();
+        if let Some(state) = RateLimitState::load(&path) {
+            inner.server_remaining = Some(state.server_remaining);
+            inner.server_limit = state.limit;
+            inner.set_server_reset(state.reset);
+        }
+        inner.state_file = Some(path);
+        inner.saved = None;
+        drop(inner);
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, RateLimiterInner> {
+        // The state stays consistent even if a holder panicked.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Default state file location: `$XDG_CACHE_HOME/scix/ratelimit.json`,
+    /// falling back to `~/.cache/scix/ratelimit.json`.
+    pub fn default_state_path() -> Option<PathBuf> {
+        let cache = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))?;
+        Some(cache.join("scix").join("ratelimit.json"))
+    }
+
+    /// The server-reported quota, or `None` if no response has carried
+    /// rate limit headers yet (and none were loaded from a state file).
+    pub async fn status(&self) -> Option<RateLimitStatus> {
+        let inner = self.lock();
+        if inner.server_remaining.is_none() && inner.server_limit.is_none() {
+            return None;
+        }
+        Some(RateLimitStatus {
+            remaining: inner.server_remaining,
+            limit: inner.server_limit,
+            reset_at: inner.server_reset_unix,
+        })
+    }
+
     /// Wait until a request is allowed, then mark it as sent.
+    ///
+    /// A limiter created with `f64::INFINITY` requests per second never
+    /// waits on the local bucket.
     pub async fn acquire(&self) {
-        let mut inner = self.inner.lock().await;
-
         // Check server-reported limits first
-        if let (Some(remaining), Some(reset)) = (inner.server_remaining, inner.server_reset) {
-            if remaining == 0 && Instant::now() < reset {
-                let wait = reset - Instant::now();
-                drop(inner);
-                tokio::time::sleep(wait).await;
-                inner = self.inner.lock().await;
-            }
+        let quota_wait = self.lock().quota_wait();
+        if let Some(wait) = quota_wait {
+            tokio::time::sleep(wait).await;
         }
 
-        // Enforce local rate limit
-        if let Some(last) = inner.last_request {
-            let min_interval = Duration::from_secs_f64(1.0 / inner.max_per_second);
-            let elapsed = last.elapsed();
-            if elapsed < min_interval {
-                let wait = min_interval - elapsed;
-                drop(inner);
-                tokio::time::sleep(wait).await;
-                inner = self.inner.lock().await;
+        // Take a token from the local bucket, waiting for one if it's empty
+        loop {
+            let token_wait = self.lock().take_token();
+            match token_wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return,
             }
         }
-
-        inner.last_request = Some(Instant::now());
     }
 
     /// Update rate limiter with headers from an ADS API response.
     pub async fn update_from_headers(&self, headers: &reqwest::header::HeaderMap) {
-        let mut inner = self.inner.lock().await;
+        let pending = self.lock().record_headers(headers);
+        if let Some((path, state)) = pending {
+            // Persistence is best-effort; a failed write must not fail the request.
+            let _ = tokio::task::spawn_blocking(move || state.save(&path)).await;
+        }
+    }
+}
 
+impl RateLimiterInner {
+    /// Record the quota from response headers, returning the state to write
+    /// to the state file, if it is due for a write.
+    fn record_headers(
+        &mut self,
+        headers: &reqwest::header::HeaderMap,
+    ) -> Option<(PathBuf, RateLimitState)> {
         if let Some(remaining) = headers
             .get("x-ratelimit-remaining")
             .and_then(|v| v.to_str().ok())
             .and_then(|v| v.parse::<u32>().ok())
         {
-            inner.server_remaining = Some(remaining);
+            self.server_remaining = Some(remaining);
+        }
+
+        if let Some(limit) = headers
+            .get("x-ratelimit-limit")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u32>().ok())
+        {
+            self.server_limit = Some(limit);
         }
 
         if let Some(reset) = headers
@@ -84,17 +225,78 @@ impl RateLimiter {
             .and_then(|v| v.to_str().ok())
             .and_then(|v| v.parse::<u64>().ok())
         {
-            // reset is a Unix timestamp; convert to Instant
-            let now_unix = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
-            if reset > now_unix {
-                let wait = Duration::from_secs(reset - now_unix);
-                inner.server_reset = Some(Instant::now() + wait);
+            self.set_server_reset(reset);
+        }
+
+        let path = self.state_file.clone()?;
+        let state = RateLimitState {
+            server_remaining: self.server_remaining?,
+            limit: self.server_limit,
+            reset: self.server_reset_unix?,
+        };
+        if !self.should_save(&state) {
+            return None;
+        }
+        self.saved = Some((state.clone(), Instant::now()));
+        Some((path, state))
+    }
+
+    /// How long to wait for the server-reported quota to reset, if it is
+    /// exhausted.
+    fn quota_wait(&self) -> Option<Duration> {
+        match (self.server_remaining, self.server_reset) {
+            (Some(0), Some(reset)) => reset.checked_duration_since(Instant::now()),
+            _ => None,
+        }
+    }
+
+    /// Take a token, or return how long until one is available.
+    fn take_token(&mut self) -> Option<Duration> {
+        if self.refill_rate.is_infinite() {
+            return None;
+        }
+        self.refill();
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return None;
+        }
+        Some(Duration::from_secs_f64(
+            (1.0 - self.tokens) / self.refill_rate,
+        ))
+    }
+
+    /// Add the tokens accrued since the last refill, up to capacity.
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let accrued = (now - self.last_refill).as_secs_f64() * self.refill_rate;
+        self.tokens = (self.tokens + accrued).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Whether `state` is worth writing to the state file: it differs from
+    /// what was last written, and either starts a new window, exhausts the
+    /// quota, or comes [`STATE_SAVE_INTERVAL`] after the last write.
+    fn should_save(&self, state: &RateLimitState) -> bool {
+        match &self.saved {
+            None => true,
+            Some((saved, at)) => {
+                saved != state
+                    && (saved.reset != state.reset
+                        || state.server_remaining == 0
+                        || at.elapsed() >= STATE_SAVE_INTERVAL)
             }
         }
     }
+
+    /// Record the server-reported reset time (a Unix timestamp).
+    fn set_server_reset(&mut self, reset: u64) {
+        let now_unix = unix_now();
+        if reset > now_unix {
+            let wait = Duration::from_secs(reset - now_unix);
+            self.server_reset = Some(Instant::now() + wait);
+            self.server_reset_unix = Some(reset);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -122,4 +324,180 @@ mod tests {
         limiter.acquire().await;
         assert!(start.elapsed() < Duration::from_millis(50));
     }
+
+    // These run on paused time: sleeps complete instantly and `Instant`
+    // only moves when the limiter waits, so elapsed times are exact.
+
+    #[tokio::test(start_paused = true)]
+    async fn test_infinite_rate_never_waits() {
+        let limiter = RateLimiter::new(f64::INFINITY);
+        let start = Instant::now();
+        for _ in 0..1000 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_throttle() {
+        let limiter = RateLimiter::with_burst(20.0, 3); // 50ms per token
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // The bucket is empty; the fourth request waits for a refill
+        limiter.acquire().await;
+        let waited = start.elapsed();
+        assert!(waited >= Duration::from_millis(50), "{:?}", waited);
+        assert!(waited < Duration::from_millis(51), "{:?}", waited);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_refills_while_idle() {
+        let limiter = RateLimiter::with_burst(100.0, 2); // 10ms per token
+        limiter.acquire().await;
+        limiter.acquire().await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Idle time beyond a full bucket isn't banked.
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn test_status_reflects_headers() {
+        let limiter = RateLimiter::new(5.0);
+        assert_eq!(limiter.status().await, None);
+
+        let reset = unix_now() + 3600;
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "5000".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "4321".parse().unwrap());
+        headers.insert("x-ratelimit-reset", reset.to_string().parse().unwrap());
+        limiter.update_from_headers(&headers).await;
+
+        assert_eq!(
+            limiter.status().await,
+            Some(RateLimitStatus {
+                remaining: Some(4321),
+                limit: Some(5000),
+                reset_at: Some(reset),
+            })
+        );
+        assert_eq!(limiter.status().await.unwrap().used(), Some(679));
+
+        // A response without a limit header keeps the last known limit.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "4320".parse().unwrap());
+        limiter.update_from_headers(&headers).await;
+        let status = limiter.status().await.unwrap();
+        assert_eq!(status.limit, Some(5000));
+        assert_eq!(status.used(), Some(680));
+    }
+
+    #[tokio::test]
+    async fn test_state_file_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("scix-ratelimit-{}", std::process::id()))
+            .join("ratelimit.json");
+        let _ = std::fs::remove_file(&path);
+        let reset = unix_now() + 600;
+
+        let limiter = RateLimiter::new(5.0).with_state_file(&path);
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        headers.insert("x-ratelimit-reset", reset.to_string().parse().unwrap());
+        limiter.update_from_headers(&headers).await;
+
+        let reloaded = RateLimiter::new(5.0).with_state_file(&path);
+        let inner = reloaded.lock();
+        assert_eq!(inner.server_remaining, Some(42));
+        assert_eq!(inner.server_reset_unix, Some(reset));
+        assert!(inner.server_reset.is_some());
+        drop(inner);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_state_file_on_shared_limiter() {
+        let path =
+            std::env::temp_dir().join(format!("scix-ratelimit-shared-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let limiter = RateLimiter::new(5.0);
+        let shared = limiter.clone();
+        let _limiter = limiter.with_state_file(&path);
+
+        // The clone handed out earlier persists through the same setting.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        let reset = (unix_now() + 600).to_string();
+        headers.insert("x-ratelimit-reset", reset.parse().unwrap());
+        shared.update_from_headers(&headers).await;
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("\"server_remaining\":42"), "{}", saved);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_state_file_writes_are_throttled() {
+        let path = std::env::temp_dir().join(format!(
+            "scix-ratelimit-throttle-{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let limiter = RateLimiter::new(5.0).with_state_file(&path);
+        let reset = (unix_now() + 600).to_string();
+        let update = |remaining: &str| {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert("x-ratelimit-remaining", remaining.parse().unwrap());
+            headers.insert("x-ratelimit-reset", reset.parse().unwrap());
+            headers
+        };
+        let saved_remaining = || {
+            let saved: RateLimitState =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            saved.server_remaining
+        };
+
+        limiter.update_from_headers(&update("42")).await;
+        assert_eq!(saved_remaining(), 42);
+
+        // A routine decrement right after a write is not persisted...
+        limiter.update_from_headers(&update("41")).await;
+        assert_eq!(saved_remaining(), 42);
+
+        // ...but running out of quota always is.
+        limiter.update_from_headers(&update("0")).await;
+        assert_eq!(saved_remaining(), 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_state_file_ignores_expired_state() {
+        let path = std::env::temp_dir().join(format!(
+            "scix-ratelimit-expired-{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, r#"{"server_remaining":0,"reset":1}"#).unwrap();
+
+        let limiter = RateLimiter::new(5.0).with_state_file(&path);
+        let inner = limiter.lock();
+        assert_eq!(inner.server_remaining, None);
+        assert!(inner.server_reset.is_none());
+        drop(inner);
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -13,11 +13,11 @@ impl SciXClient {
     pub async fn resolve_references(&self, references: &[&str]) -> Result<Vec<ResolvedReference>> {
         let text = references.join("\n");
         let response_body = self
-            .post_text("/reference/text", "text/plain", &text)
+            .post_text("/reference/text", &[], "text/plain", &text)
             .await?;
 
         let parsed: serde_json::Value = serde_json::from_str(&response_body)
-            .map_err(|e| SciXError::Parse(format!("Invalid reference response: {}", e)))?;
+            .map_err(|e| SciXError::parse("/reference/text", e, &response_body))?;
 
         let resolved = parsed["resolved"]
             .as_array()
@@ -1,29 +1,185 @@
 //! Search and discovery endpoints.
 //!
-//! Covers: search, bigquery, references, citations, similar, coreads.
+//! Covers: search, batch search, bigquery, references, citations, similar,
+//! coreads, reviews, useful.
 
 use crate::client::SciXClient;
-use crate::error::Result;
-use crate::parse::{parse_search_response, DEFAULT_SEARCH_FIELDS};
-use crate::types::{SearchResponse, Sort};
+use crate::error::{Result, SciXError};
+use crate::parse::{
+    next_cursor_mark, parse_canonical_response, parse_count_response, parse_field_stats_response,
+    parse_search_response, parse_search_response_strict, parse_version_keys, DEFAULT_SEARCH_FIELDS,
+    RICH_FIELDS,
+};
+use crate::query::QueryBuilder;
+use crate::types::{Author, Dossier, FieldStats, Neighborhood, Paper, SearchResponse, Sort};
+use futures_util::{Stream, StreamExt};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Parallelism of [`SciXClient::batch_search`] when the client has no
+/// [`SciXClient::with_max_concurrency`] cap.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// Rows requested per page by [`SciXClient::search_stream`], the ADS maximum.
+const SEARCH_STREAM_PAGE: u32 = 2000;
 
 impl SciXClient {
-    /// Search the SciX database.
+    /// Search the SciX database, newest first (`date desc`).
     ///
     /// Uses ADS query syntax: `author:"Einstein" year:1905`, `title:"dark matter"`, etc.
+    /// For keyword searches, [`Self::search_relevant`] ranks the best matches first.
     pub async fn search(&self, query: &str, rows: u32) -> Result<SearchResponse> {
-        self.search_with_options(query, DEFAULT_SEARCH_FIELDS, None, rows, 0)
+        self.search_with_options(query, DEFAULT_SEARCH_FIELDS, None, rows, 0, &[])
+            .await
+    }
+
+    /// Search the SciX database, best matches first ([`Sort::relevance`]).
+    pub async fn search_relevant(&self, query: &str, rows: u32) -> Result<SearchResponse> {
+        let sort = Sort::relevance();
+        self.search_with_options(query, DEFAULT_SEARCH_FIELDS, Some(&sort), rows, 0, &[])
             .await
     }
 
-    /// Search with full control over fields, sort, and pagination.
+    /// Run several searches in parallel, returning results in query order.
+    ///
+    /// At most [`DEFAULT_BATCH_CONCURRENCY`] searches are in flight at once,
+    /// or the client's own [`Self::with_max_concurrency`] cap if it has one.
+    /// A failed search doesn't stop the others.
+    pub async fn batch_search(
+        &self,
+        queries: Vec<String>,
+        rows: u32,
+    ) -> Vec<Result<SearchResponse>> {
+        let client = match self.concurrency {
+            Some(_) => self.clone(),
+            None => self.clone().with_max_concurrency(DEFAULT_BATCH_CONCURRENCY),
+        };
+        let handles: Vec<_> = queries
+            .into_iter()
+            .map(|query| {
+                let client = client.clone();
+                tokio::spawn(async move { client.search(&query, rows).await })
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(
+                handle
+                    .await
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())),
+            );
+        }
+        results
+    }
+
+    /// Count the papers matching a query without fetching any documents.
+    ///
+    /// Cheaper than `search(q, 1).num_found`: no rows are requested and no
+    /// papers are constructed.
+    pub async fn count(&self, query: &str) -> Result<u64> {
+        let body = self.get("/search/query", &count_params(query)).await?;
+        parse_count_response(&body)
+    }
+
+    /// Compute min/max/mean/sum of a numeric field over all papers matching a query.
+    ///
+    /// Aggregated server-side by Solr, so no documents are downloaded. Works
+    /// with numeric fields such as `citation_count`, `read_count`, or `year`.
+    pub async fn field_stats(&self, query: &str, field: &str) -> Result<FieldStats> {
+        let body = self
+            .get("/search/query", &field_stats_params(query, field))
+            .await?;
+        parse_field_stats_response(&body, field)
+    }
+
+    /// Map bibcodes to their current canonical form.
+    ///
+    /// ADS reassigns bibcodes over time (e.g. an arXiv bibcode replaced by
+    /// the journal one), keeping the old value as an `alternate_bibcode`.
+    /// Each input ADS recognizes maps to the document's current bibcode;
+    /// unrecognized inputs are absent from the map.
+    pub async fn canonicalize(&self, bibcodes: &[&str]) -> Result<HashMap<String, String>> {
+        let mut canonical = HashMap::new();
+        for chunk in bibcodes.chunks(CANONICALIZE_CHUNK) {
+            let query = QueryBuilder::new().identifier_list(chunk).build();
+            let rows = chunk.len().to_string();
+            let params = [
+                ("q", query.as_str()),
+                ("fl", "bibcode,alternate_bibcode,identifier"),
+                ("rows", rows.as_str()),
+            ];
+            let body = self.get("/search/query", &params).await?;
+            canonical.extend(parse_canonical_response(&body, chunk)?);
+        }
+        Ok(canonical)
+    }
+
+    /// Apply [`Self::canonicalize`] if enabled with
+    /// [`SciXClient::with_canonical_bibcodes`], keeping unknown bibcodes.
+    pub(crate) async fn current_bibcodes(&self, bibcodes: &[&str]) -> Result<Vec<String>> {
+        if !self.canonicalize_bibcodes || bibcodes.is_empty() {
+            return Ok(bibcodes.iter().map(|b| b.to_string()).collect());
+        }
+        let canonical = self.canonicalize(bibcodes).await?;
+        Ok(bibcodes
+            .iter()
+            .map(|b| canonical.get(*b).cloned().unwrap_or_else(|| b.to_string()))
+            .collect())
+    }
+
+    /// Fetch a single paper with rich metadata (abstract, keywords, affiliations).
+    ///
+    /// Accepts any identifier ADS knows: bibcode, DOI, or arXiv ID.
+    pub async fn get_paper(&self, identifier: &str) -> Result<Paper> {
+        let query = format!("identifier:{}", identifier);
+        let results = self
+            .search_with_options(&query, RICH_FIELDS, None, 1, 0, &[])
+            .await?;
+
+        results
+            .papers
+            .into_iter()
+            .next()
+            .ok_or_else(|| SciXError::NotFound(format!("Paper not found: {}", identifier)))
+    }
+
+    /// Fetch just a paper's abstract, or `None` if it has none.
+    ///
+    /// Much lighter than [`get_paper`](Self::get_paper): only the bibcode,
+    /// title (without which the document would be dropped), and abstract are
+    /// requested.
+    pub async fn abstract_of(&self, bibcode: &str) -> Result<Option<String>> {
+        let query = format!("identifier:{}", bibcode);
+        let results = self
+            .search_with_options(&query, "bibcode,title,abstract", None, 1, 0, &[])
+            .await?;
+
+        results
+            .papers
+            .into_iter()
+            .next()
+            .map(|paper| paper.abstract_text)
+            .ok_or_else(|| SciXError::NotFound(format!("Paper not found: {}", bibcode)))
+    }
+
+    /// Search with full control over fields, sort, pagination, and filters.
+    ///
+    /// With no `sort`, results come newest first (`date desc`).
+    ///
+    /// `fields` is a [`FieldSet`](crate::types::FieldSet) preset or a raw
+    /// comma-separated field list.
+    ///
+    /// Each entry in `filters` is sent as a Solr filter query (`fq`), e.g.
+    /// `database:astronomy`. Filters restrict results without affecting
+    /// relevance scores, and Solr caches them separately from the main query.
     pub async fn search_with_options(
         &self,
         query: &str,
-        fields: &str,
+        fields: impl AsRef<str>,
         sort: Option<&Sort>,
         rows: u32,
         start: u32,
+        filters: &[&str],
     ) -> Result<SearchResponse> {
         let rows_str = rows.to_string();
         let start_str = start.to_string();
@@ -31,21 +187,199 @@ impl SciXClient {
             .map(|s| s.to_string())
             .unwrap_or_else(|| "date desc".to_string());
 
-        let params = vec![
-            ("q", query),
-            ("fl", fields),
-            ("rows", &rows_str),
-            ("start", &start_str),
-            ("sort", &sort_str),
-        ];
+        let params = search_params(
+            query,
+            fields.as_ref(),
+            &rows_str,
+            &start_str,
+            &sort_str,
+            filters,
+        );
+        let body = self.get("/search/query", &params).await?;
+        self.parse_search(&body)
+    }
+
+    /// Parse a search response, strictly if
+    /// [`with_strict_parsing`](Self::with_strict_parsing) is on.
+    fn parse_search(&self, body: &str) -> Result<SearchResponse> {
+        if self.strict_parsing {
+            parse_search_response_strict(body)
+        } else {
+            parse_search_response(body)
+        }
+    }
+
+    /// Fetch one page of a cursor-paged search, returning the next cursor mark.
+    ///
+    /// Start with `cursor_mark` `"*"` and pass each returned mark back in to
+    /// walk the full result set; the search is exhausted when the returned
+    /// mark equals the one sent. Unlike `start` offsets, cursors stay cheap
+    /// deep into large result sets. Solr needs a unique sort tiebreaker, so
+    /// `id asc` is appended to `sort` (default `date desc`).
+    pub async fn search_with_cursor(
+        &self,
+        query: &str,
+        fields: &str,
+        sort: Option<&Sort>,
+        rows: u32,
+        cursor_mark: &str,
+    ) -> Result<(SearchResponse, String)> {
+        self.cursor_page(query, fields, sort, rows, cursor_mark, &[])
+            .await
+    }
+
+    /// [`Self::search_with_cursor`] with `fq` filters.
+    async fn cursor_page(
+        &self,
+        query: &str,
+        fields: &str,
+        sort: Option<&Sort>,
+        rows: u32,
+        cursor_mark: &str,
+        filters: &[&str],
+    ) -> Result<(SearchResponse, String)> {
+        let rows_str = rows.to_string();
+        let sort_str = cursor_sort(sort);
+        let mut params = cursor_params(query, fields, &rows_str, &sort_str, cursor_mark).to_vec();
+        params.extend(filters.iter().map(|f| ("fq", *f)));
+        let body = self.get("/search/query", &params).await?;
+        Ok((self.parse_search(&body)?, next_cursor_mark(&body)?))
+    }
+
+    /// Stream up to `max` papers matching a query, one at a time.
+    ///
+    /// Pages through the results with [`Self::search_with_cursor`], fetching
+    /// the next page only once the previous one has been consumed, so memory
+    /// stays bounded however large `max` is. The stream ends early when the
+    /// results run out and stops after the first error. `filters` are sent
+    /// as `fq` parameters, as in [`Self::search_with_options`].
+    pub fn search_stream<'a>(
+        &'a self,
+        query: &'a str,
+        fields: &'a str,
+        sort: Option<&'a Sort>,
+        filters: &'a [&'a str],
+        max: u32,
+    ) -> impl Stream<Item = Result<Paper>> + 'a {
+        let start = Some(("*".to_string(), max));
+        futures_util::stream::unfold(start, move |state| async move {
+            let (cursor, remaining) = state?;
+            if remaining == 0 {
+                return None;
+            }
+            let rows = remaining.min(SEARCH_STREAM_PAGE);
+            match self
+                .cursor_page(query, fields, sort, rows, &cursor, filters)
+                .await
+            {
+                Ok((page, next)) => {
+                    let fetched = page.papers.len() as u32;
+                    let next = (next != cursor && fetched > 0)
+                        .then(|| (next, remaining.saturating_sub(fetched)));
+                    Some((Ok(page.papers), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+        .flat_map(|page| {
+            let items: Vec<Result<Paper>> = match page {
+                Ok(papers) => papers.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            futures_util::stream::iter(items)
+        })
+        .take(max as usize)
+    }
+
+    /// Search and return the ADS response as untyped JSON.
+    ///
+    /// An escape hatch for Solr fields that [`Paper`] doesn't model, such as
+    /// `citation`, `reference`, `read_count`, or `grant`: every requested
+    /// field comes back as ADS sent it, under `response.docs`. Include
+    /// `bibcode` in `fields` so documents can be matched to other calls.
+    /// `fields` may also be a [`FieldSet`](crate::types::FieldSet) preset.
+    pub async fn search_raw(
+        &self,
+        query: &str,
+        fields: impl AsRef<str>,
+        sort: Option<&Sort>,
+        rows: u32,
+        start: u32,
+    ) -> Result<serde_json::Value> {
+        let rows_str = rows.to_string();
+        let start_str = start.to_string();
+        let sort_str = sort
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "date desc".to_string());
 
+        let params = search_params(
+            query,
+            fields.as_ref(),
+            &rows_str,
+            &start_str,
+            &sort_str,
+            &[],
+        );
         let body = self.get("/search/query", &params).await?;
-        parse_search_response(&body)
+        serde_json::from_str(&body).map_err(|e| SciXError::parse("/search/query", e, &body))
+    }
+
+    /// Ask ADS how it parses a query, returning its query tree.
+    ///
+    /// Calls `/search/qtree`, the parser ADS runs before searching. Useful
+    /// when a query matches far more or fewer papers than expected: the tree
+    /// shows which words became fields, phrases, or operators (for example,
+    /// a missing quote turning `author:Le Fevre` into an author term plus a
+    /// free-text term). ADS sends the tree as a JSON string; it is decoded
+    /// here. See [`crate::format::format_query_tree`] for a readable outline.
+    pub async fn explain_query(&self, query: &str) -> Result<serde_json::Value> {
+        let body = self.get("/search/qtree", &[("q", query)]).await?;
+        let parsed: serde_json::Value =
+            serde_json::from_str(&body).map_err(|e| SciXError::parse("/search/qtree", e, &body))?;
+        match &parsed["qtree"] {
+            serde_json::Value::String(tree) => {
+                serde_json::from_str(tree).map_err(|e| SciXError::parse("/search/qtree", e, &body))
+            }
+            serde_json::Value::Null => Err(SciXError::parse(
+                "/search/qtree",
+                "missing qtree field",
+                &body,
+            )),
+            tree => Ok(tree.clone()),
+        }
+    }
+
+    /// Search, returning one record per preprint/published version group.
+    ///
+    /// A record is dropped when its bibcode or any of its identifiers
+    /// (alternate bibcodes, DOIs, arXiv IDs) already appeared on an earlier
+    /// record, so the first version in `sort` order (default `date desc`)
+    /// is kept. Solr can't collapse on the multi-valued `alternate_bibcode`
+    /// field, so this happens client-side: a page may hold fewer than `rows`
+    /// papers, and `num_found` still counts every version.
+    pub async fn search_collapse_versions(
+        &self,
+        query: &str,
+        rows: u32,
+        sort: Option<&Sort>,
+    ) -> Result<SearchResponse> {
+        let rows_str = rows.to_string();
+        let sort_str = sort
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "date desc".to_string());
+        let params = search_params(query, DEFAULT_SEARCH_FIELDS, &rows_str, "0", &sort_str, &[]);
+        let body = self.get("/search/query", &params).await?;
+        let mut results = self.parse_search(&body)?;
+        collapse_versions(&mut results.papers, &parse_version_keys(&body)?);
+        Ok(results)
     }
 
     /// Bigquery: search within a set of known bibcodes.
     ///
     /// Useful for filtering a large set of papers by additional criteria.
+    /// Follows the ADS contract: `q`, `fl`, `rows`, and `sort` are URL query
+    /// parameters, and the body is a `big-query/csv` list with a `bibcode`
+    /// header line followed by one bibcode per line.
     pub async fn bigquery(
         &self,
         bibcodes: &[&str],
@@ -61,15 +395,23 @@ impl SciXClient {
             .unwrap_or_else(|| "date desc".to_string());
         let rows_val = rows.unwrap_or(bibcodes.len() as u32);
 
-        let bibcode_list = bibcodes.join("\n");
-        let body = serde_json::json!({
-            "bibcodes": bibcode_list.split('\n').collect::<Vec<_>>(),
-            "query": format!("q={}&fl={}&rows={}&sort={}", q, fl, rows_val, sort_str),
-        });
+        let rows_str = rows_val.to_string();
+        let params = [
+            ("q", q),
+            ("fl", fl),
+            ("rows", &rows_str),
+            ("sort", &sort_str),
+        ];
 
-        // Bigquery uses POST to /search/bigquery
-        let response_body = self.post_json("/search/bigquery", &body).await?;
-        parse_search_response(&response_body)
+        let response_body = self
+            .post_text(
+                "/search/bigquery",
+                &params,
+                "big-query/csv",
+                &bigquery_body(bibcodes),
+            )
+            .await?;
+        self.parse_search(&response_body)
     }
 
     /// Fetch papers referenced by the given paper.
@@ -84,6 +426,79 @@ impl SciXClient {
         self.search(&query, rows).await
     }
 
+    /// Fetch a paper's references and citations together.
+    ///
+    /// Both searches run concurrently (two requests, still subject to the
+    /// rate limit and any [`with_max_concurrency`](Self::with_max_concurrency)
+    /// cap), each limited to `rows` papers and deduplicated by bibcode. A
+    /// common starting point for a literature review.
+    pub async fn neighborhood(&self, bibcode: &str, rows: u32) -> Result<Neighborhood> {
+        let (references, citations) = tokio::join!(
+            self.references(bibcode, rows),
+            self.citations(bibcode, rows)
+        );
+        let (mut references, mut citations) = (references?, citations?);
+        // Dedup adjusts `num_found`; the totals report what ADS knows of.
+        let (num_references, num_citations) = (references.num_found, citations.num_found);
+        references.dedup_by_bibcode();
+        citations.dedup_by_bibcode();
+        Ok(Neighborhood {
+            bibcode: bibcode.to_string(),
+            references: references.papers,
+            citations: citations.papers,
+            num_references,
+            num_citations,
+        })
+    }
+
+    /// Fetch a paper's metadata, metrics, references, citations, and links
+    /// in one call.
+    ///
+    /// The five requests run concurrently, subject to the rate limit and any
+    /// [`with_max_concurrency`](Self::with_max_concurrency) cap; references
+    /// and citations are limited to `rows` papers. A failed request doesn't
+    /// fail the dossier: that part is left `None` and its error recorded in
+    /// [`Dossier::errors`].
+    pub async fn dossier(&self, bibcode: &str, rows: u32) -> Dossier {
+        let bibcodes = [bibcode];
+        let (paper, metrics, references, citations, links) = tokio::join!(
+            self.get_paper(bibcode),
+            self.metrics(&bibcodes),
+            self.references(bibcode, rows),
+            self.citations(bibcode, rows),
+            self.resolve_links_typed(bibcode, None),
+        );
+
+        let mut errors = BTreeMap::new();
+        Dossier {
+            bibcode: bibcode.to_string(),
+            paper: dossier_part("paper", paper, &mut errors),
+            metrics: dossier_part("metrics", metrics, &mut errors),
+            references: dossier_part("references", references, &mut errors),
+            citations: dossier_part("citations", citations, &mut errors),
+            links: dossier_part("links", links, &mut errors),
+            errors,
+        }
+    }
+
+    /// Fetch papers that cite the given paper, dropping self-citations.
+    ///
+    /// A citing paper counts as a self-citation when any of its authors
+    /// matches `author_name` (ADS format, e.g. `"Einstein, A."`): same family
+    /// name, and same first initial when both names have one. Filtering is
+    /// client-side on the first `rows` citations, so fewer than `rows` papers
+    /// may come back; `num_found` is the server's unfiltered total.
+    pub async fn citations_excluding_self(
+        &self,
+        bibcode: &str,
+        author_name: &str,
+        rows: u32,
+    ) -> Result<SearchResponse> {
+        let mut results = self.citations(bibcode, rows).await?;
+        exclude_author(&mut results.papers, author_name);
+        Ok(results)
+    }
+
     /// Fetch papers similar to the given paper (content-based).
     pub async fn similar(&self, bibcode: &str, rows: u32) -> Result<SearchResponse> {
         let query = format!("similar(bibcode:{})", bibcode);
@@ -95,4 +510,762 @@ impl SciXClient {
         let query = format!("trending(bibcode:{})", bibcode);
         self.search(&query, rows).await
     }
+
+    /// Fetch review papers citing the given paper, most relevant first.
+    pub async fn reviews(&self, bibcode: &str, rows: u32) -> Result<SearchResponse> {
+        let query = format!("reviews(bibcode:{})", bibcode);
+        self.search(&query, rows).await
+    }
+
+    /// Fetch one author's papers by ORCID iD.
+    ///
+    /// Name search conflates different people who share a surname and
+    /// initial, and misses papers under variant spellings. ORCID identifies
+    /// one person, so this searches all three ADS ORCID fields (see
+    /// [`QueryBuilder::orcid_any`]). Papers only appear once the ORCID is on
+    /// the record, from the publisher or from the author claiming them.
+    pub async fn author_orcid(
+        &self,
+        orcid: &str,
+        rows: u32,
+        sort: Option<&Sort>,
+    ) -> Result<SearchResponse> {
+        let query = QueryBuilder::new().orcid_any(orcid).build();
+        self.search_with_options(&query, DEFAULT_SEARCH_FIELDS, sort, rows, 0, &[])
+            .await
+    }
+
+    /// Fetch the papers most useful for understanding a set of papers.
+    ///
+    /// Uses the `useful()` second-order operator over the bibcode list,
+    /// `useful(bibcode:(A OR B ...))`, which ranks papers frequently cited
+    /// together by the set — a "foundational reading" list. Unlike
+    /// [`Self::citation_helper`], the result is a regular search response.
+    pub async fn useful(&self, bibcodes: &[&str], rows: u32) -> Result<SearchResponse> {
+        let query = useful_query(bibcodes)?;
+        self.search(&query, rows).await
+    }
+}
+
+/// The `big-query/csv` request body: a `bibcode` header, then one per line.
+fn bigquery_body(bibcodes: &[&str]) -> String {
+    std::iter::once("bibcode")
+        .chain(bibcodes.iter().copied())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Build the `useful()` query for a non-empty bibcode list.
+fn useful_query(bibcodes: &[&str]) -> Result<String> {
+    if bibcodes.is_empty() {
+        return Err(SciXError::InvalidQuery(
+            "useful() needs at least one bibcode".to_string(),
+        ));
+    }
+    Ok(format!("useful(bibcode:({}))", bibcodes.join(" OR ")))
+}
+
+/// Remove papers with an author matching `author_name`.
+fn exclude_author(papers: &mut Vec<Paper>, author_name: &str) {
+    let author = Author::from_ads_format(author_name);
+    papers.retain(|paper| !paper.authors.iter().any(|a| same_author(a, &author)));
+}
+
+/// Loose author match: family name, plus first initial when both have one.
+fn same_author(a: &Author, b: &Author) -> bool {
+    if !a.family_name.eq_ignore_ascii_case(&b.family_name) {
+        return false;
+    }
+    let initial = |author: &Author| {
+        author
+            .given_name
+            .as_deref()
+            .and_then(|g| g.chars().next())
+            .map(|c| c.to_lowercase().to_string())
+    };
+    match (initial(a), initial(b)) {
+        (Some(x), Some(y)) => x == y,
+        _ => true,
+    }
+}
+
+/// Bibcodes looked up per canonicalization query. The list travels in the
+/// GET query string, so this keeps URLs around 4 KB, well under the 8 KB
+/// limit common to servers and proxies.
+const CANONICALIZE_CHUNK: usize = 100;
+
+/// Keep only the first paper of each version group, in result order.
+///
+/// `keys` maps a bibcode to the other identifiers of its record; two papers
+/// are versions of each other when any of these overlap.
+fn collapse_versions(papers: &mut Vec<Paper>, keys: &HashMap<String, Vec<String>>) {
+    let mut seen = HashSet::new();
+    papers.retain(|paper| {
+        let ids: Vec<&str> = std::iter::once(paper.bibcode.as_str())
+            .chain(
+                keys.get(&paper.bibcode)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            )
+            .collect();
+        if ids.iter().any(|id| seen.contains(*id)) {
+            return false;
+        }
+        seen.extend(ids.into_iter().map(str::to_string));
+        true
+    });
+}
+
+/// The sort for a cursor-paged search, with the `id asc` tiebreaker Solr requires.
+fn cursor_sort(sort: Option<&Sort>) -> String {
+    format!(
+        "{}, id asc",
+        sort.map(|s| s.to_string())
+            .unwrap_or_else(|| "date desc".to_string())
+    )
+}
+
+/// Query parameters for one page of a cursor-paged search.
+fn cursor_params<'a>(
+    query: &'a str,
+    fields: &'a str,
+    rows: &'a str,
+    sort: &'a str,
+    cursor_mark: &'a str,
+) -> [(&'a str, &'a str); 5] {
+    [
+        ("q", query),
+        ("fl", fields),
+        ("rows", rows),
+        ("sort", sort),
+        ("cursorMark", cursor_mark),
+    ]
+}
+
+/// Query parameters for a search, with one `fq` per filter.
+fn search_params<'a>(
+    query: &'a str,
+    fields: &'a str,
+    rows: &'a str,
+    start: &'a str,
+    sort: &'a str,
+    filters: &[&'a str],
+) -> Vec<(&'a str, &'a str)> {
+    let mut params = vec![
+        ("q", query),
+        ("fl", fields),
+        ("rows", rows),
+        ("start", start),
+        ("sort", sort),
+    ];
+    params.extend(filters.iter().map(|f| ("fq", *f)));
+    params
+}
+
+/// Query parameters for a stats-only search over one field.
+fn field_stats_params<'a>(query: &'a str, field: &'a str) -> [(&'a str, &'a str); 5] {
+    [
+        ("q", query),
+        ("fl", "bibcode"),
+        ("rows", "0"),
+        ("stats", "true"),
+        ("stats.field", field),
+    ]
+}
+
+/// Query parameters for a count-only search.
+fn count_params(query: &str) -> [(&str, &str); 3] {
+    [("q", query), ("fl", "bibcode"), ("rows", "0")]
+}
+
+/// Unwrap one part of a [`Dossier`], recording its error under `name`.
+fn dossier_part<T>(
+    name: &str,
+    result: Result<T>,
+    errors: &mut BTreeMap<String, String>,
+) -> Option<T> {
+    result
+        .map_err(|e| errors.insert(name.to_string(), e.to_string()))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_count_requests_no_documents() {
+        let body = r#"{"responseHeader": {"status": 0, "QTime": 3},
+            "response": {"numFound": 48213, "start": 0, "docs": []}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+        let client = mock.client();
+
+        assert_eq!(client.count("dark matter").await.unwrap(), 48213);
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("rows=0"));
+        assert!(target.contains("fl=bibcode"));
+
+        // A zero-row search parses the same body into an empty page.
+        let results = client.search("dark matter", 0).await.unwrap();
+        assert_eq!(results.num_found, 48213);
+        assert!(results.papers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_stream_follows_cursor_up_to_max() {
+        let body = r#"{"response": {"numFound": 5, "start": 0, "docs": [
+                {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]},
+                {"bibcode": "2016PhRvL.116x1103A", "title": ["GW151226"]}]},
+            "nextCursorMark": "AoE"}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+        let client = mock.client();
+
+        let papers: Vec<Paper> = client
+            .search_stream("gw", "bibcode,title", None, &["database:physics"], 3)
+            .map(|paper| paper.unwrap())
+            .collect()
+            .await;
+        assert_eq!(papers.len(), 3);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].target.contains("rows=3"));
+        assert!(requests[1].target.contains("cursorMark=AoE"));
+        assert!(requests[1].target.contains("rows=1"));
+        assert!(requests[1].target.contains("fq=database%3Aphysics"));
+    }
+
+    #[tokio::test]
+    async fn test_relevance_sort_in_dry_run() {
+        let client = SciXClient::new("t")
+            .with_base_url("https://api.example.org/v1")
+            .with_dry_run(true);
+        let sort = Sort::relevance();
+        let described = client
+            .search_raw("dark matter halos", "bibcode", Some(&sort), 5, 0)
+            .await
+            .unwrap();
+        assert!(described["url"]
+            .as_str()
+            .unwrap()
+            .contains("sort=score+desc"));
+
+        // Searches without an explicit sort stay newest first.
+        let described = client
+            .search_raw("dark matter halos", "bibcode", None, 5, 0)
+            .await
+            .unwrap();
+        assert!(described["url"]
+            .as_str()
+            .unwrap()
+            .contains("sort=date+desc"));
+    }
+
+    #[tokio::test]
+    async fn test_search_relevant_sorts_by_score() {
+        let body = r#"{"response": {"numFound": 0, "start": 0, "docs": []}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        mock.client()
+            .search_relevant("dark matter halos", 5)
+            .await
+            .unwrap();
+        assert!(mock.requests()[0].target.contains("sort=score+desc"));
+    }
+
+    #[tokio::test]
+    async fn test_search_with_field_preset() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"], "year": "2016"}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+        let client = mock.client();
+
+        let results = client
+            .search_with_options("gw", crate::types::FieldSet::Minimal, None, 5, 0, &[])
+            .await
+            .unwrap();
+        assert_eq!(results.papers[0].year, Some(2016));
+        client
+            .search_with_options("gw", "bibcode,title,year", None, 5, 0, &[])
+            .await
+            .unwrap();
+
+        let requests = mock.requests();
+        assert!(requests[0].target.contains("fl=bibcode%2Ctitle%2Cyear"));
+        assert_eq!(requests[0].target, requests[1].target);
+    }
+
+    #[tokio::test]
+    async fn test_strict_parsing_client_option() {
+        let body = r#"{"response": {"numFound": 2, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}, {"bibcode": "2016PhRvL.116x1103A"}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let lenient = mock.client().search("gw", 5).await.unwrap();
+        assert_eq!(lenient.papers.len(), 1);
+        let err = mock
+            .client()
+            .with_strict_parsing(true)
+            .search("gw", 5)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SciXError::Parse { .. }), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_canonicalize_chunks_long_lists() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "alternate_bibcode": ["2016arXiv160203837T"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let stale: Vec<String> = (0..CANONICALIZE_CHUNK + 1)
+            .map(|i| format!("2016arXiv1602{:05}T", i))
+            .collect();
+        let mut refs: Vec<&str> = stale.iter().map(String::as_str).collect();
+        refs.push("2016arXiv160203837T");
+        let canonical = mock.client().canonicalize(&refs).await.unwrap();
+        assert_eq!(canonical["2016arXiv160203837T"], "2016PhRvL.116f1102A");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert!(request.target.len() < 8 * 1024, "{}", request.target.len());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_collapse_versions_keeps_first_version() {
+        let body = r#"{"response": {"numFound": 3, "start": 0, "docs": [
+            {"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"],
+             "identifier": ["2016PhRvL.116f1102A", "2016arXiv160203837T", "arXiv:1602.03837", "10.1103/PhysRevLett.116.061102"]},
+            {"bibcode": "2016arXiv160203837T", "title": ["GW150914 (preprint)"],
+             "identifier": ["2016arXiv160203837T", "arXiv:1602.03837"]},
+            {"bibcode": "2016PhRvL.116x1103A", "title": ["GW151226"],
+             "identifier": ["2016PhRvL.116x1103A", "arXiv:1606.04855"]}
+        ]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let sort = Sort::new("citation_count", crate::types::SortDirection::Desc);
+        let results = mock
+            .client()
+            .search_collapse_versions("gravitational waves", 25, Some(&sort))
+            .await
+            .unwrap();
+        let bibcodes: Vec<&str> = results.papers.iter().map(|p| p.bibcode.as_str()).collect();
+        assert_eq!(bibcodes, vec!["2016PhRvL.116f1102A", "2016PhRvL.116x1103A"]);
+        assert_eq!(results.num_found, 3);
+
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("q=gravitational+waves"));
+        assert!(target.contains("rows=25"));
+        assert!(target.contains("sort=citation_count+desc"));
+        assert!(target.contains("identifier"));
+        assert!(!target.contains("fq="));
+    }
+
+    #[tokio::test]
+    async fn test_abstract_of() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"], "abstract": "On September 14, 2015 ..."}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let text = mock
+            .client()
+            .abstract_of("2016PhRvL.116f1102A")
+            .await
+            .unwrap();
+        assert_eq!(text.as_deref(), Some("On September 14, 2015 ..."));
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("q=identifier%3A2016PhRvL.116f1102A"));
+        assert!(target.contains("fl=bibcode%2Ctitle%2Cabstract"));
+        assert!(target.contains("rows=1"));
+    }
+
+    #[tokio::test]
+    async fn test_abstract_of_missing_abstract_and_paper() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "1905AnP...322..891E", "title": ["Zur Elektrodynamik bewegter Körper"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+        assert_eq!(
+            mock.client()
+                .abstract_of("1905AnP...322..891E")
+                .await
+                .unwrap(),
+            None
+        );
+
+        let empty = r#"{"response": {"numFound": 0, "start": 0, "docs": []}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", empty)
+            .start()
+            .await;
+        let err = mock.client().abstract_of("nope").await.unwrap_err();
+        assert!(matches!(err, SciXError::NotFound(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_reviews_query() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016LRR....19....1A", "title": ["A review"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let results = mock
+            .client()
+            .reviews("2016PhRvL.116f1102A", 5)
+            .await
+            .unwrap();
+        assert_eq!(results.papers[0].bibcode, "2016LRR....19....1A");
+
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("q=reviews%28bibcode%3A2016PhRvL.116f1102A%29"));
+        assert!(target.contains("rows=5"));
+    }
+
+    #[tokio::test]
+    async fn test_batch_search_bounds_in_flight_requests() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .delay(Duration::from_millis(50))
+            .start()
+            .await;
+
+        let client = mock.client().with_max_concurrency(2);
+        let queries: Vec<String> = (0..6).map(|i| format!("q{}", i)).collect();
+        let results = client.batch_search(queries, 1).await;
+
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(|r| r.as_ref().unwrap().num_found == 1));
+        assert_eq!(mock.requests().len(), 6);
+        assert_eq!(mock.max_in_flight(), 2);
+    }
+
+    #[test]
+    fn test_bigquery_body() {
+        assert_eq!(
+            bigquery_body(&["2016PhRvL.116f1102A", "1998AJ....116.1009R"]),
+            "bibcode\n2016PhRvL.116f1102A\n1998AJ....116.1009R"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bigquery_encodes_params_on_url() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "title": ["GW150914"]}]}}"#;
+        let mock = MockServer::new()
+            .route("POST", "/search/bigquery", body)
+            .start()
+            .await;
+
+        let sort = Sort::new("citation_count", crate::types::SortDirection::Desc);
+        let results = mock
+            .client()
+            .bigquery(
+                &["2016PhRvL.116f1102A", "1998AJ....116.1009R"],
+                Some("abs:\"black hole\" & year:2016"),
+                Some("bibcode,title"),
+                Some(&sort),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.papers.len(), 1);
+
+        let request = &mock.requests()[0];
+        assert_eq!(
+            request.target,
+            "/search/bigquery?q=abs%3A%22black+hole%22+%26+year%3A2016&fl=bibcode%2Ctitle&rows=2&sort=citation_count+desc"
+        );
+        assert_eq!(
+            request.body,
+            "bibcode\n2016PhRvL.116f1102A\n1998AJ....116.1009R"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explain_query_decodes_tree() {
+        let tree = serde_json::json!({
+            "name": "OPERATOR", "label": "DEFOP", "children": [
+                {"name": "FIELD", "children": [
+                    {"name": "TERM_NORMAL", "input": "author"},
+                    {"name": "QNORMAL", "input": "Le"}
+                ]},
+                {"name": "QNORMAL", "input": "Fevre"}
+            ]
+        });
+        let body = serde_json::json!({
+            "responseHeader": {"status": 0},
+            "qtree": tree.to_string()
+        });
+        let mock = MockServer::new()
+            .route("GET", "/search/qtree", body.to_string())
+            .start()
+            .await;
+
+        let explained = mock
+            .client()
+            .explain_query("author:Le Fevre")
+            .await
+            .unwrap();
+        assert_eq!(explained, tree);
+        assert!(mock.requests()[0].target.contains("q=author%3ALe+Fevre"));
+    }
+
+    #[tokio::test]
+    async fn test_neighborhood_runs_both_searches() {
+        let body = r#"{"response": {"numFound": 3, "start": 0, "docs": [
+            {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]},
+            {"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]},
+            {"bibcode": "1999ApJ...517..565P", "title": ["Omega and Lambda"]}
+        ]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let hood = mock
+            .client()
+            .neighborhood("2011RvMP...83..835K", 25)
+            .await
+            .unwrap();
+        assert_eq!(hood.bibcode, "2011RvMP...83..835K");
+        assert_eq!(hood.references.len(), 2);
+        assert_eq!(hood.citations.len(), 2);
+        // Totals are the server's, not the deduplicated page size.
+        assert_eq!(hood.num_references, 3);
+        assert_eq!(hood.num_citations, 3);
+        assert_eq!(hood.papers().len(), 2);
+
+        let mut queries: Vec<String> = mock.requests().iter().map(|r| r.target.clone()).collect();
+        queries.sort();
+        assert!(queries[0].contains("q=citations%28bibcode%3A2011RvMP...83..835K%29"));
+        assert!(queries[1].contains("q=references%28bibcode%3A2011RvMP...83..835K%29"));
+    }
+
+    #[tokio::test]
+    async fn test_dossier_collects_every_part() {
+        let search = r#"{"response": {"numFound": 1, "docs": [
+            {"bibcode": "2011RvMP...83..835K", "title": ["Exoplanets"]}
+        ]}}"#;
+        let metrics = r#"{"indicators": {"h": 1}}"#;
+        let links = r#"{"links": {"count": 1, "records": [
+            {"url": "https://arxiv.org/abs/1101.0001", "title": "arXiv", "link_type": "ESOURCE|EPRINT_HTML"}
+        ]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", search)
+            .route("POST", "/metrics", metrics)
+            .route("GET", "/resolver/2011RvMP...83..835K", links)
+            .start()
+            .await;
+
+        let dossier = mock.client().dossier("2011RvMP...83..835K", 5).await;
+        assert!(dossier.errors.is_empty(), "{:?}", dossier.errors);
+        assert_eq!(dossier.paper.unwrap().title, "Exoplanets");
+        assert_eq!(dossier.metrics.unwrap().indicators.unwrap().h, Some(1));
+        assert_eq!(dossier.references.unwrap().num_found, 1);
+        assert_eq!(dossier.citations.unwrap().num_found, 1);
+        assert_eq!(
+            dossier.links.unwrap()[0].url,
+            "https://arxiv.org/abs/1101.0001"
+        );
+        assert_eq!(mock.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_dossier_tolerates_failed_parts() {
+        let search = r#"{"response": {"numFound": 0, "docs": []}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", search)
+            .start()
+            .await;
+
+        let dossier = mock.client().dossier("2011RvMP...83..835K", 5).await;
+        assert!(dossier.paper.is_none());
+        assert!(dossier.metrics.is_none());
+        assert!(dossier.links.is_none());
+        assert_eq!(dossier.references.unwrap().num_found, 0);
+        assert_eq!(dossier.citations.unwrap().num_found, 0);
+        let failed: Vec<&str> = dossier.errors.keys().map(String::as_str).collect();
+        assert_eq!(failed, ["links", "metrics", "paper"]);
+        assert!(dossier.errors["paper"].contains("Paper not found"));
+    }
+
+    #[tokio::test]
+    async fn test_search_raw_keeps_unmodeled_fields() {
+        let body = r#"{"responseHeader": {"status": 0}, "response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2016PhRvL.116f1102A", "read_count": 812, "grant": ["NSF PHY-0757058"], "reference": ["1975ApJ...195L..51H"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let raw = mock
+            .client()
+            .search_raw(
+                "bibcode:2016PhRvL.116f1102A",
+                "bibcode,read_count,grant,reference",
+                None,
+                1,
+                0,
+            )
+            .await
+            .unwrap();
+        let doc = &raw["response"]["docs"][0];
+        assert_eq!(doc["read_count"], 812);
+        assert_eq!(doc["grant"][0], "NSF PHY-0757058");
+        assert_eq!(doc["reference"][0], "1975ApJ...195L..51H");
+        assert!(mock.requests()[0]
+            .target
+            .contains("fl=bibcode%2Cread_count%2Cgrant%2Creference"));
+    }
+
+    #[tokio::test]
+    async fn test_author_orcid_searches_all_orcid_fields() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "2020ApJ...900....1C", "title": ["A paper"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let results = mock
+            .client()
+            .author_orcid("0000-0002-1825-0097", 50, Some(&Sort::date_desc()))
+            .await
+            .unwrap();
+        assert_eq!(results.papers.len(), 1);
+
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("q=%28orcid_pub%3A0000-0002-1825-0097+OR+orcid_user%3A0000-0002-1825-0097+OR+orcid_other%3A0000-0002-1825-0097%29"));
+        assert!(target.contains("sort=date+desc"));
+        assert!(target.contains("rows=50"));
+    }
+
+    #[test]
+    fn test_useful_query() {
+        assert_eq!(
+            useful_query(&["2016PhRvL.116f1102A", "1998AJ....116.1009R"]).unwrap(),
+            "useful(bibcode:(2016PhRvL.116f1102A OR 1998AJ....116.1009R))"
+        );
+        assert!(matches!(useful_query(&[]), Err(SciXError::InvalidQuery(_))));
+    }
+
+    #[tokio::test]
+    async fn test_useful_sends_operator_query() {
+        let body = r#"{"response": {"numFound": 1, "start": 0, "docs": [{"bibcode": "1998AJ....116.1009R", "title": ["Supernovae"]}]}}"#;
+        let mock = MockServer::new()
+            .route("GET", "/search/query", body)
+            .start()
+            .await;
+
+        let results = mock
+            .client()
+            .useful(&["2016PhRvL.116f1102A"], 20)
+            .await
+            .unwrap();
+        assert_eq!(results.papers[0].bibcode, "1998AJ....116.1009R");
+
+        let target = &mock.requests()[0].target;
+        assert!(target.contains("q=useful%28bibcode%3A%282016PhRvL.116f1102A%29%29"));
+        assert!(target.contains("rows=20"));
+    }
+
+    #[test]
+    fn test_search_params_pass_filters_as_fq() {
+        let params = search_params(
+            "dark matter",
+            "bibcode",
+            "10",
+            "0",
+            "date desc",
+            &["database:astronomy", "property:refereed"],
+        );
+        let fq: Vec<&str> = params
+            .iter()
+            .filter(|(k, _)| *k == "fq")
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(fq, vec!["database:astronomy", "property:refereed"]);
+        assert!(params.contains(&("q", "dark matter")));
+    }
+
+    #[test]
+    fn test_search_params_without_filters() {
+        let params = search_params("q", "bibcode", "10", "0", "date desc", &[]);
+        assert!(params.iter().all(|(k, _)| *k != "fq"));
+    }
+
+    #[test]
+    fn test_count_params_request_no_rows() {
+        let params = count_params("author:\"Einstein\"");
+        assert!(params.contains(&("q", "author:\"Einstein\"")));
+        assert!(params.contains(&("rows", "0")));
+        assert!(params.contains(&("fl", "bibcode")));
+    }
+
+    #[test]
+    fn test_cursor_params_use_mark_and_tiebreaker() {
+        let sort = cursor_sort(None);
+        assert_eq!(sort, "date desc, id asc");
+        let params = cursor_params("gw", "bibcode,title", "200", &sort, "*");
+        assert!(params.contains(&("cursorMark", "*")));
+        assert!(params.contains(&("sort", "date desc, id asc")));
+        assert!(!params.iter().any(|(key, _)| *key == "start"));
+    }
+
+    #[test]
+    fn test_exclude_author_drops_self_citation() {
+        let mut papers = vec![
+            Paper::test_stub("2017ApJ...848L..13A")
+                .with_authors(&["Abbott, B. P.", "Riess, A. G."]),
+            Paper::test_stub("2018Natur.562..545S").with_authors(&["Smith, J."]),
+            // Same family name, different person.
+            Paper::test_stub("2019MNRAS.485.1234A").with_authors(&["Abbott, T. M. C."]),
+        ];
+        exclude_author(&mut papers, "Abbott, Benjamin P.");
+
+        let bibcodes: Vec<&str> = papers.iter().map(|p| p.bibcode.as_str()).collect();
+        assert_eq!(bibcodes, vec!["2018Natur.562..545S", "2019MNRAS.485.1234A"]);
+    }
+
+    #[test]
+    fn test_same_author_without_initials() {
+        let a = Author::from_ads_format("abbott");
+        let b = Author::from_ads_format("Abbott, B. P.");
+        assert!(same_author(&a, &b));
+        assert!(!same_author(&a, &Author::from_ads_format("Riess, A.")));
+    }
+
+    #[test]
+    fn test_field_stats_params() {
+        let params = field_stats_params("dark matter", "citation_count");
+        assert!(params.contains(&("stats", "true")));
+        assert!(params.contains(&("stats.field", "citation_count")));
+        assert!(params.contains(&("rows", "0")));
+    }
 }
@@ -1,12 +1,13 @@
 //! `scix setup` — one-command MCP server configuration for AI editors.
 //!
-//! Detects installed editors (Claude Code, Claude Desktop, Cursor, Zed),
-//! prompts for an API token, validates it, and writes the correct MCP
-//! config for each editor.
+//! Detects installed editors (Claude Code, Claude Desktop, Cursor, Zed,
+//! Windsurf, and the Continue and Cline VS Code extensions), prompts for an
+//! API token, validates it, and writes the correct MCP config for each editor.
+//! With `--remove`, deletes the scix entry from each detected editor instead.
 
 use crate::error::{Result, SciXError};
 use crate::SciXClient;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Supported AI editors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
@@ -15,6 +16,24 @@ pub enum EditorTarget {
     ClaudeDesktop,
     Cursor,
     Zed,
+    Windsurf,
+    /// The Continue extension for VS Code.
+    Continue,
+    /// The Cline extension for VS Code.
+    Cline,
+}
+
+impl EditorTarget {
+    /// Every supported editor, in detection order.
+    pub const ALL: [EditorTarget; 7] = [
+        Self::ClaudeCode,
+        Self::ClaudeDesktop,
+        Self::Cursor,
+        Self::Zed,
+        Self::Windsurf,
+        Self::Continue,
+        Self::Cline,
+    ];
 }
 
 impl std::fmt::Display for EditorTarget {
@@ -24,19 +43,27 @@ impl std::fmt::Display for EditorTarget {
             Self::ClaudeDesktop => write!(f, "Claude Desktop"),
             Self::Cursor => write!(f, "Cursor"),
             Self::Zed => write!(f, "Zed"),
+            Self::Windsurf => write!(f, "Windsurf"),
+            Self::Continue => write!(f, "Continue (VS Code)"),
+            Self::Cline => write!(f, "Cline (VS Code)"),
         }
     }
 }
 
+/// VS Code extension ID of Cline, which names its settings directory.
+const CLINE_EXTENSION_ID: &str = "saoudrizwan.claude-dev";
+
 struct DetectedEditor {
     target: EditorTarget,
     config_path: Option<PathBuf>,
     use_cli: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum ConfigResult {
     Configured,
+    Removed,
+    NotConfigured,
     Skipped,
     Failed(String),
 }
@@ -45,6 +72,8 @@ impl std::fmt::Display for ConfigResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Configured => write!(f, "done"),
+            Self::Removed => write!(f, "removed"),
+            Self::NotConfigured => write!(f, "not configured"),
             Self::Skipped => write!(f, "skipped"),
             Self::Failed(msg) => write!(f, "FAILED ({})", msg),
         }
@@ -140,18 +169,40 @@ fn claude_cli_available() -> bool {
         .unwrap_or(false)
 }
 
+/// VS Code's per-user settings directory.
+fn vscode_user_dir(home: &Path) -> Option<PathBuf> {
+    if cfg!(target_os = "macos") {
+        Some(home.join("Library/Application Support/Code/User"))
+    } else if cfg!(target_os = "windows") {
+        std::env::var("APPDATA")
+            .ok()
+            .map(|p| PathBuf::from(p).join("Code/User"))
+    } else {
+        Some(home.join(".config/Code/User"))
+    }
+}
+
 /// Detect which editors are installed.
 fn detect_editors(filter: Option<EditorTarget>) -> Vec<DetectedEditor> {
-    let mut editors = Vec::new();
+    match dirs::home_dir() {
+        Some(home) => detect_editors_in(&home, filter, claude_cli_available),
+        None => Vec::new(),
+    }
+}
 
-    let home = match dirs::home_dir() {
-        Some(h) => h,
-        None => return editors,
-    };
+/// Detect which editors are installed under `home`.
+///
+/// `claude_cli` is only consulted when Claude Code is a candidate.
+fn detect_editors_in(
+    home: &Path,
+    filter: Option<EditorTarget>,
+    claude_cli: impl Fn() -> bool,
+) -> Vec<DetectedEditor> {
+    let mut editors = Vec::new();
 
     // Claude Code: prefer CLI, fallback to config file.
     if filter.is_none() || filter == Some(EditorTarget::ClaudeCode) {
-        let has_cli = claude_cli_available();
+        let has_cli = claude_cli();
         let claude_dir = home.join(".claude");
         if has_cli || claude_dir.exists() {
             editors.push(DetectedEditor {
@@ -213,10 +264,50 @@ fn detect_editors(filter: Option<EditorTarget>) -> Vec<DetectedEditor> {
         }
     }
 
+    // Windsurf
+    if filter.is_none() || filter == Some(EditorTarget::Windsurf) {
+        let windsurf_dir = home.join(".codeium/windsurf");
+        if windsurf_dir.exists() {
+            editors.push(DetectedEditor {
+                target: EditorTarget::Windsurf,
+                config_path: Some(windsurf_dir.join("mcp_config.json")),
+                use_cli: false,
+            });
+        }
+    }
+
+    // Continue: picks up any Claude Desktop-style JSON file in its
+    // mcpServers directory, so scix gets a file of its own.
+    if filter.is_none() || filter == Some(EditorTarget::Continue) {
+        let continue_dir = home.join(".continue");
+        if continue_dir.exists() {
+            editors.push(DetectedEditor {
+                target: EditorTarget::Continue,
+                config_path: Some(continue_dir.join("mcpServers/scix.json")),
+                use_cli: false,
+            });
+        }
+    }
+
+    // Cline keeps its settings in the extension's VS Code global storage.
+    if filter.is_none() || filter == Some(EditorTarget::Cline) {
+        if let Some(user_dir) = vscode_user_dir(home) {
+            let cline_dir = user_dir.join("globalStorage").join(CLINE_EXTENSION_ID);
+            if cline_dir.exists() {
+                editors.push(DetectedEditor {
+                    target: EditorTarget::Cline,
+                    config_path: Some(cline_dir.join("settings/cline_mcp_settings.json")),
+                    use_cli: false,
+                });
+            }
+        }
+    }
+
     editors
 }
 
-/// Build the MCP server entry for standard editors (Claude Code/Desktop, Cursor).
+/// Build the MCP server entry for standard editors (Claude Code/Desktop,
+/// Cursor, Windsurf, Continue).
 fn standard_mcp_entry(binary: &str, token: &str) -> serde_json::Value {
     serde_json::json!({
         "command": binary,
@@ -240,6 +331,14 @@ fn zed_mcp_entry(binary: &str, token: &str) -> serde_json::Value {
     })
 }
 
+/// Build the MCP server entry for Cline, which also records whether the
+/// server is enabled.
+fn cline_mcp_entry(binary: &str, token: &str) -> serde_json::Value {
+    let mut entry = standard_mcp_entry(binary, token);
+    entry["disabled"] = serde_json::Value::Bool(false);
+    entry
+}
+
 /// Update a JSON config file, inserting the scix entry under the given section key.
 /// Returns Ok(true) if the entry already existed.
 fn update_json_config(
@@ -324,6 +423,60 @@ fn update_json_config(
     Ok(ConfigResult::Configured)
 }
 
+/// Delete the scix entry from the given section of a JSON config file.
+fn remove_json_config(path: &Path, section_key: &str) -> std::result::Result<ConfigResult, String> {
+    if !path.exists() {
+        return Ok(ConfigResult::NotConfigured);
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    let mut root: serde_json::Value = serde_json::from_str(&content).map_err(|_| {
+        format!(
+            "Could not parse {} (may contain comments). Remove \"scix\" from \"{}\" manually.",
+            path.display(),
+            section_key
+        )
+    })?;
+
+    let removed = root
+        .get_mut(section_key)
+        .and_then(|v| v.as_object_mut())
+        .and_then(|section| section.remove("scix"))
+        .is_some();
+    if !removed {
+        return Ok(ConfigResult::NotConfigured);
+    }
+
+    let output = serde_json::to_string_pretty(&root)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::write(path, output.as_bytes())
+        .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+
+    Ok(ConfigResult::Removed)
+}
+
+/// Remove the scix server from Claude Code via its CLI, in every scope.
+fn remove_claude_code_cli() -> ConfigResult {
+    let mut removed = false;
+    for scope in ["user", "local", "project"] {
+        let status = std::process::Command::new("claude")
+            .args(["mcp", "remove", "scix", "--scope", scope])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+        match status {
+            Ok(s) if s.success() => removed = true,
+            Ok(_) => {}
+            Err(e) => return ConfigResult::Failed(format!("failed to run claude CLI: {}", e)),
+        }
+    }
+    if removed {
+        ConfigResult::Removed
+    } else {
+        ConfigResult::NotConfigured
+    }
+}
+
 /// Configure Claude Code via its CLI.
 fn configure_claude_code_cli(binary: &str, token: &str) -> ConfigResult {
     // Remove existing entry first (ignore errors — it may not exist).
@@ -370,6 +523,7 @@ fn configure_editor(editor: &DetectedEditor, binary: &str, token: &str, yes: boo
 
     let (section_key, entry) = match editor.target {
         EditorTarget::Zed => ("context_servers", zed_mcp_entry(binary, token)),
+        EditorTarget::Cline => ("mcpServers", cline_mcp_entry(binary, token)),
         _ => ("mcpServers", standard_mcp_entry(binary, token)),
     };
 
@@ -382,12 +536,84 @@ fn configure_editor(editor: &DetectedEditor, binary: &str, token: &str, yes: boo
     }
 }
 
-/// Run the setup wizard.
+/// Remove the scix entry from a single editor.
+fn unconfigure_editor(editor: &DetectedEditor) -> ConfigResult {
+    if editor.use_cli && editor.target == EditorTarget::ClaudeCode {
+        return remove_claude_code_cli();
+    }
+
+    let path = match &editor.config_path {
+        Some(p) => p,
+        None => return ConfigResult::Failed("no config path".to_string()),
+    };
+
+    let section_key = match editor.target {
+        EditorTarget::Zed => "context_servers",
+        _ => "mcpServers",
+    };
+
+    match remove_json_config(path, section_key) {
+        Ok(result) => result,
+        Err(msg) => {
+            eprintln!("  {}", msg);
+            ConfigResult::Failed("parse error".to_string())
+        }
+    }
+}
+
+/// Print one editor's status, aligned with the others.
+fn print_editor_result(target: EditorTarget, result: &ConfigResult) {
+    let pad = 20usize.saturating_sub(target.to_string().len());
+    println!("  {}:{}{}", target, " ".repeat(pad.max(1)), result);
+}
+
+/// Remove the scix MCP server from every detected editor.
+fn run_remove(editor: Option<EditorTarget>) -> Result<()> {
+    println!();
+    println!("scix setup --remove \u{2014} SciX MCP Server Removal");
+    println!("================================================");
+    println!();
+
+    let detected = detect_editors(editor);
+    if detected.is_empty() {
+        match editor {
+            Some(target) => println!("{} was not detected on this system.", target),
+            None => println!("No supported editors detected."),
+        }
+        return Ok(());
+    }
+
+    println!("Removing scix from editors...");
+    let mut any_removed = false;
+    for editor_info in &detected {
+        let result = unconfigure_editor(editor_info);
+        print_editor_result(editor_info.target, &result);
+        if result == ConfigResult::Removed {
+            any_removed = true;
+        }
+    }
+    println!();
+
+    if any_removed {
+        println!("Removal complete. Restart your editors to drop the scix server.");
+    } else {
+        println!("scix was not configured in any detected editor.");
+    }
+
+    Ok(())
+}
+
+/// Run the setup wizard, or remove the scix entries when `remove` is set.
 pub async fn run_setup(
     editor: Option<EditorTarget>,
     skip_validation: bool,
     yes: bool,
+    remove: bool,
 ) -> Result<()> {
+    if remove {
+        return run_remove(editor);
+    }
+
     println!();
     println!("scix setup \u{2014} SciX MCP Server Setup");
     println!("====================================");
@@ -414,19 +640,12 @@ pub async fn run_setup(
 
     // 4. Detect editors.
     println!("Detecting editors...");
-    let all_targets = [
-        EditorTarget::ClaudeCode,
-        EditorTarget::ClaudeDesktop,
-        EditorTarget::Cursor,
-        EditorTarget::Zed,
-    ];
-
     let detected = detect_editors(editor);
     let detected_targets: Vec<EditorTarget> = detected.iter().map(|e| e.target).collect();
 
     if editor.is_none() {
         // Show all editors with found/absent status.
-        for target in &all_targets {
+        for target in &EditorTarget::ALL {
             if detected_targets.contains(target) {
                 println!("  [found]   {}", target);
             } else {
@@ -456,13 +675,7 @@ pub async fn run_setup(
     let mut any_configured = false;
     for editor_info in &detected {
         let result = configure_editor(editor_info, &binary, &token, yes);
-        let pad = 15 - editor_info.target.to_string().len();
-        println!(
-            "  {}:{}{}",
-            editor_info.target,
-            " ".repeat(pad.max(1)),
-            result
-        );
+        print_editor_result(editor_info.target, &result);
         if matches!(result, ConfigResult::Configured) {
             any_configured = true;
         }
@@ -479,3 +692,148 @@ pub async fn run_setup(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh, empty directory standing in for `$HOME`.
+    fn fake_home(name: &str) -> PathBuf {
+        let home = std::env::temp_dir().join(format!("scix-setup-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&home);
+        std::fs::create_dir_all(&home).unwrap();
+        home
+    }
+
+    fn targets(editors: &[DetectedEditor]) -> Vec<EditorTarget> {
+        editors.iter().map(|e| e.target).collect()
+    }
+
+    #[test]
+    fn test_detects_nothing_in_empty_home() {
+        let home = fake_home("empty");
+        assert!(detect_editors_in(&home, None, || false).is_empty());
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_detects_windsurf_continue_and_cline() {
+        let home = fake_home("vscode");
+        std::fs::create_dir_all(home.join(".codeium/windsurf")).unwrap();
+        std::fs::create_dir_all(home.join(".continue")).unwrap();
+        let cline_dir = vscode_user_dir(&home)
+            .unwrap()
+            .join("globalStorage")
+            .join(CLINE_EXTENSION_ID);
+        std::fs::create_dir_all(&cline_dir).unwrap();
+
+        let editors = detect_editors_in(&home, None, || false);
+        assert_eq!(
+            targets(&editors),
+            vec![
+                EditorTarget::Windsurf,
+                EditorTarget::Continue,
+                EditorTarget::Cline
+            ]
+        );
+        assert_eq!(
+            editors[0].config_path.as_deref(),
+            Some(home.join(".codeium/windsurf/mcp_config.json").as_path())
+        );
+        assert_eq!(
+            editors[1].config_path.as_deref(),
+            Some(home.join(".continue/mcpServers/scix.json").as_path())
+        );
+        assert_eq!(
+            editors[2].config_path.as_deref(),
+            Some(cline_dir.join("settings/cline_mcp_settings.json").as_path())
+        );
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_filter_limits_detection() {
+        let home = fake_home("filter");
+        std::fs::create_dir_all(home.join(".cursor")).unwrap();
+        std::fs::create_dir_all(home.join(".continue")).unwrap();
+
+        let editors = detect_editors_in(&home, Some(EditorTarget::Continue), || true);
+        assert_eq!(targets(&editors), vec![EditorTarget::Continue]);
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_claude_code_prefers_cli() {
+        let home = fake_home("claude");
+        let editors = detect_editors_in(&home, Some(EditorTarget::ClaudeCode), || true);
+        assert_eq!(targets(&editors), vec![EditorTarget::ClaudeCode]);
+        assert!(editors[0].use_cli);
+        assert!(editors[0].config_path.is_none());
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_configure_writes_mcp_servers_entry() {
+        let home = fake_home("write");
+        let editor = DetectedEditor {
+            target: EditorTarget::Cline,
+            config_path: Some(home.join("settings/cline_mcp_settings.json")),
+            use_cli: false,
+        };
+        let result = configure_editor(&editor, "/usr/bin/scix", "token", true);
+        assert_eq!(result, ConfigResult::Configured);
+
+        let written = std::fs::read_to_string(editor.config_path.unwrap()).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&written).unwrap();
+        let entry = &config["mcpServers"]["scix"];
+        assert_eq!(entry["command"], "/usr/bin/scix");
+        assert_eq!(entry["args"], serde_json::json!(["serve"]));
+        assert_eq!(entry["env"]["SCIX_API_TOKEN"], "token");
+        assert_eq!(entry["disabled"], false);
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_remove_strips_scix_entry() {
+        let home = fake_home("remove");
+        let path = home.join("mcp.json");
+        let config = serde_json::json!({
+            "theme": "dark",
+            "mcpServers": {
+                "scix": {"command": "/usr/bin/scix", "args": ["serve"]},
+                "other": {"command": "other-server"}
+            }
+        });
+        std::fs::write(&path, config.to_string()).unwrap();
+
+        assert_eq!(
+            remove_json_config(&path, "mcpServers"),
+            Ok(ConfigResult::Removed)
+        );
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(written["mcpServers"].get("scix").is_none());
+        assert_eq!(written["mcpServers"]["other"]["command"], "other-server");
+        assert_eq!(written["theme"], "dark");
+
+        // A second removal finds nothing to do.
+        assert_eq!(
+            remove_json_config(&path, "mcpServers"),
+            Ok(ConfigResult::NotConfigured)
+        );
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn test_remove_without_config_file() {
+        let home = fake_home("remove-missing");
+        assert_eq!(
+            remove_json_config(&home.join("settings.json"), "context_servers"),
+            Ok(ConfigResult::NotConfigured)
+        );
+        let _ = std::fs::remove_dir_all(&home);
+    }
+}
@@ -7,6 +7,7 @@
 //! via PyO3 with automatic field access (`get_all`).
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// A paper (document) from ADS search results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,10 +23,23 @@ pub struct Paper {
     pub year: Option<u16>,
     /// Journal/publication name.
     pub publication: Option<String>,
+    /// Journal volume.
+    #[serde(default)]
+    pub volume: Option<String>,
+    /// First page or article number (e.g. `L13`).
+    #[serde(default)]
+    pub page: Option<String>,
     /// Abstract text.
     pub abstract_text: Option<String>,
-    /// DOI (first, if multiple).
+    /// Language ADS records for the text (its `lang` field), e.g. `en`.
+    /// Missing on most records; see [`Paper::looks_english`].
+    #[serde(default)]
+    pub abstract_language: Option<String>,
+    /// Primary DOI (the first, if ADS lists several).
     pub doi: Option<String>,
+    /// Every DOI ADS lists, primary first (errata and dataset DOIs follow).
+    #[serde(default)]
+    pub dois: Vec<String>,
     /// arXiv ID (extracted from identifiers).
     pub arxiv_id: Option<String>,
     /// Raw identifier list from ADS.
@@ -34,16 +48,229 @@ pub struct Paper {
     pub esources: Vec<String>,
     /// Number of citations.
     pub citation_count: Option<u32>,
+    /// Recent reads (ADS counts the last 90 days), rounded to a whole number.
+    #[serde(default)]
+    pub read_count: Option<u32>,
     /// Document type (article, inproceedings, etc.).
     pub doctype: Option<String>,
     /// Property flags (OPENACCESS, REFEREED, etc.).
     pub properties: Vec<String>,
+    /// Author-supplied and journal keywords.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Affiliations in author order, one entry per author; ADS uses `-`
+    /// for authors without one. Only filled when `aff` is requested.
+    #[serde(default)]
+    pub affiliations: Vec<String>,
     /// Constructed PDF links, ordered by priority.
     pub pdf_links: Vec<PdfLink>,
     /// ADS URL for this paper.
     pub url: String,
 }
 
+#[cfg(any(test, feature = "testutil"))]
+impl Paper {
+    /// A minimal valid paper for test fixtures.
+    ///
+    /// Only the bibcode, a placeholder title, and the ADS URL are set; use
+    /// the `with_*` methods or assign fields directly to fill in the rest.
+    pub fn test_stub(bibcode: &str) -> Self {
+        Paper {
+            bibcode: bibcode.to_string(),
+            title: "Untitled".to_string(),
+            authors: Vec::new(),
+            year: None,
+            publication: None,
+            volume: None,
+            page: None,
+            abstract_text: None,
+            abstract_language: None,
+            doi: None,
+            dois: Vec::new(),
+            arxiv_id: None,
+            identifiers: Vec::new(),
+            esources: Vec::new(),
+            citation_count: None,
+            read_count: None,
+            doctype: None,
+            properties: Vec::new(),
+            keywords: Vec::new(),
+            affiliations: Vec::new(),
+            pdf_links: Vec::new(),
+            url: format!("https://scixplorer.org/abs/{}", bibcode),
+        }
+    }
+
+    /// Set the title.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Set the authors from ADS-format names ("Last, First M.").
+    pub fn with_authors(mut self, names: &[&str]) -> Self {
+        self.authors = names.iter().map(|n| Author::from_ads_format(n)).collect();
+        self
+    }
+
+    /// Set the publication year.
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Set the citation count.
+    pub fn with_citation_count(mut self, count: u32) -> Self {
+        self.citation_count = Some(count);
+        self
+    }
+}
+
+impl Paper {
+    /// Author family names in citation style: `Riess`, `Abbott & Riess`,
+    /// or `Abbott et al.` for three or more. Empty if there are no authors.
+    pub fn short_authors(&self) -> String {
+        match self.authors.as_slice() {
+            [] => String::new(),
+            [only] => only.family_name.clone(),
+            [first, second] => format!("{} & {}", first.family_name, second.family_name),
+            [first, ..] => format!("{} et al.", first.family_name),
+        }
+    }
+
+    /// arXiv subject categories among the keywords, as codes such as
+    /// `astro-ph.GA`, in keyword order without repeats.
+    ///
+    /// ADS mixes these in with journal (UAT, PACS) and author keywords,
+    /// sometimes spelled out (`Astrophysics - Astrophysics of Galaxies`);
+    /// see [`crate::parse::arxiv_category`] for what is recognized.
+    pub fn arxiv_categories(&self) -> Vec<String> {
+        let mut categories = Vec::new();
+        for category in self
+            .keywords
+            .iter()
+            .filter_map(|k| crate::parse::arxiv_category(k))
+        {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        categories
+    }
+
+    /// Whether the abstract appears to be in English.
+    ///
+    /// Uses [`abstract_language`](Self::abstract_language) when ADS set it.
+    /// Otherwise this is a heuristic: at least 15% of the abstract's words
+    /// must be common English function words ("the", "of", "and", ...).
+    /// It has no notion of other languages, so it can misjudge abstracts
+    /// that are mostly formulae or mix languages. Returns `None` when there
+    /// is no language and fewer than 20 words of abstract to go on.
+    pub fn looks_english(&self) -> Option<bool> {
+        if let Some(lang) = &self.abstract_language {
+            let lang = lang.to_lowercase();
+            return Some(lang.starts_with("en"));
+        }
+        const FUNCTION_WORDS: &[&str] = &[
+            "the", "of", "and", "to", "in", "we", "is", "for", "that", "with", "on", "by", "are",
+            "this", "from", "which", "these", "be", "at", "our",
+        ];
+        let text = self.abstract_text.as_deref()?.to_lowercase();
+        let words: Vec<&str> = text
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+        if words.len() < 20 {
+            return None;
+        }
+        let common = words.iter().filter(|w| FUNCTION_WORDS.contains(w)).count();
+        Some(common * 100 >= words.len() * 15)
+    }
+
+    /// Keywords that are not arXiv categories: UAT, PACS, and free text.
+    pub fn subject_keywords(&self) -> Vec<&str> {
+        self.keywords
+            .iter()
+            .filter(|k| crate::parse::arxiv_category(k).is_none())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// A full reference line: up to three authors, year, title,
+    /// publication with volume and page, and the DOI (or bibcode), e.g.
+    /// `Riess, A. G.; Filippenko, A. V. (1998). Observational Evidence from
+    /// Supernovae. The Astronomical Journal, 116, 1009. 1998AJ....116.1009R`.
+    pub fn citation_line(&self) -> String {
+        let mut authors: Vec<&str> = self
+            .authors
+            .iter()
+            .take(3)
+            .map(|a| a.name.as_str())
+            .collect();
+        if self.authors.len() > 3 {
+            authors.truncate(1);
+            authors.push("et al.");
+        }
+
+        let mut sentences = Vec::new();
+        let mut head = authors.join("; ");
+        if let Some(year) = self.year {
+            if !head.is_empty() {
+                head.push(' ');
+            }
+            head.push_str(&format!("({})", year));
+        }
+        if !head.is_empty() {
+            sentences.push(head);
+        }
+        if !self.title.is_empty() {
+            sentences.push(self.title.trim_end_matches('.').to_string());
+        }
+        if let Some(publication) = &self.publication {
+            let source: Vec<&str> = [Some(publication), self.volume.as_ref(), self.page.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            sentences.push(source.join(", "));
+        }
+        sentences.push(match &self.doi {
+            Some(doi) => format!("doi:{}", doi),
+            None => self.bibcode.clone(),
+        });
+
+        let mut line = String::new();
+        for sentence in sentences {
+            if !line.is_empty() {
+                line.push_str(if line.ends_with('.') { " " } else { ". " });
+            }
+            line.push_str(&sentence);
+        }
+        line
+    }
+}
+
+/// One-line citation: `Abbott et al. (2016), Physical Review Letters,
+/// 2016PhRvL.116f1102A`. Missing authors, year, or publication are left out.
+impl std::fmt::Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut head = self.short_authors();
+        if let Some(year) = self.year {
+            if !head.is_empty() {
+                head.push(' ');
+            }
+            head.push_str(&format!("({})", year));
+        }
+        let parts: Vec<&str> = [Some(head.as_str()), self.publication.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .chain([self.bibcode.as_str()])
+            .collect();
+        f.write_str(&parts.join(", "))
+    }
+}
+
 /// An author of a paper.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -54,6 +281,9 @@ pub struct Author {
     pub family_name: String,
     /// Given (first) name and initials.
     pub given_name: Option<String>,
+    /// This author's affiliation, when `aff` was requested and ADS has one.
+    #[serde(default)]
+    pub affiliation: Option<String>,
 }
 
 impl Author {
@@ -65,6 +295,7 @@ impl Author {
                 name: name.to_string(),
                 family_name: parts[0].trim().to_string(),
                 given_name: Some(parts[1].trim().to_string()),
+                affiliation: None,
             }
         } else {
             let words: Vec<&str> = name.split_whitespace().collect();
@@ -73,12 +304,14 @@ impl Author {
                     name: name.to_string(),
                     family_name: words.last().unwrap().to_string(),
                     given_name: Some(words[..words.len() - 1].join(" ")),
+                    affiliation: None,
                 }
             } else {
                 Author {
                     name: name.to_string(),
                     family_name: name.to_string(),
                     given_name: None,
+                    affiliation: None,
                 }
             }
         }
@@ -204,6 +437,186 @@ pub struct SearchResponse {
     pub papers: Vec<Paper>,
     /// Total number of results (may be larger than `papers.len()`).
     pub num_found: u64,
+    /// Solr's `responseHeader`, when ADS includes one.
+    #[serde(default)]
+    pub meta: Option<ResponseMeta>,
+}
+
+impl SearchResponse {
+    /// Combine two result sets, dropping papers that appear in both.
+    ///
+    /// Papers keep their order, `self` first. `meta` is kept from `self`.
+    /// `num_found` becomes the sum of both totals minus the duplicates
+    /// found among the returned papers, so it is only an approximation:
+    /// overlap between results beyond the fetched pages can't be seen.
+    pub fn merge(mut self, other: SearchResponse) -> SearchResponse {
+        self.num_found += other.num_found;
+        self.papers.extend(other.papers);
+        self.dedup_by_bibcode();
+        self
+    }
+
+    /// Remove papers with a repeated bibcode, keeping the richest record.
+    ///
+    /// Each bibcode stays at its first position; if a later duplicate has
+    /// more populated fields (say, one query requested the abstract and
+    /// another didn't), its record takes that place. `num_found` is reduced
+    /// by the number of papers removed.
+    pub fn dedup_by_bibcode(&mut self) {
+        let before = self.papers.len();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut kept: Vec<Paper> = Vec::with_capacity(before);
+        for paper in self.papers.drain(..) {
+            match index.get(&paper.bibcode) {
+                Some(&i) => {
+                    if populated_fields(&paper) > populated_fields(&kept[i]) {
+                        kept[i] = paper;
+                    }
+                }
+                None => {
+                    index.insert(paper.bibcode.clone(), kept.len());
+                    kept.push(paper);
+                }
+            }
+        }
+        self.num_found = self.num_found.saturating_sub((before - kept.len()) as u64);
+        self.papers = kept;
+    }
+
+    /// Rank collaborators by the number of papers they share.
+    ///
+    /// Tallies author family names across `papers`, counting each name at
+    /// most once per paper, and drops the family name of `exclude` (either
+    /// "Last, First" or just "Last", compared case-insensitively). Ties are
+    /// broken alphabetically. Family names merge different people with the
+    /// same surname, so treat this as a quick overview rather than an
+    /// authoritative collaborator list.
+    pub fn coauthors(&self, exclude: &str) -> Vec<(String, usize)> {
+        let excluded = Author::from_ads_format(exclude).family_name.to_lowercase();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for paper in &self.papers {
+            let mut seen = std::collections::HashSet::new();
+            for author in &paper.authors {
+                let family = author.family_name.trim();
+                if family.is_empty() || family.to_lowercase() == excluded {
+                    continue;
+                }
+                if seen.insert(family) {
+                    *counts.entry(family.to_string()).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+}
+
+/// A paper's references and citations, fetched together by
+/// [`SciXClient::neighborhood`](crate::SciXClient::neighborhood).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct Neighborhood {
+    /// The paper at the center.
+    pub bibcode: String,
+    /// Papers it cites, without duplicates.
+    pub references: Vec<Paper>,
+    /// Papers citing it, without duplicates.
+    pub citations: Vec<Paper>,
+    /// Total references ADS knows of (may exceed `references.len()`).
+    pub num_references: u64,
+    /// Total citations ADS knows of (may exceed `citations.len()`).
+    pub num_citations: u64,
+}
+
+/// Everything about one paper, fetched together by
+/// [`SciXClient::dossier`](crate::SciXClient::dossier).
+///
+/// Each part is `None` if its request failed, with the reason in `errors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct Dossier {
+    /// The paper the dossier is about.
+    pub bibcode: String,
+    /// Full metadata, as from `get_paper`.
+    pub paper: Option<Paper>,
+    /// Citation metrics for the paper alone.
+    pub metrics: Option<Metrics>,
+    /// Papers it cites.
+    pub references: Option<SearchResponse>,
+    /// Papers citing it.
+    pub citations: Option<SearchResponse>,
+    /// Full-text, data, and other resolver links.
+    pub links: Option<Vec<ResolvedLink>>,
+    /// Error message for each part that failed, keyed by part name
+    /// (`"paper"`, `"metrics"`, `"references"`, `"citations"`, `"links"`).
+    pub errors: BTreeMap<String, String>,
+}
+
+impl Neighborhood {
+    /// Every neighboring paper once, references first.
+    ///
+    /// A paper both cited by and citing the center (common for companion
+    /// papers) appears in both lists but only once here.
+    pub fn papers(&self) -> Vec<&Paper> {
+        let mut seen = std::collections::HashSet::new();
+        self.references
+            .iter()
+            .chain(&self.citations)
+            .filter(|p| seen.insert(p.bibcode.as_str()))
+            .collect()
+    }
+}
+
+/// Number of optional fields set on a paper, to pick between duplicates.
+fn populated_fields(paper: &Paper) -> usize {
+    [
+        !paper.title.is_empty(),
+        !paper.authors.is_empty(),
+        paper.year.is_some(),
+        paper.publication.is_some(),
+        paper.volume.is_some(),
+        paper.page.is_some(),
+        paper.abstract_text.is_some(),
+        paper.abstract_language.is_some(),
+        paper.doi.is_some(),
+        paper.arxiv_id.is_some(),
+        !paper.identifiers.is_empty(),
+        !paper.esources.is_empty(),
+        paper.citation_count.is_some(),
+        paper.read_count.is_some(),
+        paper.doctype.is_some(),
+        !paper.properties.is_empty(),
+        !paper.keywords.is_empty(),
+        !paper.affiliations.is_empty(),
+        !paper.pdf_links.is_empty(),
+    ]
+    .iter()
+    .filter(|&&set| set)
+    .count()
+}
+
+/// Solr `responseHeader` metadata: timing and the parameters ADS ran.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct ResponseMeta {
+    /// Solr status code (0 on success).
+    pub status: Option<i32>,
+    /// Server-side query time in milliseconds (`QTime`).
+    pub qtime_ms: Option<u64>,
+    /// Request parameters as echoed by Solr; repeated parameters such as
+    /// `fq` keep every value.
+    pub params: HashMap<String, Vec<String>>,
+}
+
+impl ResponseMeta {
+    /// The query string Solr received (`params.q`).
+    pub fn query(&self) -> Option<&str> {
+        self.params
+            .get("q")
+            .and_then(|q| q.first())
+            .map(String::as_str)
+    }
 }
 
 /// Citation export formats supported by ADS.
@@ -253,6 +666,19 @@ impl ExportFormat {
         }
     }
 
+    /// Conventional file extension for this format, without the dot.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::BibTeX | Self::BibTeXAbs => "bib",
+            Self::AasTex | Self::Icarus | Self::Mnras | Self::Soph => "tex",
+            Self::Ris => "ris",
+            Self::Endnote => "enw",
+            Self::Csl => "json",
+            Self::DcXml | Self::RefXml | Self::RefAbsXml | Self::VoTable | Self::Rss => "xml",
+            Self::Medlars | Self::Ieee | Self::Custom => "txt",
+        }
+    }
+
     /// Parse from string (case-insensitive).
     pub fn from_str_loose(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
@@ -284,6 +710,22 @@ impl std::fmt::Display for ExportFormat {
     }
 }
 
+/// Citation key scheme for BibTeX exports.
+///
+/// ADS keys entries by bibcode; the other styles rewrite the keys locally
+/// after export. Colliding keys get `a`, `b`, ... suffixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum KeyStyle {
+    /// Keep the keys ADS generates (the bibcode).
+    AdsDefault,
+    /// First author's family name followed by the year, e.g. `Einstein1905`.
+    FirstAuthorYear,
+    /// Like `FirstAuthorYear` plus the first significant title word,
+    /// e.g. `Einstein1905Electrodynamics`.
+    FirstAuthorYearTitleWord,
+}
+
 /// Citation metrics for a set of papers.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -294,6 +736,83 @@ pub struct Metrics {
     pub citation_stats: Option<CitationStats>,
     /// Bibliometric indicators.
     pub indicators: Option<Indicators>,
+    /// Indicators year by year, oldest first. Only requested by
+    /// [`SciXClient::metrics_with_types`](crate::SciXClient::metrics_with_types)
+    /// with [`MetricType::TimeSeries`].
+    #[serde(
+        default,
+        alias = "time series",
+        deserialize_with = "crate::metrics::deserialize_time_series"
+    )]
+    pub time_series: Option<Vec<TimeSeriesPoint>>,
+    /// Yearly counts by histogram (`"citations"`, `"reads"`,
+    /// `"publications"`, ...), then series (`"refereed to refereed"`, ...),
+    /// then year; see [`Histograms`]. Only requested with [`MetricType::Histograms`]; entries
+    /// that aren't numbers are skipped.
+    #[serde(default, deserialize_with = "crate::metrics::deserialize_histograms")]
+    pub histograms: Option<Histograms>,
+}
+
+/// Metrics histograms: histogram name → series name → year → value.
+pub type Histograms = BTreeMap<String, BTreeMap<String, BTreeMap<String, f64>>>;
+
+impl Metrics {
+    /// Citations excluding self-citations, for all and for refereed papers.
+    ///
+    /// Each count is `None` when the response lacks citation statistics or
+    /// self-citation numbers.
+    pub fn external_citation_summary(&self) -> ExternalCitationSummary {
+        let stats = self.citation_stats.as_ref();
+        let external = |entry: Option<&CitationStatsEntry>| {
+            entry.and_then(CitationStatsEntry::external_citations)
+        };
+        ExternalCitationSummary {
+            total: external(stats.and_then(|s| s.total.as_ref())),
+            refereed: external(stats.and_then(|s| s.refereed.as_ref())),
+        }
+    }
+}
+
+/// A group of statistics the metrics endpoint can compute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum MetricType {
+    /// Paper counts and citation totals ([`Metrics::basic_stats`]).
+    Basic,
+    /// Citation statistics ([`Metrics::citation_stats`]).
+    Citations,
+    /// h, g, i10, and the other indicators ([`Metrics::indicators`]).
+    Indicators,
+    /// Yearly publication, read, and citation counts ([`Metrics::histograms`]).
+    Histograms,
+    /// The indicators for each year ([`Metrics::time_series`]).
+    TimeSeries,
+}
+
+impl MetricType {
+    /// Name used in the `types` list of a metrics request.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Citations => "citations",
+            Self::Indicators => "indicators",
+            Self::Histograms => "histograms",
+            Self::TimeSeries => "timeseries",
+        }
+    }
+}
+
+/// Bibliometric indicators as of the end of one year.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct TimeSeriesPoint {
+    pub year: u16,
+    pub h: Option<u32>,
+    pub g: Option<u32>,
+    pub i10: Option<u32>,
+    pub i100: Option<u32>,
+    pub read10: Option<f64>,
+    pub tori: Option<f64>,
 }
 
 /// Basic paper statistics.
@@ -339,6 +858,29 @@ pub struct CitationStatsEntry {
     pub normalized_citations: Option<f64>,
 }
 
+impl CitationStatsEntry {
+    /// Citations excluding self-citations.
+    ///
+    /// `None` when either count is missing. Self-citations are already part
+    /// of `total_citations`, so this never goes below zero.
+    pub fn external_citations(&self) -> Option<u32> {
+        Some(
+            self.total_citations?
+                .saturating_sub(self.number_of_self_citations?),
+        )
+    }
+}
+
+/// Citations excluding self-citations, from [`Metrics::external_citation_summary`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct ExternalCitationSummary {
+    /// External citations to all papers.
+    pub total: Option<u32>,
+    /// External citations to refereed papers.
+    pub refereed: Option<u32>,
+}
+
 /// Bibliometric indicators (h-index, g-index, etc.).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -375,6 +917,113 @@ pub struct LibraryDetail {
     pub documents: Vec<String>,
 }
 
+/// Changes made by [`SciXClient::sync_library`](crate::SciXClient::sync_library).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct SyncReport {
+    /// Bibcodes added to the library.
+    pub added: Vec<String>,
+    /// Bibcodes removed from the library.
+    pub removed: Vec<String>,
+}
+
+/// Export output along with which requested bibcodes made it into it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct ExportResult {
+    /// The exported text, as returned by ADS.
+    pub body: String,
+    /// Requested bibcodes found in the output.
+    pub succeeded: Vec<String>,
+    /// Requested bibcodes missing from the output (retracted, merged, or unknown).
+    pub failed: Vec<String>,
+}
+
+/// A single BibTeX entry, as read back by
+/// [`parse_bibtex`](crate::parse::parse_bibtex).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct BibEntry {
+    /// Lowercased entry type, e.g. `article`.
+    pub entry_type: String,
+    /// The citation key.
+    pub key: String,
+    /// Field values keyed by lowercased field name, outer delimiters removed.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// ADS API quota as reported by the most recent response headers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct RateLimitStatus {
+    /// Requests left in the current window (`X-RateLimit-Remaining`).
+    pub remaining: Option<u32>,
+    /// Total requests allowed per window (`X-RateLimit-Limit`).
+    pub limit: Option<u32>,
+    /// Unix timestamp at which the quota resets (`X-RateLimit-Reset`).
+    pub reset_at: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Requests spent in the current window (`limit - remaining`), or `None`
+    /// when either header is missing.
+    pub fn used(&self) -> Option<u32> {
+        Some(self.limit?.saturating_sub(self.remaining?))
+    }
+}
+
+/// Summary statistics of a numeric field over a query's matching documents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct FieldStats {
+    /// Smallest value, or `None` when no document has the field.
+    pub min: Option<f64>,
+    /// Largest value, or `None` when no document has the field.
+    pub max: Option<f64>,
+    /// Mean value, or `None` when no document has the field.
+    pub mean: Option<f64>,
+    /// Sum of all values.
+    pub sum: f64,
+    /// Number of documents that have the field.
+    pub count: u64,
+}
+
+/// A network graph from the ADS visualization endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct NetworkGraph {
+    pub nodes: Vec<NetworkNode>,
+    pub links: Vec<NetworkLink>,
+}
+
+/// A node in a [`NetworkGraph`]: an author, or a cluster of papers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct NetworkNode {
+    /// Position in [`NetworkGraph::nodes`]; links refer to nodes by this index.
+    pub id: usize,
+    /// Author name, or the cluster identifier for paper networks.
+    pub name: String,
+    /// Name of the group (cluster) the node belongs to, if any.
+    pub group: Option<String>,
+    /// Node weight (author paper count or cluster size).
+    pub weight: Option<f64>,
+    /// Bibcodes associated with the node.
+    pub papers: Vec<String>,
+}
+
+/// An edge between two nodes in a [`NetworkGraph`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct NetworkLink {
+    /// Index of the source node.
+    pub source: usize,
+    /// Index of the target node.
+    pub target: usize,
+    /// Link strength (shared papers or shared references).
+    pub weight: f64,
+}
+
 /// Result of astronomical object resolution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -383,6 +1032,16 @@ pub struct ObjectResult {
     pub bibcodes: Vec<String>,
 }
 
+/// A link returned by the ADS link resolver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct ResolvedLink {
+    pub url: String,
+    pub title: Option<String>,
+    /// Resolver link type, e.g. `ESOURCE|PUB_PDF` or `DATA|SIMBAD`.
+    pub link_type: Option<String>,
+}
+
 /// Result of free-text reference resolution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -392,6 +1051,49 @@ pub struct ResolvedReference {
     pub score: Option<String>,
 }
 
+/// Named `fl` field lists for searches.
+///
+/// Anywhere a search takes `fields: impl AsRef<str>`, a preset and a raw
+/// comma-separated string are interchangeable. Every preset includes
+/// `bibcode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldSet {
+    /// `bibcode,title,year`.
+    Minimal,
+    /// [`DEFAULT_SEARCH_FIELDS`](crate::parse::DEFAULT_SEARCH_FIELDS), what
+    /// [`SciXClient::search`](crate::SciXClient::search) requests.
+    Default,
+    /// [`RICH_FIELDS`](crate::parse::RICH_FIELDS), adding reads, volume,
+    /// page, keywords, and affiliations for detail views.
+    Rich,
+    /// `bibcode,citation_count,read_count`.
+    Metrics,
+}
+
+impl FieldSet {
+    /// The comma-separated `fl` value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Minimal => crate::parse::MINIMAL_FIELDS,
+            Self::Default => crate::parse::DEFAULT_SEARCH_FIELDS,
+            Self::Rich => crate::parse::RICH_FIELDS,
+            Self::Metrics => crate::parse::METRICS_FIELDS,
+        }
+    }
+}
+
+impl AsRef<str> for FieldSet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FieldSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Sort specification for search queries.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
@@ -408,7 +1110,76 @@ pub enum SortDirection {
     Desc,
 }
 
+/// ADS fields that search results can be sorted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum SortField {
+    Date,
+    EntryDate,
+    CitationCount,
+    CitationCountNorm,
+    ClassicFactor,
+    ReadCount,
+    FirstAuthor,
+    Bibcode,
+    Score,
+}
+
+impl SortField {
+    /// Every sortable field, in declaration order.
+    pub const ALL: [SortField; 9] = [
+        Self::Date,
+        Self::EntryDate,
+        Self::CitationCount,
+        Self::CitationCountNorm,
+        Self::ClassicFactor,
+        Self::ReadCount,
+        Self::FirstAuthor,
+        Self::Bibcode,
+        Self::Score,
+    ];
+
+    /// Parse from string (case-insensitive), accepting common aliases such
+    /// as `citations`, `reads`, `year`, and `relevance`. Hyphens and spaces
+    /// count as underscores.
+    pub fn from_str_loose(s: &str) -> Option<Self> {
+        let normalized = s.trim().to_lowercase().replace(['-', ' '], "_");
+        match normalized.as_str() {
+            "date" | "pubdate" | "year" => Some(Self::Date),
+            "entry_date" | "entrydate" | "entry" | "added" => Some(Self::EntryDate),
+            "citation_count" | "citations" | "citation" | "cited" | "citation_counts"
+            | "citationcount" => Some(Self::CitationCount),
+            "citation_count_norm" | "citations_norm" | "normalized_citations" => {
+                Some(Self::CitationCountNorm)
+            }
+            "classic_factor" | "classic" => Some(Self::ClassicFactor),
+            "read_count" | "reads" | "read" | "readcount" => Some(Self::ReadCount),
+            "first_author" | "firstauthor" | "author" => Some(Self::FirstAuthor),
+            "bibcode" => Some(Self::Bibcode),
+            "score" | "relevance" => Some(Self::Score),
+            _ => None,
+        }
+    }
+
+    /// ADS field name as used in the `sort` parameter.
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            Self::Date => "date",
+            Self::EntryDate => "entry_date",
+            Self::CitationCount => "citation_count",
+            Self::CitationCountNorm => "citation_count_norm",
+            Self::ClassicFactor => "classic_factor",
+            Self::ReadCount => "read_count",
+            Self::FirstAuthor => "first_author",
+            Self::Bibcode => "bibcode",
+            Self::Score => "score",
+        }
+    }
+}
+
 impl Sort {
+    /// Sort on an arbitrary field name. Prefer [`Sort::by`] for the fields
+    /// in [`SortField`]; ADS silently ignores misspelled fields.
     pub fn new(field: impl Into<String>, direction: SortDirection) -> Self {
         Self {
             field: field.into(),
@@ -416,6 +1187,11 @@ impl Sort {
         }
     }
 
+    /// Sort on a known field.
+    pub fn by(field: SortField, direction: SortDirection) -> Self {
+        Self::new(field.as_api_str(), direction)
+    }
+
     pub fn date_desc() -> Self {
         Self::new("date", SortDirection::Desc)
     }
@@ -427,6 +1203,41 @@ impl Sort {
     pub fn score_desc() -> Self {
         Self::new("score", SortDirection::Desc)
     }
+
+    /// Best matches first (`score desc`). Searches otherwise default to
+    /// `date desc`, which suits browsing but buries keyword matches.
+    pub fn relevance() -> Self {
+        Self::by(SortField::Score, SortDirection::Desc)
+    }
+
+    /// Map a plain-language sort request such as `"most cited"`, `"newest"`,
+    /// or `"oldest first"` to a `Sort`.
+    ///
+    /// Case, extra whitespace, and a trailing `first` are ignored. Returns
+    /// `None` for anything else, including `"field [asc|desc]"` strings,
+    /// which [`str::parse`] handles.
+    pub fn from_human(s: &str) -> Option<Self> {
+        let lower = s.to_lowercase();
+        let words: Vec<&str> = lower.split_whitespace().collect();
+        let phrase = match words.split_last() {
+            Some((&"first", rest)) if !rest.is_empty() => rest.join(" "),
+            _ => words.join(" "),
+        };
+        let (field, direction) = match phrase.as_str() {
+            "most cited" | "highly cited" | "most citations" => {
+                (SortField::CitationCount, SortDirection::Desc)
+            }
+            "least cited" | "fewest citations" => (SortField::CitationCount, SortDirection::Asc),
+            "newest" | "recent" | "most recent" | "latest" => {
+                (SortField::Date, SortDirection::Desc)
+            }
+            "oldest" | "earliest" => (SortField::Date, SortDirection::Asc),
+            "most read" | "most popular" | "popular" => (SortField::ReadCount, SortDirection::Desc),
+            "best match" | "relevance" | "most relevant" => (SortField::Score, SortDirection::Desc),
+            _ => return None,
+        };
+        Some(Self::by(field, direction))
+    }
 }
 
 impl std::fmt::Display for Sort {
@@ -438,3 +1249,407 @@ impl std::fmt::Display for Sort {
         write!(f, "{} {}", self.field, dir)
     }
 }
+
+impl std::str::FromStr for Sort {
+    type Err = crate::error::SciXError;
+
+    /// Parse `"field [asc|desc]"`, e.g. `"citation_count desc"`.
+    ///
+    /// The field is checked with [`SortField::from_str_loose`], so aliases
+    /// like `citations` map to `citation_count` and unknown fields are an
+    /// error rather than a sort ADS silently ignores. Prefix the field with
+    /// `raw:` (`"raw:property asc"`) to send it unchecked. The direction
+    /// defaults to descending when omitted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            crate::error::SciXError::InvalidQuery(format!(
+                "Invalid sort '{}': expected \"field [asc|desc]\"",
+                s
+            ))
+        };
+        let mut parts = s.split_whitespace();
+        let field = parts.next().ok_or_else(invalid)?;
+        let direction = match parts.next().map(str::to_lowercase).as_deref() {
+            None | Some("desc") => SortDirection::Desc,
+            Some("asc") => SortDirection::Asc,
+            Some(_) => return Err(invalid()),
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        if let Some(raw) = field.strip_prefix("raw:") {
+            return Ok(Self::new(raw, direction));
+        }
+        let field = SortField::from_str_loose(field).ok_or_else(|| {
+            let known: Vec<&str> = SortField::ALL.iter().map(SortField::as_api_str).collect();
+            crate::error::SciXError::InvalidQuery(format!(
+                "Unknown sort field '{}': expected one of {} (or raw:<field> to send it as-is)",
+                field,
+                known.join(", ")
+            ))
+        })?;
+        Ok(Self::by(field, direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_response_merge_dedups_by_bibcode() {
+        let first = SearchResponse {
+            papers: vec![
+                Paper::test_stub("2016PhRvL.116f1102A").with_title("GW150914"),
+                Paper::test_stub("1998AJ....116.1009R").with_title("Supernovae"),
+            ],
+            num_found: 10,
+            meta: None,
+        };
+        let mut richer = Paper::test_stub("2016PhRvL.116f1102A")
+            .with_title("GW150914")
+            .with_year(2016)
+            .with_citation_count(5000);
+        richer.abstract_text = Some("On September 14, 2015...".to_string());
+        let second = SearchResponse {
+            papers: vec![
+                richer,
+                Paper::test_stub("1999ApJ...517..565P").with_title("Omega"),
+            ],
+            num_found: 5,
+            meta: None,
+        };
+
+        let merged = first.merge(second);
+        let bibcodes: Vec<&str> = merged.papers.iter().map(|p| p.bibcode.as_str()).collect();
+        assert_eq!(
+            bibcodes,
+            [
+                "2016PhRvL.116f1102A",
+                "1998AJ....116.1009R",
+                "1999ApJ...517..565P"
+            ]
+        );
+        assert_eq!(merged.papers[0].citation_count, Some(5000));
+        assert_eq!(merged.num_found, 14);
+    }
+
+    #[test]
+    fn test_dedup_by_bibcode_keeps_first_when_equally_rich() {
+        let mut response = SearchResponse {
+            papers: vec![
+                Paper::test_stub("A").with_title("first"),
+                Paper::test_stub("A").with_title("second"),
+                Paper::test_stub("B").with_title("other"),
+            ],
+            num_found: 3,
+            meta: None,
+        };
+        response.dedup_by_bibcode();
+        assert_eq!(response.papers.len(), 2);
+        assert_eq!(response.papers[0].title, "first");
+        assert_eq!(response.num_found, 2);
+    }
+
+    #[test]
+    fn test_search_response_coauthors() {
+        let response = SearchResponse {
+            papers: vec![
+                Paper::test_stub("A").with_authors(&["Smith, J.", "Jones, A.", "Lee, K."]),
+                Paper::test_stub("B").with_authors(&["Jones, A.", "Smith, J.", "Jones, B."]),
+                Paper::test_stub("C").with_authors(&["Lee, K.", "Brown, T.", "smith, j."]),
+            ],
+            num_found: 3,
+            meta: None,
+        };
+
+        assert_eq!(
+            response.coauthors("Smith, J."),
+            vec![
+                ("Jones".to_string(), 2),
+                ("Lee".to_string(), 2),
+                ("Brown".to_string(), 1),
+            ]
+        );
+        assert_eq!(response.coauthors("Smith").len(), 3);
+    }
+
+    #[test]
+    fn test_paper_test_stub() {
+        let paper = Paper::test_stub("2016PhRvL.116f1102A")
+            .with_title("Observation of Gravitational Waves")
+            .with_authors(&["Abbott, B. P.", "Abbott, R."])
+            .with_year(2016)
+            .with_citation_count(5000);
+
+        assert_eq!(paper.bibcode, "2016PhRvL.116f1102A");
+        assert_eq!(paper.title, "Observation of Gravitational Waves");
+        assert_eq!(paper.authors[0].family_name, "Abbott");
+        assert_eq!(paper.year, Some(2016));
+        assert_eq!(paper.citation_count, Some(5000));
+        assert_eq!(paper.url, "https://scixplorer.org/abs/2016PhRvL.116f1102A");
+        assert!(paper.pdf_links.is_empty());
+    }
+
+    #[test]
+    fn test_sort_from_str() {
+        let sort: Sort = "citation_count desc".parse().unwrap();
+        assert_eq!(sort.field, "citation_count");
+        assert_eq!(sort.direction, SortDirection::Desc);
+
+        let sort: Sort = "date asc".parse().unwrap();
+        assert_eq!(sort.field, "date");
+        assert_eq!(sort.direction, SortDirection::Asc);
+
+        let sort: Sort = "date".parse().unwrap();
+        assert_eq!(sort.direction, SortDirection::Desc);
+    }
+
+    #[test]
+    fn test_sort_field_api_names() {
+        let cases = [
+            (SortField::Date, "date"),
+            (SortField::EntryDate, "entry_date"),
+            (SortField::CitationCount, "citation_count"),
+            (SortField::CitationCountNorm, "citation_count_norm"),
+            (SortField::ClassicFactor, "classic_factor"),
+            (SortField::ReadCount, "read_count"),
+            (SortField::FirstAuthor, "first_author"),
+            (SortField::Bibcode, "bibcode"),
+            (SortField::Score, "score"),
+        ];
+        for (field, name) in cases {
+            assert_eq!(field.as_api_str(), name);
+        }
+        assert_eq!(
+            Sort::by(SortField::ReadCount, SortDirection::Asc).to_string(),
+            "read_count asc"
+        );
+    }
+
+    #[test]
+    fn test_sort_from_str_maps_aliases() {
+        let cases = [
+            ("citations desc", "citation_count desc"),
+            ("Citation-Count", "citation_count desc"),
+            ("reads desc", "read_count desc"),
+            ("year asc", "date asc"),
+            ("relevance", "score desc"),
+            ("author asc", "first_author asc"),
+            ("entry-date", "entry_date desc"),
+            ("raw:property asc", "property asc"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                input.parse::<Sort>().unwrap().to_string(),
+                expected,
+                "{}",
+                input
+            );
+        }
+        for field in SortField::ALL {
+            assert_eq!(SortField::from_str_loose(field.as_api_str()), Some(field));
+        }
+    }
+
+    #[test]
+    fn test_paper_display_single_author() {
+        let mut paper = Paper::test_stub("1998AJ....116.1009R")
+            .with_authors(&["Riess, A. G."])
+            .with_year(1998);
+        paper.publication = Some("The Astronomical Journal".to_string());
+        assert_eq!(
+            paper.to_string(),
+            "Riess (1998), The Astronomical Journal, 1998AJ....116.1009R"
+        );
+        assert_eq!(
+            paper.citation_line(),
+            "Riess, A. G. (1998). Untitled. The Astronomical Journal. 1998AJ....116.1009R"
+        );
+    }
+
+    #[test]
+    fn test_paper_display_multiple_authors() {
+        let two = Paper::test_stub("2020X").with_authors(&["Abbott, B. P.", "Riess, A. G."]);
+        assert_eq!(two.short_authors(), "Abbott & Riess");
+
+        let mut many = Paper::test_stub("2016PhRvL.116f1102A")
+            .with_title("Observation of Gravitational Waves")
+            .with_authors(&[
+                "Abbott, B. P.",
+                "Abbott, R.",
+                "Abbott, T. D.",
+                "Acernese, F.",
+            ])
+            .with_year(2016);
+        many.publication = Some("Physical Review Letters".to_string());
+        many.volume = Some("116".to_string());
+        many.page = Some("061102".to_string());
+        many.doi = Some("10.1103/PhysRevLett.116.061102".to_string());
+        assert_eq!(
+            many.to_string(),
+            "Abbott et al. (2016), Physical Review Letters, 2016PhRvL.116f1102A"
+        );
+        assert_eq!(
+            many.citation_line(),
+            "Abbott, B. P.; et al. (2016). Observation of Gravitational Waves. \
+             Physical Review Letters, 116, 061102. doi:10.1103/PhysRevLett.116.061102"
+        );
+    }
+
+    #[test]
+    fn test_paper_display_missing_year() {
+        let paper = Paper::test_stub("2024arXiv240100001S").with_authors(&["Smith, J."]);
+        assert_eq!(paper.to_string(), "Smith, 2024arXiv240100001S");
+        assert_eq!(
+            paper.citation_line(),
+            "Smith, J. Untitled. 2024arXiv240100001S"
+        );
+
+        let anonymous = Paper::test_stub("2024arXiv240100001S");
+        assert_eq!(anonymous.to_string(), "2024arXiv240100001S");
+    }
+
+    #[test]
+    fn test_paper_looks_english() {
+        let mut paper = Paper::test_stub("x");
+        assert_eq!(paper.looks_english(), None);
+
+        paper.abstract_text = Some(
+            "We report the observation of a gravitational-wave signal produced by the \
+             coalescence of two stellar-mass black holes. The signal was observed by the two \
+             detectors of the Laser Interferometer Gravitational-Wave Observatory."
+                .to_string(),
+        );
+        assert_eq!(paper.looks_english(), Some(true));
+
+        paper.abstract_text = Some(
+            "Nous présentons une analyse détaillée des propriétés spectrales des galaxies \
+             naines observées avec le télescope spatial, et nous montrons que leur \
+             métallicité dépend fortement de leur masse stellaire totale dans cet échantillon."
+                .to_string(),
+        );
+        assert_eq!(paper.looks_english(), Some(false));
+
+        paper.abstract_language = Some("EN".to_string());
+        assert_eq!(paper.looks_english(), Some(true));
+        paper.abstract_language = Some("fr".to_string());
+        assert_eq!(paper.looks_english(), Some(false));
+    }
+
+    #[test]
+    fn test_paper_arxiv_categories() {
+        let mut paper = Paper::test_stub("2023arXiv230100001X");
+        paper.keywords = [
+            "Astrophysics - Astrophysics of Galaxies",
+            "astro-ph.GA",
+            "astro-ph.CO",
+            "Galaxy evolution",
+            "galaxies: high-redshift",
+            "98.80.Es",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(paper.arxiv_categories(), ["astro-ph.GA", "astro-ph.CO"]);
+        assert_eq!(
+            paper.subject_keywords(),
+            ["Galaxy evolution", "galaxies: high-redshift", "98.80.Es"]
+        );
+        assert!(Paper::test_stub("x").arxiv_categories().is_empty());
+    }
+
+    #[test]
+    fn test_field_sets_include_bibcode() {
+        for set in [
+            FieldSet::Minimal,
+            FieldSet::Default,
+            FieldSet::Rich,
+            FieldSet::Metrics,
+        ] {
+            assert!(set.as_str().split(',').any(|f| f == "bibcode"), "{:?}", set);
+            assert_eq!(set.to_string(), set.as_ref());
+        }
+        assert_eq!(FieldSet::Minimal.as_str(), "bibcode,title,year");
+        assert_eq!(FieldSet::Rich.as_str(), crate::parse::RICH_FIELDS);
+    }
+
+    #[test]
+    fn test_external_citations() {
+        let entry = CitationStatsEntry {
+            total_citations: Some(120),
+            number_of_self_citations: Some(15),
+            ..Default::default()
+        };
+        assert_eq!(entry.external_citations(), Some(105));
+
+        let no_self = CitationStatsEntry {
+            total_citations: Some(120),
+            ..Default::default()
+        };
+        assert_eq!(no_self.external_citations(), None);
+        assert_eq!(CitationStatsEntry::default().external_citations(), None);
+    }
+
+    #[test]
+    fn test_external_citation_summary() {
+        let metrics: Metrics = serde_json::from_str(
+            r#"{"citation_stats": {
+                "total": {"total_citations": 40, "number_of_self_citations": 4},
+                "refereed": {"total_citations": 30}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            metrics.external_citation_summary(),
+            ExternalCitationSummary {
+                total: Some(36),
+                refereed: None,
+            }
+        );
+        assert_eq!(
+            Metrics::default().external_citation_summary(),
+            ExternalCitationSummary::default()
+        );
+    }
+
+    #[test]
+    fn test_sort_from_human() {
+        let cases = [
+            ("most cited", "citation_count desc"),
+            ("Most  Cited first", "citation_count desc"),
+            ("least cited", "citation_count asc"),
+            ("newest", "date desc"),
+            ("recent", "date desc"),
+            ("newest first", "date desc"),
+            ("oldest", "date asc"),
+            ("most read", "read_count desc"),
+            ("best match", "score desc"),
+            ("relevance", "score desc"),
+        ];
+        for (phrase, expected) in cases {
+            let sort = Sort::from_human(phrase).unwrap_or_else(|| panic!("{}", phrase));
+            assert_eq!(sort.to_string(), expected, "{}", phrase);
+        }
+        assert!(Sort::from_human("citation_count desc").is_none());
+        assert!(Sort::from_human("first").is_none());
+        assert!(Sort::from_human("").is_none());
+    }
+
+    #[test]
+    fn test_sort_from_str_rejects_unknown_field() {
+        let err = "citaton_count desc".parse::<Sort>().unwrap_err();
+        assert!(matches!(err, crate::error::SciXError::InvalidQuery(_)));
+        assert!(err
+            .to_string()
+            .contains("Unknown sort field 'citaton_count'"));
+        assert!(err.to_string().contains("citation_count"));
+    }
+
+    #[test]
+    fn test_sort_from_str_rejects_bad_direction() {
+        assert!("date ascending".parse::<Sort>().is_err());
+        assert!("date asc extra".parse::<Sort>().is_err());
+        assert!("".parse::<Sort>().is_err());
+    }
+}
@@ -0,0 +1,63 @@
+//! Example integration test: the client against a local `wiremock` server.
+//!
+//! Downstream crates can follow the same pattern to test code built on
+//! `SciXClient` without network access or an API token.
+
+use scix_client::SciXClient;
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn test_client(server: &MockServer) -> SciXClient {
+    SciXClient::new_with_client("test-token", reqwest::Client::new(), server.uri())
+        .with_rate_limit(f64::INFINITY)
+}
+
+#[tokio::test]
+async fn search_against_mock_server() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/query"))
+        .and(query_param("q", "author:\"Einstein\" year:1905"))
+        .and(query_param("rows", "2"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "responseHeader": { "status": 0, "QTime": 3 },
+            "response": {
+                "numFound": 1,
+                "start": 0,
+                "docs": [{
+                    "bibcode": "1905AnP...322..891E",
+                    "title": ["Zur Elektrodynamik bewegter Körper"],
+                    "author": ["Einstein, A."],
+                    "year": "1905"
+                }]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let results = test_client(&server)
+        .search("author:\"Einstein\" year:1905", 2)
+        .await
+        .unwrap();
+
+    assert_eq!(results.num_found, 1);
+    assert_eq!(results.papers[0].bibcode, "1905AnP...322..891E");
+    assert_eq!(results.papers[0].authors[0].family_name, "Einstein");
+    assert_eq!(results.meta.unwrap().qtime_ms, Some(3));
+}
+
+#[tokio::test]
+async fn unauthorized_maps_to_auth_required() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/query"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let err = test_client(&server).search("x", 1).await.unwrap_err();
+    assert!(matches!(err, scix_client::SciXError::AuthRequired));
+}
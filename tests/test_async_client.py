@@ -0,0 +1,150 @@
+"""Tests for the asyncio client, run against a local mock of the ADS API.
+
+Build the extension first (``maturin develop --features python``), then run
+``python -m pytest tests/`` or ``python -m unittest discover tests``.
+"""
+
+import asyncio
+import json
+import threading
+import unittest
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from urllib.parse import parse_qs, urlparse
+
+import scix_client
+
+SEARCH_RESPONSE = {
+    "responseHeader": {"status": 0},
+    "response": {
+        "numFound": 2,
+        "start": 0,
+        "docs": [
+            {
+                "bibcode": "2016PhRvL.116f1102A",
+                "title": ["Observation of Gravitational Waves from a Binary Black Hole Merger"],
+                "author": ["Abbott, B. P.", "Abbott, R."],
+                "year": "2016",
+                "citation_count": 10000,
+            },
+            {
+                "bibcode": "1998AJ....116.1009R",
+                "title": ["Observational Evidence from Supernovae for an Accelerating Universe"],
+                "author": ["Riess, A. G."],
+                "year": "1998",
+            },
+        ],
+    },
+}
+
+
+class MockADS(BaseHTTPRequestHandler):
+    requests = []
+
+    def do_GET(self):
+        url = urlparse(self.path)
+        MockADS.requests.append((url.path, parse_qs(url.query), self.headers.get("Authorization")))
+        if url.path == "/search/query":
+            body = json.dumps(SEARCH_RESPONSE).encode()
+            self.send_response(200)
+        else:
+            body = b"server error"
+            self.send_response(500)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def log_message(self, format, *args):
+        pass
+
+
+class AsyncClientTest(unittest.IsolatedAsyncioTestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.server = ThreadingHTTPServer(("127.0.0.1", 0), MockADS)
+        threading.Thread(target=cls.server.serve_forever, daemon=True).start()
+
+    @classmethod
+    def tearDownClass(cls):
+        cls.server.shutdown()
+
+    def setUp(self):
+        MockADS.requests.clear()
+        self.client = scix_client.AsyncSciXClient("test-token")
+        self.client.set_base_url("http://127.0.0.1:%d" % self.server.server_address[1])
+
+    async def test_search(self):
+        results = await self.client.search("gravitational waves", rows=2)
+
+        self.assertIsInstance(results, scix_client.SearchResponse)
+        self.assertEqual(results.num_found, 2)
+        self.assertEqual(results.papers[0].bibcode, "2016PhRvL.116f1102A")
+        self.assertEqual(results.papers[0].authors[0].family_name, "Abbott")
+
+        path, params, auth = MockADS.requests[0]
+        self.assertEqual(path, "/search/query")
+        self.assertEqual(params["q"], ["gravitational waves"])
+        self.assertEqual(params["rows"], ["2"])
+        self.assertEqual(auth, "Bearer test-token")
+
+    async def test_concurrent_requests(self):
+        counts = await asyncio.gather(*(self.client.count("q%d" % i) for i in range(5)))
+        self.assertEqual(counts, [2] * 5)
+
+    async def test_error_raises(self):
+        with self.assertRaises(RuntimeError):
+            await self.client.export_bibtex(["2016PhRvL.116f1102A"])
+
+
+class SyncClientAsyncVariantsTest(unittest.IsolatedAsyncioTestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.server = ThreadingHTTPServer(("127.0.0.1", 0), MockADS)
+        threading.Thread(target=cls.server.serve_forever, daemon=True).start()
+
+    @classmethod
+    def tearDownClass(cls):
+        cls.server.shutdown()
+
+    def setUp(self):
+        MockADS.requests.clear()
+        self.client = scix_client.SciXClient("test-token")
+        self.client.set_base_url("http://127.0.0.1:%d" % self.server.server_address[1])
+
+    async def test_search_async(self):
+        results = await self.client.search_async("gravitational waves", rows=2)
+
+        self.assertEqual(results.num_found, 2)
+        self.assertEqual(results.papers[1].bibcode, "1998AJ....116.1009R")
+        path, params, _ = MockADS.requests[0]
+        self.assertEqual(path, "/search/query")
+        self.assertEqual(params["q"], ["gravitational waves"])
+
+    def test_context_manager(self):
+        with scix_client.SciXClient("test-token") as client:
+            client.set_base_url("http://127.0.0.1:%d" % self.server.server_address[1])
+            results = client.search("dark matter", rows=2)
+            self.assertEqual(results.num_found, 2)
+        # Closing drops pooled connections; the client reconnects on demand.
+        self.assertEqual(client.count("dark matter"), 2)
+
+    def test_set_base_url_rejects_invalid_url(self):
+        with self.assertRaises(ValueError):
+            self.client.set_base_url("localhost:8080")
+
+    def test_exception_propagates_from_with_block(self):
+        with self.assertRaises(ValueError):
+            with scix_client.SciXClient("test-token"):
+                raise ValueError("boom")
+
+    async def test_gather_async_variants(self):
+        results, count = await asyncio.gather(
+            self.client.search_async("dark matter"),
+            self.client.count_async("dark matter"),
+        )
+        self.assertEqual(len(results), 2)
+        self.assertEqual(count, 2)
+
+
+if __name__ == "__main__":
+    unittest.main()
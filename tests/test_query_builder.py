@@ -0,0 +1,66 @@
+"""Tests for the Python QueryBuilder wrapper.
+
+Build the extension first (``maturin develop --features python``), then run
+``python -m pytest tests/`` or ``python -m unittest discover tests``.
+"""
+
+import unittest
+
+import scix_client
+
+
+class QueryBuilderTest(unittest.TestCase):
+    def test_chained_build(self):
+        query = (
+            scix_client.QueryBuilder()
+            .author("Weinberg")
+            .and_()
+            .title("cosmological constant")
+            .and_()
+            .year_range(1985, 1990)
+            .and_()
+            .property("refereed")
+            .build()
+        )
+        self.assertEqual(
+            query,
+            'author:"Weinberg" AND title:"cosmological constant" '
+            "AND year:[1985 TO 1990] AND property:refereed",
+        )
+
+    def test_chaining_returns_same_builder(self):
+        q = scix_client.QueryBuilder()
+        self.assertIs(q.author("Einstein"), q)
+
+    def test_in_place_mutation_still_works(self):
+        q = scix_client.QueryBuilder()
+        q.author("Einstein")
+        q.and_()
+        q.year(1905)
+        self.assertEqual(q.build(), 'author:"Einstein" AND year:1905')
+
+    def test_negated_field_terms(self):
+        query = (
+            scix_client.QueryBuilder()
+            .title("dark energy")
+            .not_property("nonarticle")
+            .not_doctype("proposal")
+            .build()
+        )
+        self.assertEqual(
+            query, 'title:"dark energy" -property:nonarticle -doctype:proposal'
+        )
+
+    def test_pubdate_range(self):
+        q = scix_client.QueryBuilder().pubdate_range("2020-01", "2020-06")
+        self.assertEqual(q.build(), "pubdate:[2020-01 TO 2020-06]")
+
+    def test_pubdate_range_rejects_bad_month(self):
+        q = scix_client.QueryBuilder().author("Einstein")
+        with self.assertRaises(ValueError):
+            q.pubdate_range("2020-13", "2021-01")
+        self.assertEqual(q.build(), 'author:"Einstein"')
+
+
+if __name__ == "__main__":
+    unittest.main()
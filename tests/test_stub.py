@@ -0,0 +1,155 @@
+"""Check that scix_client.pyi matches the compiled module and src/types.rs.
+
+The extension exposes struct fields automatically, so a field added in Rust
+shows up at runtime without touching the stub. These tests catch that drift.
+"""
+
+import ast
+import inspect
+import re
+import unittest
+from pathlib import Path
+
+import scix_client
+
+ROOT = Path(__file__).resolve().parent.parent
+STUB = ROOT / "scix_client.pyi"
+TYPES_RS = ROOT / "src" / "types.rs"
+
+RUST_TO_PY = {
+    "String": "str",
+    "bool": "bool",
+    "i32": "int",
+    "u16": "int",
+    "u32": "int",
+    "u64": "int",
+    "usize": "int",
+    "f64": "float",
+}
+
+
+def rust_to_py(ty):
+    """Translate a Rust field type to the annotation the stub should use."""
+    ty = ty.strip()
+    for mapping in ("HashMap", "BTreeMap"):
+        if ty.startswith(mapping + "<") and ty.endswith(">"):
+            key, value = ty[len(mapping) + 1 : -1].split(",", 1)
+            return "Dict[%s, %s]" % (rust_to_py(key), rust_to_py(value))
+    for wrapper, py in (("Option", "Optional"), ("Vec", "List")):
+        if ty.startswith(wrapper + "<") and ty.endswith(">"):
+            return "%s[%s]" % (py, rust_to_py(ty[len(wrapper) + 1 : -1]))
+    return RUST_TO_PY.get(ty, ty)
+
+
+def rust_get_all_fields():
+    """Map each `#[pyclass(get_all)]` struct in types.rs to {field: py type}."""
+    source = TYPES_RS.read_text()
+    structs = {}
+    pattern = re.compile(
+        r"pyclass\(get_all\)\)\]\s*pub struct (\w+) \{(.*?)\n\}", re.DOTALL
+    )
+    for name, body in pattern.findall(source):
+        fields = re.findall(r"^\s*pub (\w+): (.+),$", body, re.MULTILINE)
+        structs[name] = {field: rust_to_py(ty) for field, ty in fields}
+    return structs
+
+
+def stub_classes():
+    """Map each class in the stub to its properties, methods, and attributes."""
+    tree = ast.parse(STUB.read_text())
+    classes = {}
+    for node in tree.body:
+        if not isinstance(node, ast.ClassDef):
+            continue
+        properties, methods, attributes = {}, {}, set()
+        for item in node.body:
+            if isinstance(item, ast.AnnAssign):
+                attributes.add(item.target.id)
+            elif isinstance(item, ast.FunctionDef):
+                decorators = {d.id for d in item.decorator_list if isinstance(d, ast.Name)}
+                if "property" in decorators:
+                    properties[item.name] = ast.unparse(item.returns)
+                else:
+                    methods[item.name] = item
+        classes[node.name] = (properties, methods, attributes)
+    return classes
+
+
+def runtime_params(func):
+    """Parameter names of a builtin method or constructor, minus `self`."""
+    try:
+        names = list(inspect.signature(func).parameters)
+    except ValueError:
+        # Signatures using Python keywords (`year_range(from, to)`) don't parse.
+        params = func.__text_signature__.strip("()").split(",")
+        names = [param.split("=")[0].strip() for param in params]
+    names = [name.strip("_") for name in names]
+    return [name for name in names if name not in ("self", "$self", "/", "*")]
+
+
+def stub_params(node):
+    names = [arg.arg.strip("_") for arg in node.args.args]
+    return [name for name in names if name != "self"]
+
+
+class StubTest(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.stub = stub_classes()
+
+    def test_classes_match_module(self):
+        exported = {
+            name
+            for name, value in vars(scix_client).items()
+            if isinstance(value, type) and not name.startswith("_")
+        }
+        self.assertEqual(set(self.stub), exported)
+
+    def test_fields_match_types_rs(self):
+        for name, fields in rust_get_all_fields().items():
+            with self.subTest(cls=name):
+                self.assertIn(name, self.stub)
+                self.assertEqual(self.stub[name][0], fields)
+
+    def test_properties_exist(self):
+        for name, (properties, _, _) in self.stub.items():
+            cls = getattr(scix_client, name)
+            runtime = {
+                attr
+                for attr, value in vars(cls).items()
+                if type(value).__name__ == "getset_descriptor"
+            }
+            with self.subTest(cls=name):
+                self.assertEqual(set(properties), runtime)
+
+    def test_enum_variants_exist(self):
+        for name, (_, _, attributes) in self.stub.items():
+            cls = getattr(scix_client, name)
+            for attr in attributes:
+                with self.subTest(cls=name, attr=attr):
+                    self.assertIsInstance(getattr(cls, attr), cls)
+
+    def test_methods_match_module(self):
+        for name, (_, methods, _) in self.stub.items():
+            cls = getattr(scix_client, name)
+            runtime = {
+                attr
+                for attr, value in vars(cls).items()
+                if callable(value) and not attr.startswith("__")
+            }
+            with self.subTest(cls=name):
+                self.assertEqual({m for m in methods if not m.startswith("__")}, runtime)
+
+    def test_method_parameters_match(self):
+        for name, (_, methods, _) in self.stub.items():
+            cls = getattr(scix_client, name)
+            for method, node in methods.items():
+                func = cls if method == "__init__" else getattr(cls, method)
+                if not getattr(func, "__text_signature__", None):
+                    continue
+                with self.subTest(cls=name, method=method):
+                    self.assertEqual(stub_params(node), runtime_params(func))
+
+
+if __name__ == "__main__":
+    unittest.main()